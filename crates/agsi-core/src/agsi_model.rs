@@ -1,15 +1,20 @@
 use serde::{Deserialize, Serialize};
 use validator::Validate;
 
+use crate::geometry::Geometry;
+
 /// Root AGSi structure
 #[derive(Debug, Clone, Serialize, Deserialize, Validate)]
 #[serde(rename_all = "camelCase")]
 pub struct AgsiRoot {
+    #[validate(nested)]
     pub ags_schema: AgsSchema,
+    #[validate(nested)]
     pub ags_file: AgsFile,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub ags_project: Option<AgsProject>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[validate(nested)]
     pub agsi_model: Vec<AgsiModel>,
 }
 
@@ -92,7 +97,11 @@ pub struct AgsiModel {
     pub document_set_id: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub alignment_id: Option<String>,
+    /// Alignment the model is referenced to, see [`AgsiModel::set_alignment`]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub agsi_alignment: Option<Alignment>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[validate(nested)]
     pub agsi_model_element: Vec<AgsiModelElement>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub agsi_model_boundary: Option<AgsiModelBoundary>,
@@ -121,10 +130,12 @@ pub struct AgsiModelElement {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub agsi_geometry_area_limit: Option<serde_json::Value>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[validate(nested)]
     pub agsi_data_parameter_value: Vec<AgsiDataParameterValue>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub agsi_data_property_value: Vec<AgsiDataPropertyValue>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[validate(nested)]
     pub agsi_data_property_summary: Vec<AgsiDataPropertySummary>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub agsi_data_property_from_file: Option<serde_json::Value>,
@@ -159,6 +170,22 @@ pub struct AgsiDataParameterValue {
     pub remarks: Option<String>,
 }
 
+/// Legacy or informal spellings of standard codes found in older files
+pub const PARAMETER_CODE_ALIASES: &[(&str, AgsiParameterCode)] = &[
+    ("FrictionAngle", AgsiParameterCode::AngleFriction),
+    ("Phi", AgsiParameterCode::AngleFriction),
+    ("DilationAngle", AgsiParameterCode::AngleDilation),
+    ("Cu", AgsiParameterCode::UndrainedShearStrength),
+    ("Su", AgsiParameterCode::UndrainedShearStrength),
+    ("UCS", AgsiParameterCode::UniaxialCompressiveStrength),
+    ("UnitWeight", AgsiParameterCode::UnitWeightBulk),
+    ("PoissonRatio", AgsiParameterCode::PoissonsRatio),
+    (
+        "K0",
+        AgsiParameterCode::CoefficientLateralEarthPressureAtRest,
+    ),
+];
+
 /// Standard parameter codes as per AGSi specification
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum AgsiParameterCode {
@@ -166,10 +193,10 @@ pub enum AgsiParameterCode {
     Depth,
     Elevation,
     AnalysisDrainageCondition,
-    
+
     // Density
     UnitWeightBulk,
-    
+
     // Strength
     AngleFriction,
     AngleFrictionPeak,
@@ -180,7 +207,7 @@ pub enum AgsiParameterCode {
     UndrainedShearStrength,
     UndrainedShearStrengthTriaxial,
     UniaxialCompressiveStrength,
-    
+
     // Stiffness
     YoungsModulusDrained,
     YoungsModulusUndrained,
@@ -192,22 +219,22 @@ pub enum AgsiParameterCode {
     ShearModulusDrained,
     ShearModulusUndrained,
     PoissonsRatio,
-    
+
     // Retaining wall
     CoefficientLateralEarthPressureAtRest,
     CoefficientLateralEarthPressureActive,
     CoefficientLateralEarthPressurePassive,
     CoefficientLateralEarthPressureStar,
-    
+
     // Pavement
     CBR,
     SubgradeSurfaceModulus,
-    
+
     // Permeability
     Permeability,
     PermeabilityHorizontal,
     PermeabilityVertical,
-    
+
     // ACEC
     ACECClass,
     ACECDSClass,
@@ -220,41 +247,69 @@ impl AgsiParameterCode {
         match self {
             Self::Depth | Self::Elevation => Some("m"),
             Self::UnitWeightBulk => Some("kN/m3"),
-            Self::AngleFriction | Self::AngleFrictionPeak | Self::AngleFrictionCritical 
-                | Self::AngleFrictionResidual | Self::AngleDilation => Some("deg"),
-            Self::Cohesion | Self::UndrainedShearStrength | Self::UndrainedShearStrengthTriaxial => Some("kPa"),
-            Self::UniaxialCompressiveStrength | Self::YoungsModulusDrained | Self::YoungsModulusUndrained
-                | Self::YoungsModulusDrainedVertical | Self::YoungsModulusUndrainedVertical
-                | Self::YoungsModulusDrainedHorizontal | Self::YoungsModulusUndrainedHorizontal
-                | Self::BulkModulus | Self::ShearModulusDrained | Self::ShearModulusUndrained
-                | Self::SubgradeSurfaceModulus => Some("MPa"),
+            Self::AngleFriction
+            | Self::AngleFrictionPeak
+            | Self::AngleFrictionCritical
+            | Self::AngleFrictionResidual
+            | Self::AngleDilation => Some("deg"),
+            Self::Cohesion
+            | Self::UndrainedShearStrength
+            | Self::UndrainedShearStrengthTriaxial => Some("kPa"),
+            Self::UniaxialCompressiveStrength
+            | Self::YoungsModulusDrained
+            | Self::YoungsModulusUndrained
+            | Self::YoungsModulusDrainedVertical
+            | Self::YoungsModulusUndrainedVertical
+            | Self::YoungsModulusDrainedHorizontal
+            | Self::YoungsModulusUndrainedHorizontal
+            | Self::BulkModulus
+            | Self::ShearModulusDrained
+            | Self::ShearModulusUndrained
+            | Self::SubgradeSurfaceModulus => Some("MPa"),
             Self::CBR => Some("%"),
-            Self::Permeability | Self::PermeabilityHorizontal | Self::PermeabilityVertical => Some("m/s"),
+            Self::Permeability | Self::PermeabilityHorizontal | Self::PermeabilityVertical => {
+                Some("m/s")
+            }
             _ => None,
         }
     }
-    
+
     /// Get the category for this parameter
     pub fn category(&self) -> &'static str {
         match self {
             Self::Depth | Self::Elevation | Self::AnalysisDrainageCondition => "General",
             Self::UnitWeightBulk => "Density",
-            Self::AngleFriction | Self::AngleFrictionPeak | Self::AngleFrictionCritical 
-                | Self::AngleFrictionResidual | Self::AngleDilation | Self::Cohesion 
-                | Self::UndrainedShearStrength | Self::UndrainedShearStrengthTriaxial => "Strength",
-            Self::UniaxialCompressiveStrength | Self::YoungsModulusDrained | Self::YoungsModulusUndrained
-                | Self::YoungsModulusDrainedVertical | Self::YoungsModulusUndrainedVertical
-                | Self::YoungsModulusDrainedHorizontal | Self::YoungsModulusUndrainedHorizontal
-                | Self::BulkModulus | Self::ShearModulusDrained | Self::ShearModulusUndrained
-                | Self::PoissonsRatio => "Stiffness",
-            Self::CoefficientLateralEarthPressureAtRest | Self::CoefficientLateralEarthPressureActive
-                | Self::CoefficientLateralEarthPressurePassive | Self::CoefficientLateralEarthPressureStar => "Ret wall",
+            Self::AngleFriction
+            | Self::AngleFrictionPeak
+            | Self::AngleFrictionCritical
+            | Self::AngleFrictionResidual
+            | Self::AngleDilation
+            | Self::Cohesion
+            | Self::UndrainedShearStrength
+            | Self::UndrainedShearStrengthTriaxial => "Strength",
+            Self::UniaxialCompressiveStrength
+            | Self::YoungsModulusDrained
+            | Self::YoungsModulusUndrained
+            | Self::YoungsModulusDrainedVertical
+            | Self::YoungsModulusUndrainedVertical
+            | Self::YoungsModulusDrainedHorizontal
+            | Self::YoungsModulusUndrainedHorizontal
+            | Self::BulkModulus
+            | Self::ShearModulusDrained
+            | Self::ShearModulusUndrained
+            | Self::PoissonsRatio => "Stiffness",
+            Self::CoefficientLateralEarthPressureAtRest
+            | Self::CoefficientLateralEarthPressureActive
+            | Self::CoefficientLateralEarthPressurePassive
+            | Self::CoefficientLateralEarthPressureStar => "Ret wall",
             Self::CBR | Self::SubgradeSurfaceModulus => "Pavement",
-            Self::Permeability | Self::PermeabilityHorizontal | Self::PermeabilityVertical => "Permeability",
+            Self::Permeability | Self::PermeabilityHorizontal | Self::PermeabilityVertical => {
+                "Permeability"
+            }
             Self::ACECClass | Self::ACECDSClass | Self::ACECCDCClass => "ACEC",
         }
     }
-    
+
     /// Get the description for this parameter
     pub fn description(&self) -> &'static str {
         match self {
@@ -295,7 +350,7 @@ impl AgsiParameterCode {
             Self::ACECCDCClass => "ACEC Design chemical class",
         }
     }
-    
+
     /// Convert to code ID string
     pub fn as_code_id(&self) -> &'static str {
         match self {
@@ -324,7 +379,9 @@ impl AgsiParameterCode {
             Self::PoissonsRatio => "PoissonsRatio",
             Self::CoefficientLateralEarthPressureAtRest => "CoefficientLateralEarthPressureAtRest",
             Self::CoefficientLateralEarthPressureActive => "CoefficientLateralEarthPressureActive",
-            Self::CoefficientLateralEarthPressurePassive => "CoefficientLateralEarthPressurePassive",
+            Self::CoefficientLateralEarthPressurePassive => {
+                "CoefficientLateralEarthPressurePassive"
+            }
             Self::CoefficientLateralEarthPressureStar => "CoefficientLateralEarthPressureStar",
             Self::CBR => "CBR",
             Self::SubgradeSurfaceModulus => "SubgradeSurfaceModulus",
@@ -336,9 +393,24 @@ impl AgsiParameterCode {
             Self::ACECCDCClass => "ACECCDCClass",
         }
     }
-    
+
     /// Try to parse from a code ID string
+    ///
+    /// Legacy spellings listed in [`PARAMETER_CODE_ALIASES`] resolve to
+    /// their canonical code as well.
     pub fn from_code_id(code: &str) -> Option<Self> {
+        Self::from_canonical_code_id(code).or_else(|| Self::from_alias(code))
+    }
+
+    /// Get the canonical code a legacy spelling stands for, if it is one
+    pub fn from_alias(code: &str) -> Option<Self> {
+        PARAMETER_CODE_ALIASES
+            .iter()
+            .find(|(alias, _)| *alias == code)
+            .map(|(_, canonical)| *canonical)
+    }
+
+    fn from_canonical_code_id(code: &str) -> Option<Self> {
         match code {
             "Depth" => Some(Self::Depth),
             "Elevation" => Some(Self::Elevation),
@@ -363,10 +435,18 @@ impl AgsiParameterCode {
             "ShearModulusDrained" => Some(Self::ShearModulusDrained),
             "ShearModulusUndrained" => Some(Self::ShearModulusUndrained),
             "PoissonsRatio" => Some(Self::PoissonsRatio),
-            "CoefficientLateralEarthPressureAtRest" => Some(Self::CoefficientLateralEarthPressureAtRest),
-            "CoefficientLateralEarthPressureActive" => Some(Self::CoefficientLateralEarthPressureActive),
-            "CoefficientLateralEarthPressurePassive" => Some(Self::CoefficientLateralEarthPressurePassive),
-            "CoefficientLateralEarthPressureStar" => Some(Self::CoefficientLateralEarthPressureStar),
+            "CoefficientLateralEarthPressureAtRest" => {
+                Some(Self::CoefficientLateralEarthPressureAtRest)
+            }
+            "CoefficientLateralEarthPressureActive" => {
+                Some(Self::CoefficientLateralEarthPressureActive)
+            }
+            "CoefficientLateralEarthPressurePassive" => {
+                Some(Self::CoefficientLateralEarthPressurePassive)
+            }
+            "CoefficientLateralEarthPressureStar" => {
+                Some(Self::CoefficientLateralEarthPressureStar)
+            }
             "CBR" => Some(Self::CBR),
             "SubgradeSurfaceModulus" => Some(Self::SubgradeSurfaceModulus),
             "Permeability" => Some(Self::Permeability),
@@ -401,7 +481,7 @@ pub struct AgsiDataPropertyValue {
 }
 
 /// AGSi Data Property Summary
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
 #[serde(rename_all = "camelCase")]
 pub struct AgsiDataPropertySummary {
     #[serde(rename = "dataID")]
@@ -418,10 +498,115 @@ pub struct AgsiDataPropertySummary {
     pub value_max: Option<f64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub value_mean: Option<f64>,
+    /// Standard deviation of the summarised values
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(range(min = 0.0))]
+    pub value_std: Option<f64>,
+    /// Number of values summarised
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value_count: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remarks: Option<String>,
+}
+
+/// AGSi Data Property From File - property data held in an external file
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AgsiDataPropertyFromFile {
+    #[serde(rename = "dataID")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data_id: Option<String>,
+    /// ID of the file holding the data, within the model's document set
+    #[serde(rename = "fileID")]
+    pub file_id: String,
+    /// Columns of the file that hold property values
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub columns: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub remarks: Option<String>,
 }
 
+/// Alignment for chainage-referenced (linear infrastructure) models
+///
+/// Each station chainage has a plan position and, optionally, an elevation.
+/// Positions between stations are interpolated linearly.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Alignment {
+    #[serde(rename = "alignmentID")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alignment_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Station chainages, ascending
+    pub stations: Vec<f64>,
+    /// Plan `[x, y]` position of each station
+    pub horizontal: Vec<[f64; 2]>,
+    /// Elevation of each station; empty when there is no vertical profile
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub vertical: Vec<f64>,
+}
+
+impl Alignment {
+    /// Create an alignment from stations and their plan positions
+    pub fn new(id: impl Into<String>, stations: Vec<f64>, horizontal: Vec<[f64; 2]>) -> Self {
+        Self {
+            alignment_id: Some(id.into()),
+            description: None,
+            stations,
+            horizontal,
+            vertical: Vec::new(),
+        }
+    }
+
+    /// Set the elevation of each station
+    pub fn with_vertical(mut self, vertical: Vec<f64>) -> Self {
+        self.vertical = vertical;
+        self
+    }
+
+    /// Convert a chainage to an `[x, y, z]` position
+    ///
+    /// Returns `None` outside the station range or when the horizontal (or a
+    /// non-empty vertical) profile doesn't have one entry per station. `z` is
+    /// 0 without a vertical profile.
+    pub fn chainage_to_xyz(&self, chainage: f64) -> Option<[f64; 3]> {
+        let n = self.stations.len();
+        if n == 0
+            || self.horizontal.len() != n
+            || !(self.vertical.is_empty() || self.vertical.len() == n)
+        {
+            return None;
+        }
+
+        let z_at = |i: usize| self.vertical.get(i).copied().unwrap_or(0.0);
+        if n == 1 {
+            return (chainage == self.stations[0])
+                .then(|| [self.horizontal[0][0], self.horizontal[0][1], z_at(0)]);
+        }
+
+        let i = self
+            .stations
+            .windows(2)
+            .position(|w| w[0] <= chainage && chainage <= w[1])?;
+        let (start, end) = (self.stations[i], self.stations[i + 1]);
+        let t = if end > start {
+            (chainage - start) / (end - start)
+        } else {
+            0.0
+        };
+        let lerp = |a: f64, b: f64| a + (b - a) * t;
+
+        Some([
+            lerp(self.horizontal[i][0], self.horizontal[i + 1][0]),
+            lerp(self.horizontal[i][1], self.horizontal[i + 1][1]),
+            lerp(z_at(i), z_at(i + 1)),
+        ])
+    }
+}
+
 /// AGSi Model Boundary
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -454,7 +639,9 @@ impl AgsiRoot {
             ags_schema: AgsSchema {
                 name: "AGSi".to_string(),
                 version: "1.0.1".to_string(),
-                link: Some("https://ags-data-format-wg.gitlab.io/agsi/agsi_standard/1.0.1/".to_string()),
+                link: Some(
+                    "https://ags-data-format-wg.gitlab.io/agsi/agsi_standard/1.0.1/".to_string(),
+                ),
             },
             ags_file: AgsFile {
                 file_uuid: None,
@@ -471,10 +658,26 @@ impl AgsiRoot {
             agsi_model: Vec::new(),
         }
     }
-    
+
     pub fn add_model(&mut self, model: AgsiModel) {
         self.agsi_model.push(model);
     }
+
+    /// Report how many models and elements populate each optional AGSi
+    /// field, see [`crate::coverage::field_coverage`]
+    pub fn field_coverage(&self) -> Vec<crate::coverage::FieldCoverage> {
+        serde_json::to_value(self)
+            .map(|value| crate::coverage::field_coverage(&value))
+            .unwrap_or_default()
+    }
+
+    /// Find an element by ID across all models
+    pub fn find_element(&self, element_id: &str) -> Option<&AgsiModelElement> {
+        self.agsi_model
+            .iter()
+            .flat_map(|m| &m.agsi_model_element)
+            .find(|e| e.element_id.as_deref() == Some(element_id))
+    }
 }
 
 impl AgsiModel {
@@ -493,15 +696,157 @@ impl AgsiModel {
             uncertainty: None,
             document_set_id: None,
             alignment_id: None,
+            agsi_alignment: None,
             agsi_model_element: Vec::new(),
             agsi_model_boundary: None,
             remarks: None,
         }
     }
-    
+
     pub fn add_element(&mut self, element: AgsiModelElement) {
         self.agsi_model_element.push(element);
     }
+
+    /// Reference the model to an alignment, updating `alignment_id` to match
+    pub fn set_alignment(&mut self, alignment: Alignment) {
+        self.alignment_id = alignment.alignment_id.clone();
+        self.agsi_alignment = Some(alignment);
+    }
+
+    /// Split the parameter codes used by the model's elements into standard
+    /// [`AgsiParameterCode`]s and free-text codes
+    ///
+    /// Each list is sorted and free of duplicates.
+    pub fn code_usage(&self) -> (Vec<String>, Vec<String>) {
+        let codes: std::collections::BTreeSet<&str> = self
+            .agsi_model_element
+            .iter()
+            .flat_map(|e| &e.agsi_data_parameter_value)
+            .map(|p| p.code_id.as_str())
+            .collect();
+
+        let (standard, free_text): (Vec<&str>, Vec<&str>) = codes
+            .into_iter()
+            .partition(|code| AgsiParameterCode::from_code_id(code).is_some());
+        (
+            standard.into_iter().map(String::from).collect(),
+            free_text.into_iter().map(String::from).collect(),
+        )
+    }
+
+    /// Multiply every numeric value of a parameter code by a factor
+    ///
+    /// Profile values are scaled too. With `new_case` the originals are left
+    /// alone and a scaled copy of each element's case-less value is added
+    /// under that case instead. An element with no case-less value has its
+    /// only cased value copied; one with several cased values is skipped, so
+    /// the new case never ends up with conflicting values. An earlier copy
+    /// under the same case is replaced so repeating a run doesn't compound
+    /// the factor. Returns the number of values scaled.
+    pub fn scale_parameter(&mut self, code_id: &str, factor: f64, new_case: Option<&str>) -> usize {
+        let scale = |parameter: &mut AgsiDataParameterValue| {
+            if let Some(ref mut value) = parameter.value_numeric {
+                *value *= factor;
+            }
+            if let Some(ref mut profile) = parameter.value_profile {
+                profile.iter_mut().for_each(|point| point[1] *= factor);
+            }
+        };
+        let is_numeric = |p: &AgsiDataParameterValue| {
+            p.code_id == code_id && (p.value_numeric.is_some() || p.value_profile.is_some())
+        };
+
+        let mut scaled = 0;
+        for element in &mut self.agsi_model_element {
+            let parameters = &mut element.agsi_data_parameter_value;
+            match new_case {
+                Some(case) => {
+                    let candidates: Vec<_> = parameters
+                        .iter()
+                        .filter(|p| is_numeric(p) && p.case_id.as_deref() != Some(case))
+                        .collect();
+                    let source = match candidates.iter().find(|p| p.case_id.is_none()) {
+                        Some(caseless) => *caseless,
+                        None if candidates.len() == 1 => candidates[0],
+                        None => continue,
+                    };
+                    let mut copy = source.clone().with_case(case);
+                    copy.data_id = None;
+                    scale(&mut copy);
+
+                    parameters
+                        .retain(|p| !(p.code_id == code_id && p.case_id.as_deref() == Some(case)));
+                    parameters.push(copy);
+                    scaled += 1;
+                }
+                None => {
+                    for parameter in parameters.iter_mut().filter(|p| is_numeric(p)) {
+                        scale(parameter);
+                        scaled += 1;
+                    }
+                }
+            }
+        }
+        scaled
+    }
+
+    /// Give every element without a `colour_rgb` a colour from the palette
+    ///
+    /// Palette colours already used by other elements are skipped so the
+    /// result stays distinct. Returns `true` if the palette ran out and
+    /// colours had to be reused.
+    pub fn auto_assign_colours(&mut self, palette: &[&str]) -> bool {
+        self.assign_colours(palette, false)
+    }
+
+    /// Recolour every element from the palette, replacing existing colours
+    ///
+    /// Returns `true` if the palette ran out and colours had to be reused.
+    pub fn reassign_colours(&mut self, palette: &[&str]) -> bool {
+        self.assign_colours(palette, true)
+    }
+
+    fn assign_colours(&mut self, palette: &[&str], overwrite: bool) -> bool {
+        if palette.is_empty() {
+            return false;
+        }
+
+        let used: std::collections::HashSet<String> = if overwrite {
+            Default::default()
+        } else {
+            self.agsi_model_element
+                .iter()
+                .filter_map(|e| e.colour_rgb.as_deref().map(str::to_uppercase))
+                .collect()
+        };
+        let mut available = palette
+            .iter()
+            .filter(|c| !used.contains(&c.to_uppercase()))
+            .chain(palette.iter().cycle())
+            .enumerate();
+
+        let fresh = palette
+            .iter()
+            .filter(|c| !used.contains(&c.to_uppercase()))
+            .count();
+        let mut exhausted = false;
+        for element in &mut self.agsi_model_element {
+            if element.colour_rgb.is_some() && !overwrite {
+                continue;
+            }
+            if let Some((idx, colour)) = available.next() {
+                exhausted |= idx >= fresh;
+                element.colour_rgb = Some(colour.to_string());
+            }
+        }
+        exhausted
+    }
+}
+
+impl Default for AgsiModel {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl AgsiModelElement {
@@ -522,25 +867,145 @@ impl AgsiModelElement {
             remarks: None,
         }
     }
-    
+
     pub fn with_name(mut self, name: impl Into<String>) -> Self {
         self.element_name = Some(name.into());
         self
     }
-    
+
     pub fn with_description(mut self, description: impl Into<String>) -> Self {
         self.description = Some(description.into());
         self
     }
-    
+
     pub fn add_parameter(&mut self, parameter: AgsiDataParameterValue) {
         self.agsi_data_parameter_value.push(parameter);
     }
-    
+
     pub fn with_parameter(mut self, parameter: AgsiDataParameterValue) -> Self {
         self.agsi_data_parameter_value.push(parameter);
         self
     }
+
+    /// Remove parameters that repeat an earlier one's code, case and value
+    ///
+    /// The first occurrence is kept. Returns the number removed.
+    pub fn dedupe_parameters(&mut self) -> usize {
+        let before = self.agsi_data_parameter_value.len();
+        let mut kept: Vec<AgsiDataParameterValue> = Vec::with_capacity(before);
+        for parameter in self.agsi_data_parameter_value.drain(..) {
+            if !kept
+                .iter()
+                .any(|k| k.same_case(&parameter) && k.same_value(&parameter))
+            {
+                kept.push(parameter);
+            }
+        }
+        self.agsi_data_parameter_value = kept;
+        before - self.agsi_data_parameter_value.len()
+    }
+
+    /// Get the numeric value of a parameter code under each case, in order
+    ///
+    /// Parameters without a case come back as `None`; text-only values are
+    /// skipped.
+    pub fn case_comparison(&self, code_id: &str) -> Vec<(Option<String>, f64)> {
+        self.agsi_data_parameter_value
+            .iter()
+            .filter(|p| p.code_id == code_id)
+            .filter_map(|p| Some((p.case_id.clone(), p.value_numeric?)))
+            .collect()
+    }
+
+    /// Rewrite parameter codes given as aliases to their canonical spelling
+    ///
+    /// Custom codes are left alone. Returns the index, old code and new code
+    /// of each parameter rewritten.
+    pub fn normalize_codes(&mut self) -> Vec<(usize, String, &'static str)> {
+        let mut rewrites = Vec::new();
+        for (idx, parameter) in self.agsi_data_parameter_value.iter_mut().enumerate() {
+            let Some(code) = AgsiParameterCode::from_code_id(parameter.code_id.trim()) else {
+                continue;
+            };
+            let canonical = code.as_code_id();
+            if parameter.code_id != canonical {
+                let old = std::mem::replace(&mut parameter.code_id, canonical.to_string());
+                rewrites.push((idx, old, canonical));
+            }
+        }
+        rewrites
+    }
+
+    /// Get the standard unit for each parameter, in order
+    ///
+    /// Free-text codes and codes without a standard unit map to `None`.
+    pub fn units_for_parameters(&self) -> Vec<(String, Option<&'static str>)> {
+        self.agsi_data_parameter_value
+            .iter()
+            .map(|p| {
+                let units = AgsiParameterCode::from_code_id(&p.code_id).and_then(|c| c.units());
+                (p.code_id.clone(), units)
+            })
+            .collect()
+    }
+
+    /// Read the element's geometry as a typed [`Geometry`]
+    ///
+    /// Returns `None` if no geometry is set, or an error if the stored value
+    /// isn't a recognised geometry.
+    pub fn geometry(&self) -> Option<crate::Result<Geometry>> {
+        self.agsi_geometry
+            .as_ref()
+            .map(|value| serde_json::from_value(value.clone()).map_err(Into::into))
+    }
+
+    /// Store a typed geometry as the element's geometry
+    pub fn set_geometry(&mut self, geometry: &Geometry) {
+        self.agsi_geometry = serde_json::to_value(geometry).ok();
+    }
+
+    /// Store a polygon as the element's area limit
+    ///
+    /// The first ring is the exterior, any others are holes.
+    pub fn set_area_limit_polygon(&mut self, rings: Vec<Vec<[f64; 3]>>) {
+        let polygon = Geometry::Polygon {
+            rings,
+            crs: None,
+            wkt: None,
+            wkb: None,
+        };
+        self.agsi_geometry_area_limit = serde_json::to_value(polygon).ok();
+    }
+
+    /// Read the element's from-file property reference as a typed value
+    ///
+    /// Returns `None` if no reference is set, or an error if the stored value
+    /// isn't a recognised reference.
+    pub fn property_from_file(&self) -> Option<crate::Result<AgsiDataPropertyFromFile>> {
+        self.agsi_data_property_from_file
+            .as_ref()
+            .map(|value| serde_json::from_value(value.clone()).map_err(Into::into))
+    }
+
+    /// Store a typed from-file property reference on the element
+    pub fn set_property_from_file(&mut self, reference: &AgsiDataPropertyFromFile) {
+        self.agsi_data_property_from_file = serde_json::to_value(reference).ok();
+    }
+
+    /// Read the area limit back as a typed geometry
+    ///
+    /// Returns `None` if no limit is set or it isn't a recognised geometry.
+    pub fn area_limit_geometry(&self) -> Option<Geometry> {
+        self.agsi_geometry_area_limit
+            .as_ref()
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+    }
+}
+
+impl Default for AgsiModelElement {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl AgsiDataParameterValue {
@@ -557,7 +1022,7 @@ impl AgsiDataParameterValue {
             remarks: None,
         }
     }
-    
+
     /// Create a new parameter with text value
     pub fn text(code_id: impl Into<String>, value: impl Into<String>) -> Self {
         Self {
@@ -571,23 +1036,117 @@ impl AgsiDataParameterValue {
             remarks: None,
         }
     }
-    
+
     /// Create from a standard parameter code
     pub fn from_standard_code(code: AgsiParameterCode, value: f64) -> Self {
         Self::numeric(code.as_code_id(), value)
     }
-    
+
     /// Set the case ID
     pub fn with_case(mut self, case_id: impl Into<String>) -> Self {
         self.case_id = Some(case_id.into());
         self
     }
+
+    /// Split a `value_profile` into independent and dependent vectors for plotting
+    ///
+    /// Returns `None` if the parameter has no profile.
+    pub fn profile_as_xy(&self) -> Option<(Vec<f64>, Vec<f64>)> {
+        let profile = self.value_profile.as_ref()?;
+        Some(profile.iter().map(|[x, y]| (*x, *y)).unzip())
+    }
+
+    /// Check whether two parameters share a code and case
+    pub fn same_case(&self, other: &Self) -> bool {
+        self.code_id == other.code_id && self.case_id == other.case_id
+    }
+
+    /// Check whether two parameters hold the same numeric, text and profile values
+    pub fn same_value(&self, other: &Self) -> bool {
+        self.value_numeric == other.value_numeric
+            && self.value_text == other.value_text
+            && self.value_profile == other.value_profile
+    }
+}
+
+impl AgsiDataPropertySummary {
+    /// Create an empty summary for a property code
+    pub fn new(code_id: impl Into<String>) -> Self {
+        Self {
+            data_id: None,
+            code_id: code_id.into(),
+            case_id: None,
+            value_min: None,
+            value_max: None,
+            value_mean: None,
+            value_std: None,
+            value_count: None,
+            remarks: None,
+        }
+    }
+
+    /// Set the minimum and maximum values
+    pub fn with_range(mut self, min: f64, max: f64) -> Self {
+        self.value_min = Some(min);
+        self.value_max = Some(max);
+        self
+    }
+
+    /// Set the mean value
+    pub fn with_mean(mut self, mean: f64) -> Self {
+        self.value_mean = Some(mean);
+        self
+    }
+
+    /// Set the standard deviation and the number of values it was computed from
+    pub fn with_std(mut self, std: f64, count: usize) -> Self {
+        self.value_std = Some(std);
+        self.value_count = Some(count);
+        self
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    
+
+    #[test]
+    fn test_alignment_chainage_to_xyz() {
+        let alignment =
+            Alignment::new("AL01", vec![1000.0, 1100.0], vec![[0.0, 0.0], [60.0, 80.0]])
+                .with_vertical(vec![10.0, 20.0]);
+        let mut model = AgsiModel::new();
+        model.set_alignment(alignment);
+        assert_eq!(model.alignment_id.as_deref(), Some("AL01"));
+
+        let alignment = model.agsi_alignment.as_ref().unwrap();
+        assert_eq!(alignment.chainage_to_xyz(1050.0), Some([30.0, 40.0, 15.0]));
+        assert_eq!(alignment.chainage_to_xyz(1100.0), Some([60.0, 80.0, 20.0]));
+        assert_eq!(alignment.chainage_to_xyz(999.0), None);
+    }
+
+    #[test]
+    fn test_property_from_file_round_trip() {
+        let mut element = AgsiModelElement::new();
+        assert!(element.property_from_file().is_none());
+
+        let reference = AgsiDataPropertyFromFile {
+            data_id: Some("DATA001".to_string()),
+            file_id: "LAB-RESULTS".to_string(),
+            columns: vec!["Depth".to_string(), "MoistureContent".to_string()],
+            description: None,
+            remarks: None,
+        };
+        element.set_property_from_file(&reference);
+
+        let stored = element.agsi_data_property_from_file.as_ref().unwrap();
+        assert_eq!(stored["fileID"], "LAB-RESULTS");
+        assert_eq!(element.property_from_file().unwrap().unwrap(), reference);
+
+        element.agsi_data_property_from_file = Some(serde_json::json!({"columns": 3}));
+        assert!(element.property_from_file().unwrap().is_err());
+    }
+
     #[test]
     fn test_create_agsi_root() {
         let root = AgsiRoot::new("Test Project");
@@ -595,20 +1154,314 @@ mod tests {
         assert_eq!(root.ags_schema.version, "1.0.1");
         assert_eq!(root.ags_file.project_name, "Test Project");
     }
-    
+
     #[test]
     fn test_create_model_element() {
         let mut element = AgsiModelElement::new()
             .with_name("Dense Sand")
             .with_description("Dense, medium to coarse sand");
-        
+
         element.add_parameter(AgsiDataParameterValue::numeric("UnitWeightBulk", 19.0));
         element.add_parameter(AgsiDataParameterValue::numeric("AngleFriction", 35.0));
-        
+
         assert_eq!(element.element_name, Some("Dense Sand".to_string()));
         assert_eq!(element.agsi_data_parameter_value.len(), 2);
     }
-    
+
+    #[test]
+    fn test_auto_assign_colours() {
+        let palette = ["#FF0000", "#00FF00", "#0000FF"];
+        let mut model = AgsiModel::new();
+        for _ in 0..3 {
+            model.add_element(AgsiModelElement::new());
+        }
+
+        assert!(!model.auto_assign_colours(&palette));
+        let colours: Vec<_> = model
+            .agsi_model_element
+            .iter()
+            .map(|e| e.colour_rgb.as_deref().unwrap())
+            .collect();
+        assert_eq!(colours, palette);
+
+        // Existing colours are kept and a fourth element reuses the palette
+        model.add_element(AgsiModelElement::new());
+        assert!(model.auto_assign_colours(&palette));
+        assert_eq!(
+            model.agsi_model_element[0].colour_rgb.as_deref(),
+            Some("#FF0000")
+        );
+        assert_eq!(
+            model.agsi_model_element[3].colour_rgb.as_deref(),
+            Some("#FF0000")
+        );
+
+        assert!(model.reassign_colours(&["#000000"]));
+        assert!(model
+            .agsi_model_element
+            .iter()
+            .all(|e| e.colour_rgb.as_deref() == Some("#000000")));
+    }
+
+    #[test]
+    fn test_scale_parameter() {
+        let mut model = AgsiModel::new();
+        model.add_element(
+            AgsiModelElement::new()
+                .with_parameter(AgsiDataParameterValue::numeric(
+                    "YoungsModulusDrained",
+                    30.0,
+                ))
+                .with_parameter(AgsiDataParameterValue::numeric("AngleFriction", 35.0)),
+        );
+        model.add_element(
+            AgsiModelElement::new().with_parameter(AgsiDataParameterValue::numeric(
+                "YoungsModulusDrained",
+                50.0,
+            )),
+        );
+
+        assert_eq!(
+            model.scale_parameter("YoungsModulusDrained", 0.5, Some("Sensitivity")),
+            2
+        );
+        let first = &model.agsi_model_element[0].agsi_data_parameter_value;
+        assert_eq!(first.len(), 3);
+        assert_eq!(first[0].value_numeric, Some(30.0));
+        assert_eq!(first[2].case_id.as_deref(), Some("Sensitivity"));
+        assert_eq!(first[2].value_numeric, Some(15.0));
+        let second = &model.agsi_model_element[1].agsi_data_parameter_value;
+        assert_eq!(second[1].value_numeric, Some(25.0));
+
+        // Rerunning replaces the sensitivity case rather than compounding it
+        model.scale_parameter("YoungsModulusDrained", 0.5, Some("Sensitivity"));
+        assert_eq!(
+            model.agsi_model_element[0].agsi_data_parameter_value.len(),
+            3
+        );
+
+        assert_eq!(model.scale_parameter("AngleFriction", 2.0, None), 1);
+        assert_eq!(
+            model.agsi_model_element[0].agsi_data_parameter_value[1].value_numeric,
+            Some(70.0)
+        );
+    }
+
+    #[test]
+    fn test_scale_parameter_copies_only_caseless_value() {
+        let mut model = AgsiModel::new();
+        model.add_element(
+            AgsiModelElement::new()
+                .with_parameter(AgsiDataParameterValue::numeric("AngleFriction", 36.0))
+                .with_parameter(
+                    AgsiDataParameterValue::numeric("AngleFriction", 38.0)
+                        .with_case("Characteristic"),
+                )
+                .with_parameter(
+                    AgsiDataParameterValue::numeric("AngleFriction", 35.0)
+                        .with_case("Conservative"),
+                ),
+        );
+        // Several cased values and no case-less one, so no value to copy
+        model.add_element(
+            AgsiModelElement::new()
+                .with_parameter(
+                    AgsiDataParameterValue::numeric("AngleFriction", 38.0)
+                        .with_case("Characteristic"),
+                )
+                .with_parameter(
+                    AgsiDataParameterValue::numeric("AngleFriction", 35.0)
+                        .with_case("Conservative"),
+                ),
+        );
+
+        assert_eq!(
+            model.scale_parameter("AngleFriction", 0.5, Some("Sensitivity")),
+            1
+        );
+        let sensitivity = model.agsi_model_element[0].case_comparison("AngleFriction");
+        assert_eq!(
+            sensitivity.last(),
+            Some(&(Some("Sensitivity".to_string()), 18.0))
+        );
+        assert_eq!(
+            model.agsi_model_element[1].agsi_data_parameter_value.len(),
+            2
+        );
+
+        let mut root = AgsiRoot::new("Test");
+        root.add_model(model);
+        let result = crate::validation::validate_parameter_duplicates(&root).unwrap();
+        assert!(result.warnings.is_empty());
+    }
+
+    #[test]
+    fn test_scale_parameter_falls_back_to_only_cased_value() {
+        let mut model = AgsiModel::new();
+        model.add_element(AgsiModelElement::new().with_parameter(
+            AgsiDataParameterValue::numeric("AngleFriction", 38.0).with_case("Characteristic"),
+        ));
+
+        assert_eq!(
+            model.scale_parameter("AngleFriction", 0.5, Some("Sensitivity")),
+            1
+        );
+        let element = &model.agsi_model_element[0];
+        assert_eq!(
+            element.case_comparison("AngleFriction"),
+            vec![
+                (Some("Characteristic".to_string()), 38.0),
+                (Some("Sensitivity".to_string()), 19.0),
+            ]
+        );
+
+        // The earlier copy is not a second candidate on a rerun
+        assert_eq!(
+            model.scale_parameter("AngleFriction", 0.5, Some("Sensitivity")),
+            1
+        );
+        assert_eq!(
+            model.agsi_model_element[0].agsi_data_parameter_value.len(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_dedupe_parameters() {
+        let mut element = AgsiModelElement::new()
+            .with_parameter(AgsiDataParameterValue::numeric("AngleFriction", 32.0))
+            .with_parameter(AgsiDataParameterValue::numeric("AngleFriction", 32.0))
+            .with_parameter(
+                AgsiDataParameterValue::numeric("AngleFriction", 32.0).with_case("Design"),
+            )
+            .with_parameter(AgsiDataParameterValue::numeric("AngleFriction", 30.0));
+
+        assert_eq!(element.dedupe_parameters(), 1);
+        assert_eq!(element.agsi_data_parameter_value.len(), 3);
+        assert_eq!(element.dedupe_parameters(), 0);
+    }
+
+    #[test]
+    fn test_profile_as_xy() {
+        let mut parameter = AgsiDataParameterValue::numeric("UndrainedShearStrength", 50.0);
+        assert!(parameter.profile_as_xy().is_none());
+
+        parameter.value_profile_ind_var_code_id = Some("Depth".to_string());
+        parameter.value_profile = Some(vec![[0.0, 50.0], [5.0, 75.0], [10.0, 100.0]]);
+
+        let (depths, values) = parameter.profile_as_xy().unwrap();
+        assert_eq!(depths, vec![0.0, 5.0, 10.0]);
+        assert_eq!(values, vec![50.0, 75.0, 100.0]);
+    }
+
+    #[test]
+    fn test_code_usage() {
+        let mut model = AgsiModel::new();
+        model.add_element(
+            AgsiModelElement::new()
+                .with_parameter(AgsiDataParameterValue::numeric("AngleFriction", 32.0))
+                .with_parameter(AgsiDataParameterValue::text("SiteSpecificIndex", "B")),
+        );
+        model.add_element(
+            AgsiModelElement::new()
+                .with_parameter(AgsiDataParameterValue::numeric("AngleFriction", 28.0)),
+        );
+
+        let (standard, free_text) = model.code_usage();
+        assert_eq!(standard, vec!["AngleFriction"]);
+        assert_eq!(free_text, vec!["SiteSpecificIndex"]);
+    }
+
+    #[test]
+    fn test_case_comparison() {
+        let root = crate::fixtures::agsi_model_example();
+        let gravel = root.agsi_model[0]
+            .agsi_model_element
+            .iter()
+            .find(|e| e.element_name.as_deref() == Some("River Terrace Deposits"))
+            .unwrap();
+
+        assert_eq!(
+            gravel.case_comparison("AngleFriction"),
+            vec![
+                (Some("Characteristic".to_string()), 38.0),
+                (Some("Conservative".to_string()), 35.0),
+            ]
+        );
+        assert_eq!(gravel.case_comparison("UnitWeightBulk"), vec![(None, 20.0)]);
+    }
+
+    #[test]
+    fn test_units_for_parameters() {
+        let element = AgsiModelElement::new()
+            .with_parameter(AgsiDataParameterValue::from_standard_code(
+                AgsiParameterCode::AngleFriction,
+                32.0,
+            ))
+            .with_parameter(AgsiDataParameterValue::text(
+                "CustomParameter",
+                "Custom Value",
+            ));
+
+        assert_eq!(
+            element.units_for_parameters(),
+            vec![
+                ("AngleFriction".to_string(), Some("deg")),
+                ("CustomParameter".to_string(), None),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_typed_geometry() {
+        let mut element = AgsiModelElement::new().with_name("Made Ground");
+        assert!(element.geometry().is_none());
+
+        let polygon = Geometry::polygon(
+            vec![
+                [0.0, 0.0, 5.0],
+                [20.0, 0.0, 5.0],
+                [20.0, 20.0, 5.0],
+                [0.0, 0.0, 5.0],
+            ],
+            vec![],
+        )
+        .unwrap()
+        .with_crs("EPSG:27700");
+        element.set_geometry(&polygon);
+
+        let typed = element.geometry().unwrap().unwrap();
+        assert_eq!(typed.type_name(), "Polygon");
+        assert_eq!(typed.crs(), Some("EPSG:27700"));
+        assert_eq!(typed.coordinates().len(), 4);
+
+        element.agsi_geometry = Some(serde_json::json!("not a geometry"));
+        assert!(element.geometry().unwrap().is_err());
+    }
+
+    #[test]
+    fn test_area_limit_polygon() {
+        let mut element = AgsiModelElement::new().with_name("Alluvium");
+        assert!(element.area_limit_geometry().is_none());
+
+        let exterior = vec![
+            [0.0, 0.0, 0.0],
+            [100.0, 0.0, 0.0],
+            [100.0, 50.0, 0.0],
+            [0.0, 50.0, 0.0],
+            [0.0, 0.0, 0.0],
+        ];
+        element.set_area_limit_polygon(vec![exterior.clone()]);
+
+        match element.area_limit_geometry() {
+            Some(Geometry::Polygon { rings, .. }) => assert_eq!(rings, vec![exterior]),
+            other => panic!("expected polygon, got {:?}", other),
+        }
+
+        element.agsi_geometry_area_limit = Some(serde_json::json!({"opaque": true}));
+        assert!(element.area_limit_geometry().is_none());
+    }
+
     #[test]
     fn test_standard_parameter_codes() {
         let code = AgsiParameterCode::UndrainedShearStrength;
@@ -616,56 +1469,74 @@ mod tests {
         assert_eq!(code.category(), "Strength");
         assert_eq!(code.as_code_id(), "UndrainedShearStrength");
     }
-    
+
     #[test]
     fn test_free_text_parameter() {
         let param = AgsiDataParameterValue::text("CustomParameter", "Custom Value");
         assert_eq!(param.code_id, "CustomParameter");
         assert_eq!(param.value_text, Some("Custom Value".to_string()));
     }
-    
+
     #[test]
     fn test_json_serialization() {
         let mut root = AgsiRoot::new("Test Project");
         let mut model = AgsiModel::new();
         model.model_name = Some("Test Model".to_string());
-        
+
         let element = AgsiModelElement::new()
             .with_name("Test Material")
             .with_parameter(AgsiDataParameterValue::from_standard_code(
                 AgsiParameterCode::UnitWeightBulk,
-                20.0
+                20.0,
             ));
-        
+
         model.add_element(element);
         root.add_model(model);
-        
+
         // Serialize to JSON
         let json = serde_json::to_string(&root).unwrap();
         assert!(json.contains("AGSi"));
         assert!(json.contains("Test Project"));
         assert!(json.contains("Test Material"));
         assert!(json.contains("UnitWeightBulk"));
-        
+
         // Deserialize back
         let deserialized: AgsiRoot = serde_json::from_str(&json).unwrap();
         assert_eq!(deserialized.ags_file.project_name, "Test Project");
         assert_eq!(deserialized.agsi_model.len(), 1);
         assert_eq!(deserialized.agsi_model[0].agsi_model_element.len(), 1);
     }
-    
+
     #[test]
     fn test_parameter_code_lookup() {
         // Test forward lookup
         let code = AgsiParameterCode::AngleFriction;
         let code_id = code.as_code_id();
         assert_eq!(code_id, "AngleFriction");
-        
+
         // Test reverse lookup
         let parsed = AgsiParameterCode::from_code_id(code_id);
         assert_eq!(parsed, Some(code));
     }
-    
+
+    #[test]
+    fn test_parameter_code_aliases() {
+        assert_eq!(
+            AgsiParameterCode::from_code_id("Cu"),
+            Some(AgsiParameterCode::UndrainedShearStrength)
+        );
+        assert_eq!(
+            AgsiParameterCode::from_code_id("FrictionAngle"),
+            Some(AgsiParameterCode::AngleFriction)
+        );
+        assert_eq!(AgsiParameterCode::from_alias("AngleFriction"), None);
+
+        // Aliases never shadow a canonical code
+        for (alias, _) in PARAMETER_CODE_ALIASES {
+            assert!(AgsiParameterCode::from_canonical_code_id(alias).is_none());
+        }
+    }
+
     #[test]
     fn test_all_parameter_codes_have_metadata() {
         // Ensure all parameter codes have units, category, and description
@@ -678,16 +1549,46 @@ mod tests {
             AgsiParameterCode::Permeability,
             AgsiParameterCode::CBR,
         ];
-        
+
         for code in codes {
             // All should have a description
             assert!(!code.description().is_empty());
-            
+
             // All should have a category
             assert!(!code.category().is_empty());
-            
+
             // All should have a code ID
             assert!(!code.as_code_id().is_empty());
         }
     }
+
+    #[test]
+    fn test_property_summary_with_std() {
+        let summary = AgsiDataPropertySummary::new("UndrainedShearStrength")
+            .with_range(40.0, 85.0)
+            .with_mean(62.5)
+            .with_std(11.2, 14);
+        assert!(summary.validate().is_ok());
+
+        let json = serde_json::to_value(&summary).unwrap();
+        assert_eq!(json["valueStd"], 11.2);
+        assert_eq!(json["valueCount"], 14);
+
+        let parsed: AgsiDataPropertySummary = serde_json::from_value(json).unwrap();
+        assert_eq!(parsed.value_std, Some(11.2));
+        assert_eq!(parsed.value_count, Some(14));
+        assert_eq!(parsed.value_mean, Some(62.5));
+
+        // Older files without the new fields still load, and don't gain them
+        let plain = AgsiDataPropertySummary::new("Cohesion").with_mean(5.0);
+        let json = serde_json::to_value(&plain).unwrap();
+        assert!(json.get("valueStd").is_none());
+        assert!(json.get("valueCount").is_none());
+
+        let mut negative = AgsiModelElement::new();
+        negative
+            .agsi_data_property_summary
+            .push(AgsiDataPropertySummary::new("Cohesion").with_std(-1.0, 3));
+        assert!(negative.validate().is_err());
+    }
 }
@@ -0,0 +1,165 @@
+//! Shared cache of loaded documents for long-running servers
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, Instant, SystemTime};
+
+use crate::document::Document;
+use crate::error::Result;
+
+/// Default time an entry stays fresh
+pub const DEFAULT_TTL: Duration = Duration::from_secs(60);
+
+/// Default maximum number of cached documents
+pub const DEFAULT_MAX_ENTRIES: usize = 32;
+
+struct CacheEntry {
+    modified: SystemTime,
+    loaded_at: Instant,
+    document: Arc<Document>,
+}
+
+/// Thread-safe cache of parsed JSON documents keyed by path and modification time
+///
+/// A cached document is reused while it is younger than the TTL and the file's
+/// modification time is unchanged. When full, expired entries are dropped
+/// first, then the oldest.
+pub struct DocumentCache {
+    entries: Mutex<HashMap<PathBuf, CacheEntry>>,
+    ttl: Duration,
+    max_entries: usize,
+}
+
+impl DocumentCache {
+    /// Create a cache with the given TTL and size limit
+    pub fn new(ttl: Duration, max_entries: usize) -> Self {
+        Self {
+            entries: Mutex::new(HashMap::new()),
+            ttl,
+            max_entries: max_entries.max(1),
+        }
+    }
+
+    /// Load a document, reusing the cached copy if it is still fresh
+    pub fn load(&self, path: impl AsRef<Path>) -> Result<Arc<Document>> {
+        let path = path.as_ref();
+        let modified = std::fs::metadata(path)?.modified()?;
+
+        if let Some(entry) = self.lock().get(path) {
+            if entry.modified == modified && entry.loaded_at.elapsed() < self.ttl {
+                return Ok(Arc::clone(&entry.document));
+            }
+        }
+
+        // Parse without holding the lock so other paths aren't blocked
+        let document = Arc::new(Document::from_json_file(path)?);
+
+        let mut entries = self.lock();
+        if !entries.contains_key(path) && entries.len() >= self.max_entries {
+            entries.retain(|_, entry| entry.loaded_at.elapsed() < self.ttl);
+            if entries.len() >= self.max_entries {
+                let oldest = entries
+                    .iter()
+                    .min_by_key(|(_, entry)| entry.loaded_at)
+                    .map(|(path, _)| path.clone());
+                if let Some(oldest) = oldest {
+                    entries.remove(&oldest);
+                }
+            }
+        }
+        entries.insert(
+            path.to_path_buf(),
+            CacheEntry {
+                modified,
+                loaded_at: Instant::now(),
+                document: Arc::clone(&document),
+            },
+        );
+
+        Ok(document)
+    }
+
+    /// Drop the cached copy of a document
+    pub fn invalidate(&self, path: impl AsRef<Path>) {
+        self.lock().remove(path.as_ref());
+    }
+
+    /// Drop all cached documents
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    /// Number of cached documents
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Check whether the cache is empty
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HashMap<PathBuf, CacheEntry>> {
+        // A panic elsewhere can't leave an entry half-written, so recover
+        self.entries.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Default for DocumentCache {
+    fn default() -> Self {
+        Self::new(DEFAULT_TTL, DEFAULT_MAX_ENTRIES)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_temp_document(name: &str, file_id: &str) -> PathBuf {
+        let path =
+            std::env::temp_dir().join(format!("agsi-cache-{}-{}.json", name, std::process::id()));
+        Document::new(file_id).to_json_file(&path).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_cache_hit_within_ttl() {
+        let path = write_temp_document("hit", "DOC001");
+        let cache = DocumentCache::default();
+
+        let first = cache.load(&path).unwrap();
+        let second = cache.load(&path).unwrap();
+        assert!(Arc::ptr_eq(&first, &second));
+        assert_eq!(cache.len(), 1);
+
+        // A new modification time forces a reload
+        Document::new("DOC002").to_json_file(&path).unwrap();
+        let file = std::fs::File::options().write(true).open(&path).unwrap();
+        file.set_modified(SystemTime::now() + Duration::from_secs(10))
+            .unwrap();
+        let third = cache.load(&path).unwrap();
+        assert!(!Arc::ptr_eq(&first, &third));
+        assert_eq!(third.ags_file.file_id, "DOC002");
+
+        std::fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn test_cache_expiry_and_size_limit() {
+        let a = write_temp_document("limit-a", "DOC_A");
+        let b = write_temp_document("limit-b", "DOC_B");
+
+        let expired = DocumentCache::new(Duration::ZERO, 4);
+        let first = expired.load(&a).unwrap();
+        assert!(!Arc::ptr_eq(&first, &expired.load(&a).unwrap()));
+
+        let small = DocumentCache::new(DEFAULT_TTL, 1);
+        small.load(&a).unwrap();
+        small.load(&b).unwrap();
+        assert_eq!(small.len(), 1);
+
+        std::fs::remove_file(&a).ok();
+        std::fs::remove_file(&b).ok();
+    }
+}
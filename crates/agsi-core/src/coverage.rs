@@ -0,0 +1,174 @@
+//! Population of optional AGSi fields, as a measure of how complete a file is
+
+use serde::Serialize;
+use serde_json::Value;
+
+/// Optional spec fields checked for each kind of object, keyed by the array
+/// the objects are listed in
+const SPEC_FIELDS: &[(&str, &[&str])] = &[
+    (
+        "agsiModel",
+        &[
+            "modelName",
+            "description",
+            "coordSystemID",
+            "modelType",
+            "category",
+            "domain",
+            "input",
+            "method",
+            "usage",
+            "uncertainty",
+            "documentSetID",
+            "alignmentID",
+            "remarks",
+        ],
+    ),
+    (
+        "agsiModelElement",
+        &[
+            "elementName",
+            "description",
+            "elementType",
+            "geometryObject",
+            "agsiGeometry",
+            "colourRGB",
+            "remarks",
+        ],
+    ),
+    ("materials", &["description", "properties"]),
+    ("components", &["description", "top", "base"]),
+];
+
+/// How many objects of one kind have an optional field populated
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FieldCoverage {
+    /// Array the objects are listed in, e.g. `agsiModel`
+    pub object: String,
+    pub field: String,
+    pub populated: usize,
+    pub total: usize,
+}
+
+impl FieldCoverage {
+    /// Whether the field is populated on every object
+    pub fn is_complete(&self) -> bool {
+        self.populated == self.total
+    }
+}
+
+impl std::fmt::Display for FieldCoverage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let absent = self.total - self.populated;
+        if absent == 0 {
+            write!(
+                f,
+                "{}: populated on all {} {}",
+                self.field, self.total, self.object
+            )
+        } else {
+            write!(
+                f,
+                "{}: absent on {}/{} {}",
+                self.field, absent, self.total, self.object
+            )
+        }
+    }
+}
+
+/// Measure optional field coverage of a serialized document
+///
+/// Works on either the AGSi schema layout or [`crate::Document`]. A field
+/// counts as populated unless it is missing, null, an empty string or an
+/// empty array. Kinds of object that don't appear in the document are left
+/// out of the result.
+pub fn field_coverage(value: &Value) -> Vec<FieldCoverage> {
+    let mut counts: Vec<FieldCoverage> = Vec::new();
+    let mut objects: Vec<(&str, &Value)> = Vec::new();
+    collect_objects(value, &mut objects);
+
+    for (object, fields) in SPEC_FIELDS {
+        let matching: Vec<&Value> = objects
+            .iter()
+            .filter(|(name, _)| name == object)
+            .map(|(_, value)| *value)
+            .collect();
+        if matching.is_empty() {
+            continue;
+        }
+        for field in *fields {
+            counts.push(FieldCoverage {
+                object: object.to_string(),
+                field: field.to_string(),
+                populated: matching
+                    .iter()
+                    .filter(|o| is_populated(field_value(o, field)))
+                    .count(),
+                total: matching.len(),
+            });
+        }
+    }
+
+    counts
+}
+
+/// Gather every object listed in a known array, wherever it is nested
+fn collect_objects<'a>(value: &'a Value, objects: &mut Vec<(&'a str, &'a Value)>) {
+    match value {
+        Value::Object(map) => {
+            for (key, child) in map {
+                let kind = SPEC_FIELDS.iter().find(|(name, _)| name == key);
+                if let (Some((name, _)), Value::Array(items)) = (kind, child) {
+                    objects.extend(
+                        items
+                            .iter()
+                            .filter(|item| item.is_object())
+                            .map(|item| (*name, item)),
+                    );
+                }
+                collect_objects(child, objects);
+            }
+        }
+        Value::Array(items) => items.iter().for_each(|item| collect_objects(item, objects)),
+        _ => {}
+    }
+}
+
+/// Look up a field ignoring case, since `coordSystemID` may be written
+/// `coordSystemId` and so on
+fn field_value<'a>(object: &'a Value, field: &str) -> Option<&'a Value> {
+    object
+        .as_object()?
+        .iter()
+        .find(|(key, _)| key.eq_ignore_ascii_case(field))
+        .map(|(_, value)| value)
+}
+
+fn is_populated(value: Option<&Value>) -> bool {
+    match value {
+        None | Some(Value::Null) => false,
+        Some(Value::String(s)) => !s.is_empty(),
+        Some(Value::Array(items)) => !items.is_empty(),
+        Some(_) => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::agsi_model::AgsiModel;
+
+    #[test]
+    fn test_field_coverage_reports_usage() {
+        let mut root = crate::fixtures::agsi_model_example();
+        root.add_model(AgsiModel::new());
+
+        let coverage = root.field_coverage();
+        let usage = coverage.iter().find(|c| c.field == "usage").unwrap();
+        assert_eq!((usage.populated, usage.total), (1, 2));
+        assert_eq!(usage.to_string(), "usage: absent on 1/2 agsiModel");
+
+        let uncertainty = coverage.iter().find(|c| c.field == "uncertainty").unwrap();
+        assert_eq!(uncertainty.populated, 0);
+    }
+}
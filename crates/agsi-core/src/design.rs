@@ -0,0 +1,155 @@
+//! Eurocode 7 partial factors for deriving design values
+
+use crate::agsi_model::{AgsiModelElement, AgsiParameterCode};
+
+/// Material partial factors (EN 1997-1 Annex A, Table A.4)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PartialFactors {
+    /// Applied to tan φ'
+    pub tan_phi: f64,
+    /// Applied to effective cohesion c'
+    pub cohesion: f64,
+    /// Applied to undrained shear strength cu
+    pub undrained_strength: f64,
+    /// Applied to unconfined strength qu
+    pub unconfined_strength: f64,
+    /// Applied to weight density γ
+    pub unit_weight: f64,
+}
+
+impl PartialFactors {
+    /// Material factor set M1
+    pub const M1: Self = Self {
+        tan_phi: 1.0,
+        cohesion: 1.0,
+        undrained_strength: 1.0,
+        unconfined_strength: 1.0,
+        unit_weight: 1.0,
+    };
+
+    /// Material factor set M2
+    pub const M2: Self = Self {
+        tan_phi: 1.25,
+        cohesion: 1.25,
+        undrained_strength: 1.4,
+        unconfined_strength: 1.4,
+        unit_weight: 1.0,
+    };
+}
+
+/// Standard EC7 design approach combinations
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FactorSet {
+    /// Design Approach 1, Combination 1 (A1 + M1 + R1)
+    Da1Combination1,
+    /// Design Approach 1, Combination 2 (A2 + M2 + R1)
+    Da1Combination2,
+    /// Design Approach 2 (A1 + M1 + R2)
+    Da2,
+    /// Design Approach 3 (A1/A2 + M2 + R3)
+    Da3,
+}
+
+impl FactorSet {
+    /// Material partial factors for this combination
+    pub fn factors(&self) -> PartialFactors {
+        match self {
+            Self::Da1Combination1 | Self::Da2 => PartialFactors::M1,
+            Self::Da1Combination2 | Self::Da3 => PartialFactors::M2,
+        }
+    }
+
+    /// Case ID given to design-value parameters
+    pub fn case_id(&self) -> &'static str {
+        match self {
+            Self::Da1Combination1 => "Design-DA1-1",
+            Self::Da1Combination2 => "Design-DA1-2",
+            Self::Da2 => "Design-DA2",
+            Self::Da3 => "Design-DA3",
+        }
+    }
+}
+
+/// Derive design values for an element's parameters
+///
+/// Strength and weight parameters with numeric values are factored (angles of
+/// friction through tan φ); other parameters are copied unchanged. Every
+/// parameter in the result is tagged with the factor set's case ID.
+pub fn apply_partial_factors(element: &AgsiModelElement, set: FactorSet) -> AgsiModelElement {
+    use AgsiParameterCode as Code;
+
+    let factors = set.factors();
+    let mut design = element.clone();
+
+    for parameter in &mut design.agsi_data_parameter_value {
+        if let (Some(value), Some(code)) = (
+            parameter.value_numeric,
+            AgsiParameterCode::from_code_id(&parameter.code_id),
+        ) {
+            let factored = match code {
+                Code::AngleFriction
+                | Code::AngleFrictionPeak
+                | Code::AngleFrictionCritical
+                | Code::AngleFrictionResidual => Some(
+                    (value.to_radians().tan() / factors.tan_phi)
+                        .atan()
+                        .to_degrees(),
+                ),
+                Code::Cohesion => Some(value / factors.cohesion),
+                Code::UndrainedShearStrength | Code::UndrainedShearStrengthTriaxial => {
+                    Some(value / factors.undrained_strength)
+                }
+                Code::UniaxialCompressiveStrength => Some(value / factors.unconfined_strength),
+                Code::UnitWeightBulk => Some(value / factors.unit_weight),
+                _ => None,
+            };
+            if let Some(factored) = factored {
+                parameter.value_numeric = Some(factored);
+            }
+        }
+        parameter.case_id = Some(set.case_id().to_string());
+    }
+
+    design
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::agsi_model::AgsiDataParameterValue;
+    use AgsiParameterCode as Code;
+
+    #[test]
+    fn test_angle_friction_design_value() {
+        let element = AgsiModelElement::new()
+            .with_name("Dense Sand")
+            .with_parameter(AgsiDataParameterValue::from_standard_code(
+                Code::AngleFriction,
+                35.0,
+            ))
+            .with_parameter(AgsiDataParameterValue::from_standard_code(
+                Code::UndrainedShearStrength,
+                70.0,
+            ))
+            .with_parameter(AgsiDataParameterValue::text("Description", "Dense sand"));
+
+        let design = apply_partial_factors(&element, FactorSet::Da1Combination2);
+        let params = &design.agsi_data_parameter_value;
+
+        let expected = (35.0_f64.to_radians().tan() / 1.25).atan().to_degrees();
+        assert!((params[0].value_numeric.unwrap() - expected).abs() < 1e-9);
+        assert!((params[0].value_numeric.unwrap() - 29.26).abs() < 0.01);
+        assert_eq!(params[1].value_numeric, Some(50.0));
+        assert_eq!(params[2].value_text.as_deref(), Some("Dense sand"));
+        assert!(params
+            .iter()
+            .all(|p| p.case_id.as_deref() == Some("Design-DA1-2")));
+
+        // M1 leaves values unchanged
+        let design = apply_partial_factors(&element, FactorSet::Da1Combination1);
+        assert_eq!(
+            design.agsi_data_parameter_value[0].value_numeric,
+            Some(35.0)
+        );
+    }
+}
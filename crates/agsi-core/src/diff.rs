@@ -0,0 +1,255 @@
+//! Structural comparison of two documents
+
+use serde::Serialize;
+
+use crate::model::GroundModel;
+use crate::Document;
+
+/// A single difference between two documents
+///
+/// Serialized as an object tagged with `kind` (e.g. `"materialAdded"`) and
+/// camelCase fields. Models, materials and components are matched by ID.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(
+    tag = "kind",
+    rename_all = "camelCase",
+    rename_all_fields = "camelCase"
+)]
+pub enum Change {
+    SchemaVersion {
+        from: String,
+        to: String,
+    },
+    FileId {
+        from: String,
+        to: String,
+    },
+    ModelAdded {
+        model_id: String,
+    },
+    ModelRemoved {
+        model_id: String,
+    },
+    ModelRenamed {
+        model_id: String,
+        from: String,
+        to: String,
+    },
+    MaterialAdded {
+        model_id: String,
+        material_id: String,
+    },
+    MaterialRemoved {
+        model_id: String,
+        material_id: String,
+    },
+    MaterialRenamed {
+        model_id: String,
+        material_id: String,
+        from: String,
+        to: String,
+    },
+    MaterialPropertyCount {
+        model_id: String,
+        material_id: String,
+        from: usize,
+        to: usize,
+    },
+    ComponentAdded {
+        model_id: String,
+        component_id: String,
+    },
+    ComponentRemoved {
+        model_id: String,
+        component_id: String,
+    },
+}
+
+/// Differences between an old and a new document, in document order
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct DocumentDiff {
+    pub changes: Vec<Change>,
+}
+
+impl DocumentDiff {
+    /// Compare two documents
+    pub fn between(old: &Document, new: &Document) -> Self {
+        let mut changes = Vec::new();
+
+        if old.ags_schema.version != new.ags_schema.version {
+            changes.push(Change::SchemaVersion {
+                from: old.ags_schema.version.clone(),
+                to: new.ags_schema.version.clone(),
+            });
+        }
+        if old.ags_file.file_id != new.ags_file.file_id {
+            changes.push(Change::FileId {
+                from: old.ags_file.file_id.clone(),
+                to: new.ags_file.file_id.clone(),
+            });
+        }
+
+        for old_model in &old.agsi_model {
+            match new.get_model(&old_model.id) {
+                Some(new_model) => diff_models(old_model, new_model, &mut changes),
+                None => changes.push(Change::ModelRemoved {
+                    model_id: old_model.id.clone(),
+                }),
+            }
+        }
+        for new_model in &new.agsi_model {
+            if old.get_model(&new_model.id).is_none() {
+                changes.push(Change::ModelAdded {
+                    model_id: new_model.id.clone(),
+                });
+            }
+        }
+
+        Self { changes }
+    }
+
+    /// Check whether the documents were found to be the same
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+fn diff_models(old: &GroundModel, new: &GroundModel, changes: &mut Vec<Change>) {
+    let model_id = || old.id.clone();
+
+    if old.name != new.name {
+        changes.push(Change::ModelRenamed {
+            model_id: model_id(),
+            from: old.name.clone(),
+            to: new.name.clone(),
+        });
+    }
+
+    for old_material in &old.materials {
+        let material_id = old_material.id.clone();
+        let Some(new_material) = new.get_material(&old_material.id) else {
+            changes.push(Change::MaterialRemoved {
+                model_id: model_id(),
+                material_id,
+            });
+            continue;
+        };
+        if old_material.name != new_material.name {
+            changes.push(Change::MaterialRenamed {
+                model_id: model_id(),
+                material_id: material_id.clone(),
+                from: old_material.name.clone(),
+                to: new_material.name.clone(),
+            });
+        }
+        if old_material.properties.len() != new_material.properties.len() {
+            changes.push(Change::MaterialPropertyCount {
+                model_id: model_id(),
+                material_id,
+                from: old_material.properties.len(),
+                to: new_material.properties.len(),
+            });
+        }
+    }
+    for new_material in &new.materials {
+        if old.get_material(&new_material.id).is_none() {
+            changes.push(Change::MaterialAdded {
+                model_id: model_id(),
+                material_id: new_material.id.clone(),
+            });
+        }
+    }
+
+    for old_component in &old.components {
+        if new.get_component(&old_component.id).is_none() {
+            changes.push(Change::ComponentRemoved {
+                model_id: model_id(),
+                component_id: old_component.id.clone(),
+            });
+        }
+    }
+    for new_component in &new.components {
+        if old.get_component(&new_component.id).is_none() {
+            changes.push(Change::ComponentAdded {
+                model_id: model_id(),
+                component_id: new_component.id.clone(),
+            });
+        }
+    }
+}
+
+impl std::fmt::Display for Change {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::SchemaVersion { from, to } => write!(f, "Schema version: {} → {}", from, to),
+            Self::FileId { from, to } => write!(f, "File ID: {} → {}", from, to),
+            Self::ModelAdded { model_id } => write!(f, "Model {} added", model_id),
+            Self::ModelRemoved { model_id } => write!(f, "Model {} removed", model_id),
+            Self::ModelRenamed { model_id, from, to } => {
+                write!(f, "Model {} name: {} → {}", model_id, from, to)
+            }
+            Self::MaterialAdded { material_id, .. } => write!(f, "Material {} added", material_id),
+            Self::MaterialRemoved { material_id, .. } => {
+                write!(f, "Material {} removed", material_id)
+            }
+            Self::MaterialRenamed {
+                material_id,
+                from,
+                to,
+                ..
+            } => {
+                write!(f, "Material {} name: {} → {}", material_id, from, to)
+            }
+            Self::MaterialPropertyCount {
+                material_id,
+                from,
+                to,
+                ..
+            } => {
+                write!(f, "Material {} properties: {} → {}", material_id, from, to)
+            }
+            Self::ComponentAdded { component_id, .. } => {
+                write!(f, "Component {} added", component_id)
+            }
+            Self::ComponentRemoved { component_id, .. } => {
+                write!(f, "Component {} removed", component_id)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::material::{Material, MaterialType};
+    use crate::model::{ModelDimension, ModelType};
+
+    #[test]
+    fn test_json_diff_reports_added_material() {
+        let mut old = Document::new("DOC001");
+        old.add_model(GroundModel::new(
+            "MODEL001",
+            "Test Model",
+            ModelType::Stratigraphic,
+            ModelDimension::TwoD,
+        ));
+        let mut new = old.clone();
+        new.get_model_mut("MODEL001")
+            .unwrap()
+            .add_material(Material::new("MAT001", "Clay", MaterialType::Soil));
+
+        let diff = DocumentDiff::between(&old, &new);
+        assert_eq!(diff.changes.len(), 1);
+
+        let json = serde_json::to_value(&diff).unwrap();
+        assert_eq!(
+            json["changes"][0],
+            serde_json::json!({
+                "kind": "materialAdded",
+                "modelId": "MODEL001",
+                "materialId": "MAT001",
+            })
+        );
+        assert!(DocumentDiff::between(&old, &old).is_empty());
+    }
+}
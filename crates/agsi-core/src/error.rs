@@ -24,6 +24,9 @@ pub enum Error {
     #[error("JSON error: {0}")]
     Json(#[from] serde_json::Error),
 
+    #[error("YAML error: {0}")]
+    Yaml(#[from] serde_yaml::Error),
+
     #[error("Invalid schema version: expected {expected}, found {found}")]
     InvalidSchemaVersion { expected: String, found: String },
 
@@ -38,6 +41,9 @@ pub enum Error {
 
     #[error("Material not found: {0}")]
     MaterialNotFound(String),
+
+    #[error("Path not found: {0}")]
+    PathNotFound(String),
 }
 
 impl From<validator::ValidationErrors> for Error {
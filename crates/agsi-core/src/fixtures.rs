@@ -0,0 +1,296 @@
+//! Test fixtures shared between modules, built like the repository examples
+
+use crate::agsi_model::{
+    AgsiDataParameterValue, AgsiModel, AgsiModelBoundary, AgsiModelElement, AgsiParameterCode,
+    AgsiRoot,
+};
+use crate::document::Document;
+use crate::geometry::Geometry;
+use crate::material::{Material, MaterialProperty, MaterialType, PropertySource};
+use crate::model::{
+    ComponentType, GroundModel, ModelComponent, ModelDimension, ModelExtent, ModelType,
+};
+use crate::project::{Location, Project};
+
+/// The root built by `examples/agsi_model_example.rs`: one sitewide model with
+/// five elements, including the cased "River Terrace Deposits" gravel
+pub(crate) fn agsi_model_example() -> AgsiRoot {
+    use AgsiParameterCode::*;
+
+    let standard = |code, value| AgsiDataParameterValue::from_standard_code(code, value);
+    let element = |name: &str, description: &str, parameters: Vec<AgsiDataParameterValue>| {
+        let mut element = AgsiModelElement::new()
+            .with_name(name)
+            .with_description(description);
+        for parameter in parameters {
+            element.add_parameter(parameter);
+        }
+        element
+    };
+
+    let mut root = AgsiRoot::new("Example Geotechnical Project");
+    root.ags_file.title = Some("Ground Model for Example Site".to_string());
+    root.ags_file.description =
+        Some("Geological model based on 2024 site investigation".to_string());
+    root.ags_file.project_country = Some("United Kingdom".to_string());
+
+    let mut model = AgsiModel::new();
+    model.model_name = Some("Sitewide Geological Model".to_string());
+    model.description =
+        Some("3D geological model incorporating all site investigation data".to_string());
+    model.model_type = Some("Geological model".to_string());
+    model.category = Some("Observational".to_string());
+    model.domain = Some("Engineering geology".to_string());
+    model.usage =
+        Some("For reference and visualization. Not suitable for direct use in design.".to_string());
+    model.agsi_model_boundary = Some(AgsiModelBoundary {
+        boundary_id: Some("SiteBoundary".to_string()),
+        description: Some("Site boundary box".to_string()),
+        min_x: Some(520000.0),
+        max_x: Some(521000.0),
+        min_y: Some(180000.0),
+        max_y: Some(181000.0),
+        top_elevation: Some(50.0),
+        bottom_elevation: Some(-30.0),
+        remarks: None,
+    });
+
+    model.add_element(element(
+        "Dense Sand",
+        "Dense, medium to coarse sand with occasional gravel",
+        vec![
+            standard(UnitWeightBulk, 19.0),
+            standard(AngleFriction, 35.0),
+            standard(Cohesion, 0.0),
+            standard(YoungsModulusDrained, 30.0),
+            standard(PoissonsRatio, 0.3),
+        ],
+    ));
+    model.add_element(element(
+        "London Clay",
+        "Stiff to very stiff slightly sandy blue/grey CLAY",
+        vec![
+            standard(UnitWeightBulk, 20.0),
+            standard(UndrainedShearStrength, 100.0),
+            standard(YoungsModulusUndrained, 50.0),
+            standard(PoissonsRatio, 0.5),
+            standard(AngleFriction, 25.0),
+            standard(Cohesion, 5.0),
+            standard(PermeabilityVertical, 1e-9),
+        ],
+    ));
+    model.add_element(element(
+        "Made Ground",
+        "Variable fill material containing brick, concrete, ash and soil",
+        vec![
+            standard(UnitWeightBulk, 18.0),
+            standard(AngleFriction, 30.0),
+            AgsiDataParameterValue::text("MaterialOrigin", "Historical demolition fill"),
+            AgsiDataParameterValue::numeric("EstimatedAge", 50.0),
+            AgsiDataParameterValue::text("ContaminationRisk", "Low to moderate"),
+        ],
+    ));
+    model.add_element(element(
+        "River Terrace Deposits",
+        "Dense sandy gravel",
+        vec![
+            standard(AngleFriction, 38.0).with_case("Characteristic"),
+            standard(AngleFriction, 35.0).with_case("Conservative"),
+            standard(UnitWeightBulk, 20.0),
+        ],
+    ));
+    model.add_element(element(
+        "Aggressive Groundwater Zone",
+        "Zone with aggressive groundwater chemistry",
+        vec![
+            AgsiDataParameterValue::text("ACECClass", "AC-3"),
+            AgsiDataParameterValue::text("ACECDSClass", "DS-2"),
+            AgsiDataParameterValue::numeric("SulphateConcentration", 2400.0),
+        ],
+    ));
+
+    root.add_model(model);
+    root
+}
+
+/// The document built by `examples/3d_model.rs`: the Silvertown model with five
+/// materials and a 1 km square polygon volume for each
+pub(crate) fn silvertown_3d_model() -> Document {
+    let location = Location::new("Silvertown, London").with_coordinates(
+        -0.0249,
+        51.5033,
+        Some("EPSG:4326".into()),
+    );
+    let project = Project::new("SILVER-2024", "Silvertown Tunnel Site Investigation")
+        .with_client("Transport for London")
+        .with_contractor("Ground Investigation Ltd")
+        .with_location(location);
+
+    let mut doc = Document::new("DOC-SILVER-001")
+        .with_file_name("silvertown-3d-model.agsi.json")
+        .with_author("Senior Geotechnical Engineer")
+        .with_project(project);
+
+    let materials = [
+        Material::new("MAT001", "Made Ground", MaterialType::MadeGround)
+            .with_description(
+                "Heterogeneous fill comprising brick, concrete fragments, sand and gravel",
+            )
+            .with_property(
+                MaterialProperty::range("bulk_density", 1700.0, 1900.0, Some("kg/m3".into()))
+                    .with_source(PropertySource::Estimated),
+            )
+            .with_property(MaterialProperty::text(
+                "consistency",
+                "Loose to medium dense",
+            )),
+        Material::new("MAT002", "Alluvium", MaterialType::Soil)
+            .with_description("Soft to firm, silty clay with occasional peat lenses")
+            .with_property(
+                MaterialProperty::numeric("undrained_shear_strength", 35.0, Some("kPa".into()))
+                    .with_source(PropertySource::Tested)
+                    .with_method("Triaxial UU"),
+            )
+            .with_property(
+                MaterialProperty::range("water_content", 25.0, 45.0, Some("%".into()))
+                    .with_source(PropertySource::Tested),
+            )
+            .with_property(MaterialProperty::numeric(
+                "plasticity_index",
+                28.0,
+                Some("%".into()),
+            )),
+        Material::new("MAT003", "River Terrace Deposits", MaterialType::Soil)
+            .with_description("Dense, sandy GRAVEL with occasional cobbles")
+            .with_property(
+                MaterialProperty::numeric("spt_n", 42.0, Some("blows/300mm".into()))
+                    .with_source(PropertySource::Tested),
+            )
+            .with_property(
+                MaterialProperty::numeric("friction_angle", 38.0, Some("degrees".into()))
+                    .with_source(PropertySource::Estimated),
+            )
+            .with_property(
+                MaterialProperty::numeric("permeability", 1e-4, Some("m/s".into()))
+                    .with_source(PropertySource::Tested),
+            ),
+        Material::new("MAT004", "London Clay", MaterialType::Soil)
+            .with_description("Very stiff to hard, fissured, grey-brown CLAY")
+            .with_property(
+                MaterialProperty::numeric("undrained_shear_strength", 150.0, Some("kPa".into()))
+                    .with_source(PropertySource::Tested)
+                    .with_method("Triaxial UU"),
+            )
+            .with_property(MaterialProperty::range(
+                "plasticity_index",
+                40.0,
+                55.0,
+                Some("%".into()),
+            ))
+            .with_property(
+                MaterialProperty::numeric("bulk_density", 2050.0, Some("kg/m3".into()))
+                    .with_source(PropertySource::Tested),
+            )
+            .with_property(
+                MaterialProperty::numeric("cohesion", 15.0, Some("kPa".into()))
+                    .with_source(PropertySource::Calculated),
+            ),
+        Material::new("MAT005", "Thanet Sand", MaterialType::Soil)
+            .with_description("Dense to very dense, fine to medium SAND")
+            .with_property(
+                MaterialProperty::numeric("spt_n", 55.0, Some("blows/300mm".into()))
+                    .with_source(PropertySource::Tested),
+            )
+            .with_property(MaterialProperty::numeric(
+                "friction_angle",
+                40.0,
+                Some("degrees".into()),
+            )),
+    ];
+
+    let mut model = GroundModel::new(
+        "MODEL-3D-001",
+        "Silvertown 3D Geological Model",
+        ModelType::Stratigraphic,
+        ModelDimension::ThreeD,
+    )
+    .with_crs("EPSG:27700")
+    .with_extent(ModelExtent::new_3d(
+        540000.0, 541000.0, 179000.0, 180000.0, -50.0, 5.0,
+    ));
+    for material in materials {
+        model.add_material(material);
+    }
+
+    let volumes = [
+        (
+            "VOL001",
+            "Made Ground Volume",
+            "MAT001",
+            5.0,
+            4.5,
+            2.0,
+            "medium",
+        ),
+        (
+            "VOL002",
+            "Alluvium Volume",
+            "MAT002",
+            2.0,
+            1.5,
+            -5.0,
+            "high",
+        ),
+        (
+            "VOL003",
+            "River Terrace Gravel Volume",
+            "MAT003",
+            -5.0,
+            -6.0,
+            -12.0,
+            "high",
+        ),
+        (
+            "VOL004",
+            "London Clay Formation",
+            "MAT004",
+            -12.0,
+            -13.0,
+            -45.0,
+            "high",
+        ),
+        (
+            "VOL005",
+            "Thanet Sand",
+            "MAT005",
+            -45.0,
+            -46.0,
+            -50.0,
+            "medium",
+        ),
+    ];
+    for (id, name, material_id, top, north, bottom, confidence) in volumes {
+        let geometry = Geometry::polygon(
+            vec![
+                [540000.0, 179000.0, top],
+                [541000.0, 179000.0, top],
+                [541000.0, 180000.0, north],
+                [540000.0, 180000.0, north],
+                [540000.0, 179000.0, top],
+            ],
+            vec![],
+        )
+        .unwrap();
+        let mut component =
+            ModelComponent::new(id, name, ComponentType::Volume, material_id, geometry)
+                .with_elevations(top, bottom)
+                .with_attribute("confidence", confidence);
+        if id == "VOL001" {
+            component = component.with_attribute("data_density", "high");
+        }
+        model.add_component(component);
+    }
+
+    doc.add_model(model);
+    doc
+}
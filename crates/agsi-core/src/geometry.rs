@@ -1,4 +1,4 @@
-use geo_types::{Coord, LineString, Point, Polygon};
+use geo_types::{Coord, Line, LineString, Point, Polygon};
 use serde::{Deserialize, Serialize};
 use wkt::ToWkt;
 
@@ -20,6 +20,9 @@ pub enum Geometry {
     /// Polyline geometry (1D/2D)
     LineString {
         coordinates: Vec<[f64; 3]>,
+        /// Optional measure (M) per coordinate, e.g. chainage along an alignment
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        measures: Option<Vec<f64>>,
         #[serde(skip_serializing_if = "Option::is_none")]
         crs: Option<String>,
         /// WKT representation (embedded as text)
@@ -47,7 +50,12 @@ pub enum Geometry {
     /// Surface geometry (3D) - stored as OBJ format
     Surface {
         /// OBJ file content (embedded as base64 binary)
+        #[serde(default)]
         obj_data: String,
+        /// Path to an external OBJ file, relative to the document, used when
+        /// `obj_data` is empty
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        obj_ref: Option<String>,
         #[serde(skip_serializing_if = "Option::is_none")]
         crs: Option<String>,
         /// Optional metadata about the surface
@@ -73,13 +81,32 @@ pub struct SurfaceMetadata {
 }
 
 /// 3D Bounding box
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct BoundingBox {
     pub min: [f64; 3],
     pub max: [f64; 3],
 }
 
 impl Geometry {
+    /// Placeholder for components whose real geometry hasn't been supplied yet
+    ///
+    /// Represented as an empty collection so it can't be mistaken for a real
+    /// location. Use [`Geometry::is_placeholder`] to detect it.
+    pub const PLACEHOLDER: Geometry = Geometry::Collection {
+        geometries: Vec::new(),
+        crs: None,
+    };
+
+    /// Create a placeholder geometry
+    pub fn placeholder() -> Self {
+        Self::PLACEHOLDER
+    }
+
+    /// Check whether this is a placeholder (empty) geometry
+    pub fn is_placeholder(&self) -> bool {
+        matches!(self, Self::Collection { geometries, .. } if geometries.is_empty())
+    }
+
     /// Create a point geometry
     pub fn point(x: f64, y: f64, z: f64) -> Self {
         Self::Point {
@@ -98,12 +125,41 @@ impl Geometry {
 
         Ok(Self::LineString {
             coordinates: coords,
+            measures: None,
             crs: None,
             wkt: None,
             wkb: None,
         })
     }
 
+    /// Attach a measure (M) value to each coordinate of a linestring
+    ///
+    /// There must be exactly one measure per coordinate. Other geometry types
+    /// have no measures and are rejected.
+    pub fn with_measures(mut self, values: Vec<f64>) -> Result<Self> {
+        match &mut self {
+            Self::LineString {
+                coordinates,
+                measures,
+                ..
+            } => {
+                if values.len() != coordinates.len() {
+                    return Err(Error::Geometry(format!(
+                        "LineString has {} coordinates but {} measures",
+                        coordinates.len(),
+                        values.len()
+                    )));
+                }
+                *measures = Some(values);
+                Ok(self)
+            }
+            _ => Err(Error::Geometry(format!(
+                "{} cannot carry measures",
+                self.type_name()
+            ))),
+        }
+    }
+
     /// Create a polygon from rings
     pub fn polygon(exterior: Vec<[f64; 3]>, interiors: Vec<Vec<[f64; 3]>>) -> Result<Self> {
         if exterior.len() < 3 {
@@ -128,11 +184,130 @@ impl Geometry {
         use base64::{engine::general_purpose, Engine as _};
         Self::Surface {
             obj_data: general_purpose::STANDARD.encode(&obj_data),
+            obj_ref: None,
+            crs: None,
+            metadata,
+        }
+    }
+
+    /// Create a surface that references an external OBJ file
+    ///
+    /// The path is resolved relative to the document's directory, see
+    /// [`crate::Document::from_json_file_with_base`].
+    pub fn surface_ref(path: impl Into<String>, metadata: Option<SurfaceMetadata>) -> Self {
+        Self::Surface {
+            obj_data: String::new(),
+            obj_ref: Some(path.into()),
             crs: None,
             metadata,
         }
     }
 
+    /// Get the raw OBJ content of a surface
+    ///
+    /// Embedded data is decoded directly; a reference is read from disk,
+    /// relative to `base_dir` when given. References that are absolute or
+    /// climb out of the directory with `..` are refused.
+    pub fn obj_bytes(&self, base_dir: Option<&std::path::Path>) -> Result<Vec<u8>> {
+        use base64::{engine::general_purpose, Engine as _};
+
+        match self {
+            Self::Surface { obj_data, .. } if !obj_data.is_empty() => general_purpose::STANDARD
+                .decode(obj_data)
+                .map_err(|e| Error::Geometry(format!("Invalid OBJ data: {}", e))),
+            Self::Surface {
+                obj_ref: Some(path),
+                ..
+            } => {
+                let resolved =
+                    crate::document::contained_path(base_dir, path).ok_or_else(|| {
+                        Error::Geometry(format!(
+                            "OBJ reference '{}' points outside the document directory",
+                            path
+                        ))
+                    })?;
+                Ok(std::fs::read(resolved)?)
+            }
+            Self::Surface { .. } => Err(Error::Geometry("Surface has no OBJ data".to_string())),
+            _ => Err(Error::Geometry(format!(
+                "{} is not a surface",
+                self.type_name()
+            ))),
+        }
+    }
+
+    /// Replace the embedded OBJ content of a surface
+    ///
+    /// Clears any bounds recorded by [`Geometry::cached_bounding_box`] so they
+    /// are recomputed from the new mesh. Other geometry types are unchanged.
+    pub fn set_obj_data(&mut self, data: Vec<u8>) {
+        use base64::{engine::general_purpose, Engine as _};
+
+        if let Self::Surface {
+            obj_data,
+            obj_ref,
+            metadata,
+            ..
+        } = self
+        {
+            *obj_data = general_purpose::STANDARD.encode(&data);
+            *obj_ref = None;
+            if let Some(meta) = metadata {
+                meta.bounds = None;
+            }
+        }
+    }
+
+    /// Get the 3D bounding box, storing it in surface metadata once computed
+    ///
+    /// Surfaces without recorded bounds have their OBJ vertices scanned and
+    /// the result saved in [`SurfaceMetadata`], so later calls (including
+    /// [`Geometry::bounding_box`]) don't touch the mesh again. Referenced OBJ
+    /// files are read relative to `base_dir`, as for [`Geometry::obj_bytes`].
+    /// Other geometry types hold their coordinates in memory and are computed
+    /// directly.
+    pub fn cached_bounding_box(
+        &mut self,
+        base_dir: Option<&std::path::Path>,
+    ) -> Option<BoundingBox> {
+        match self {
+            Self::Surface {
+                metadata:
+                    Some(SurfaceMetadata {
+                        bounds: Some(bounds),
+                        ..
+                    }),
+                ..
+            } => Some(bounds.clone()),
+            Self::Surface { .. } => {
+                let obj = self.obj_bytes(base_dir).ok()?;
+                let stats = obj_stats(&obj);
+                let Self::Surface { metadata, .. } = self else {
+                    unreachable!()
+                };
+                let bounds = stats.bounds.clone();
+                *metadata = Some(stats);
+                bounds
+            }
+            Self::Collection { geometries, .. } => geometries
+                .iter_mut()
+                .filter_map(|g| g.cached_bounding_box(base_dir))
+                .reduce(|a, b| BoundingBox {
+                    min: [
+                        a.min[0].min(b.min[0]),
+                        a.min[1].min(b.min[1]),
+                        a.min[2].min(b.min[2]),
+                    ],
+                    max: [
+                        a.max[0].max(b.max[0]),
+                        a.max[1].max(b.max[1]),
+                        a.max[2].max(b.max[2]),
+                    ],
+                }),
+            _ => self.bounding_box(),
+        }
+    }
+
     /// Convert to WKT representation (for 1D/2D geometries)
     pub fn to_wkt(&self) -> Result<String> {
         match self {
@@ -140,6 +315,26 @@ impl Geometry {
                 let point = Point::new(coordinates[0], coordinates[1]);
                 Ok(point.to_wkt().to_string())
             }
+            Self::LineString {
+                coordinates,
+                measures: Some(measures),
+                ..
+            } => {
+                if measures.len() != coordinates.len() {
+                    return Err(Error::Geometry(format!(
+                        "LineString has {} coordinates but {} measures",
+                        coordinates.len(),
+                        measures.len()
+                    )));
+                }
+                // geo-types has no M dimension, so write ZM text directly
+                let points: Vec<String> = coordinates
+                    .iter()
+                    .zip(measures)
+                    .map(|(c, m)| format!("{} {} {} {}", c[0], c[1], c[2], m))
+                    .collect();
+                Ok(format!("LINESTRING ZM({})", points.join(",")))
+            }
             Self::LineString { coordinates, .. } => {
                 let coords: Vec<Coord<f64>> = coordinates
                     .iter()
@@ -152,6 +347,13 @@ impl Geometry {
                 if rings.is_empty() {
                     return Err(Error::Geometry("Empty polygon".to_string()));
                 }
+                // A closed triangle is the smallest valid exterior ring
+                if rings[0].len() < 4 {
+                    return Err(Error::Geometry(format!(
+                        "Polygon exterior ring has {} coordinates, expected at least 4",
+                        rings[0].len()
+                    )));
+                }
 
                 let exterior: Vec<Coord<f64>> = rings[0]
                     .iter()
@@ -171,11 +373,382 @@ impl Geometry {
                 let polygon = Polygon::new(exterior_ring, interior_rings);
                 Ok(polygon.to_wkt().to_string())
             }
-            Self::Surface { .. } => {
-                Err(Error::Geometry("Surface geometry cannot be converted to WKT".to_string()))
+            Self::Surface { .. } => Err(Error::Geometry(
+                "Surface geometry cannot be converted to WKT".to_string(),
+            )),
+            Self::Collection { .. } => Err(Error::Geometry(
+                "Collection geometry not yet supported for WKT".to_string(),
+            )),
+        }
+    }
+
+    /// Parse a POINT, LINESTRING or POLYGON from WKT
+    ///
+    /// 2D input gets a Z of 0.0. A LINESTRING with M values keeps them as
+    /// measures. Multi-geometries and collections are rejected.
+    pub fn from_wkt(s: &str) -> Result<Self> {
+        let parsed: wkt::Wkt<f64> = s
+            .parse()
+            .map_err(|e| Error::Geometry(format!("Invalid WKT: {}", e)))?;
+        let to_xyz = |c: &wkt::types::Coord<f64>| [c.x, c.y, c.z.unwrap_or(0.0)];
+
+        match parsed {
+            wkt::Wkt::Point(wkt::types::Point(Some(coord))) => {
+                let [x, y, z] = to_xyz(&coord);
+                Ok(Self::point(x, y, z))
+            }
+            wkt::Wkt::Point(wkt::types::Point(None)) => Err(Error::Geometry(
+                "Empty POINT has no coordinates".to_string(),
+            )),
+            wkt::Wkt::LineString(wkt::types::LineString(coords)) => {
+                let line = Self::linestring(coords.iter().map(to_xyz).collect())?;
+                match coords.iter().map(|c| c.m).collect::<Option<Vec<f64>>>() {
+                    Some(measures) if !measures.is_empty() => line.with_measures(measures),
+                    _ => Ok(line),
+                }
+            }
+            wkt::Wkt::Polygon(wkt::types::Polygon(rings)) => {
+                let mut rings = rings
+                    .iter()
+                    .map(|ring| ring.0.iter().map(to_xyz).collect::<Vec<_>>());
+                let exterior = rings
+                    .next()
+                    .ok_or_else(|| Error::Geometry("Empty POLYGON has no rings".to_string()))?;
+                Self::polygon(exterior, rings.collect())
+            }
+            other => {
+                let name = match other {
+                    wkt::Wkt::MultiPoint(_) => "MULTIPOINT",
+                    wkt::Wkt::MultiLineString(_) => "MULTILINESTRING",
+                    wkt::Wkt::MultiPolygon(_) => "MULTIPOLYGON",
+                    _ => "GEOMETRYCOLLECTION",
+                };
+                Err(Error::Geometry(format!(
+                    "Unsupported WKT type {}; expected POINT, LINESTRING or POLYGON",
+                    name
+                )))
+            }
+        }
+    }
+
+    /// Get every coordinate in the geometry, including collection members
+    ///
+    /// Surfaces store their vertices as OBJ data and contribute nothing.
+    pub fn coordinates(&self) -> Vec<&[f64; 3]> {
+        match self {
+            Self::Point { coordinates, .. } => vec![coordinates],
+            Self::LineString { coordinates, .. } => coordinates.iter().collect(),
+            Self::Polygon { rings, .. } => rings.iter().flatten().collect(),
+            Self::Surface { .. } => Vec::new(),
+            Self::Collection { geometries, .. } => {
+                geometries.iter().flat_map(|g| g.coordinates()).collect()
+            }
+        }
+    }
+
+    /// Check that every coordinate is finite (not NaN or infinite)
+    pub fn is_finite(&self) -> bool {
+        let finite = |c: &[f64; 3]| c.iter().all(|v| v.is_finite());
+        match self {
+            Self::Point { coordinates, .. } => finite(coordinates),
+            Self::LineString { coordinates, .. } => coordinates.iter().all(finite),
+            Self::Polygon { rings, .. } => rings.iter().flatten().all(finite),
+            Self::Surface { .. } => true,
+            Self::Collection { geometries, .. } => geometries.iter().all(|g| g.is_finite()),
+        }
+    }
+
+    /// Get the name of the geometry type (e.g. "Polygon")
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Self::Point { .. } => "Point",
+            Self::LineString { .. } => "LineString",
+            Self::Polygon { .. } => "Polygon",
+            Self::Surface { .. } => "Surface",
+            Self::Collection { .. } => "Collection",
+        }
+    }
+
+    /// Check whether a plan (XY) location lies strictly inside the geometry
+    ///
+    /// Polygons use ray casting against the exterior ring and exclude points
+    /// inside holes; collections match if any member does. Points, lines and
+    /// surfaces have no plan area and never contain a location. Whether a
+    /// location exactly on a boundary is inside is unspecified.
+    pub fn contains_point(&self, x: f64, y: f64) -> bool {
+        match self {
+            Self::Polygon { rings, .. } => match rings.split_first() {
+                Some((exterior, holes)) => {
+                    ring_contains(exterior, x, y)
+                        && !holes.iter().any(|hole| ring_contains(hole, x, y))
+                }
+                None => false,
+            },
+            Self::Collection { geometries, .. } => {
+                geometries.iter().any(|g| g.contains_point(x, y))
+            }
+            _ => false,
+        }
+    }
+
+    /// Get the plan (XY) area of the geometry
+    ///
+    /// Ring areas are summed with their winding, so interior rings wound
+    /// against the exterior ring (holes) subtract and rings wound with it
+    /// add. Collections sum their members; other geometry types have no
+    /// plan area and return `0.0`.
+    pub fn area_2d(&self) -> f64 {
+        match self {
+            Self::Polygon { rings, .. } => rings
+                .iter()
+                .map(|ring| signed_area(ring))
+                .sum::<f64>()
+                .abs(),
+            Self::Collection { geometries, .. } => geometries.iter().map(|g| g.area_2d()).sum(),
+            _ => 0.0,
+        }
+    }
+
+    /// Get the plan (XY) area of a polygon, in squared coordinate units
+    ///
+    /// Unlike [`Geometry::area_2d`] winding is ignored: every interior ring is
+    /// taken to be a hole and subtracted from the exterior. Other geometry
+    /// types are an error.
+    pub fn area(&self) -> Result<f64> {
+        match self {
+            Self::Polygon { rings, .. } => {
+                let exterior = rings
+                    .first()
+                    .ok_or_else(|| Error::Geometry("Empty polygon".to_string()))?;
+                let holes: f64 = rings[1..].iter().map(|ring| signed_area(ring).abs()).sum();
+                Ok(signed_area(exterior).abs() - holes)
+            }
+            other => Err(Error::Geometry(format!(
+                "Area is only defined for Polygon geometry, not {}",
+                other.type_name()
+            ))),
+        }
+    }
+
+    /// Get the plan (XY) length of a linestring, in coordinate units
+    ///
+    /// Sums the straight segments between consecutive points. Other geometry
+    /// types are an error.
+    pub fn length(&self) -> Result<f64> {
+        match self {
+            Self::LineString { coordinates, .. } => Ok(coordinates
+                .windows(2)
+                .map(|pair| (pair[1][0] - pair[0][0]).hypot(pair[1][1] - pair[0][1]))
+                .sum()),
+            other => Err(Error::Geometry(format!(
+                "Length is only defined for LineString geometry, not {}",
+                other.type_name()
+            ))),
+        }
+    }
+
+    /// Check whether a polygon ring is a hole, judged by its winding
+    ///
+    /// A ring is a hole when it is wound opposite to the exterior ring, so
+    /// the exterior ring itself is never one. Returns `None` for other
+    /// geometry types, a missing ring, or when either ring has no area.
+    pub fn ring_is_hole(&self, ring_index: usize) -> Option<bool> {
+        let Self::Polygon { rings, .. } = self else {
+            return None;
+        };
+        let exterior = signed_area(rings.first()?);
+        let ring = signed_area(rings.get(ring_index)?);
+        if exterior == 0.0 || ring == 0.0 {
+            return None;
+        }
+        Some(exterior.signum() != ring.signum())
+    }
+
+    /// Simplify lines and polygon rings to about `target_points` vertices each
+    ///
+    /// Uses Visvalingam-Whyatt: the vertex forming the smallest plan triangle
+    /// with its neighbours is removed until the target is reached. Line end
+    /// points are kept and rings stay closed, so a ring never drops below a
+    /// closed triangle (4 points) and a line below 2. Measures are thinned
+    /// with their line, or dropped when their count doesn't match the
+    /// coordinates. Points and surfaces are returned unchanged; collections
+    /// are simplified member by member.
+    ///
+    /// A vertex is only removed if the shortcut between its neighbours
+    /// crosses no other edge of the line or ring, nor of the polygon's other
+    /// rings, so the result never self-intersects. When every remaining
+    /// removal would cross, simplification stops short of the target.
+    pub fn simplify_vw(&self, target_points: usize) -> Geometry {
+        let mut simplified = self.clone();
+        match &mut simplified {
+            Self::LineString {
+                coordinates,
+                measures,
+                wkt,
+                wkb,
+                ..
+            } => {
+                let kept = vw_keep(coordinates, target_points.max(2), false, &[]);
+                // Measures that don't pair up with the coordinates can't be thinned
+                // alongside them, so they are dropped
+                *measures = measures
+                    .take()
+                    .filter(|values| values.len() == coordinates.len())
+                    .map(|values| kept.iter().map(|&i| values[i]).collect());
+                *coordinates = kept.iter().map(|&i| coordinates[i]).collect();
+                *wkt = None;
+                *wkb = None;
+            }
+            Self::Polygon {
+                rings, wkt, wkb, ..
+            } => {
+                for idx in 0..rings.len() {
+                    // Rings already simplified are checked in their new form
+                    let others: Vec<Line<f64>> = rings
+                        .iter()
+                        .enumerate()
+                        .filter(|(other, _)| *other != idx)
+                        .flat_map(|(_, ring)| ring_edges(ring))
+                        .collect();
+                    let ring = &mut rings[idx];
+                    let closed = ring.len() > 1 && ring.first() == ring.last();
+                    let open = if closed {
+                        &ring[..ring.len() - 1]
+                    } else {
+                        &ring[..]
+                    };
+                    // The closing point counts towards the target
+                    let kept = vw_keep(open, target_points.max(4) - 1, true, &others);
+                    let mut simplified: Vec<[f64; 3]> = kept.iter().map(|&i| open[i]).collect();
+                    if let Some(&first) = simplified.first() {
+                        simplified.push(first);
+                    }
+                    *ring = simplified;
+                }
+                *wkt = None;
+                *wkb = None;
             }
-            Self::Collection { .. } => {
-                Err(Error::Geometry("Collection geometry not yet supported for WKT".to_string()))
+            Self::Collection { geometries, .. } => {
+                for member in geometries.iter_mut() {
+                    *member = member.simplify_vw(target_points);
+                }
+            }
+            Self::Point { .. } | Self::Surface { .. } => {}
+        }
+        simplified
+    }
+
+    /// Get the plan (XY) polygons of the geometry, dropping elevations
+    ///
+    /// Collections contribute the polygons of their members; other geometry
+    /// types have no plan area and return nothing.
+    pub fn plan_polygons(&self) -> Vec<Polygon<f64>> {
+        match self {
+            Self::Polygon { rings, .. } => {
+                let Some((exterior, interiors)) = rings.split_first() else {
+                    return Vec::new();
+                };
+                let to_ring = |ring: &Vec<[f64; 3]>| {
+                    LineString::from(
+                        ring.iter()
+                            .map(|c| Coord { x: c[0], y: c[1] })
+                            .collect::<Vec<_>>(),
+                    )
+                };
+                vec![Polygon::new(
+                    to_ring(exterior),
+                    interiors.iter().map(to_ring).collect(),
+                )]
+            }
+            Self::Collection { geometries, .. } => {
+                geometries.iter().flat_map(|g| g.plan_polygons()).collect()
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    /// Get the plan bounding box as `[min_x, min_y, max_x, max_y]`
+    ///
+    /// Returns `None` for surfaces and geometries without coordinates.
+    pub fn bounds_xy(&self) -> Option<[f64; 4]> {
+        let merge = |a: Option<[f64; 4]>, b: [f64; 4]| match a {
+            Some(a) => Some([
+                a[0].min(b[0]),
+                a[1].min(b[1]),
+                a[2].max(b[2]),
+                a[3].max(b[3]),
+            ]),
+            None => Some(b),
+        };
+        let from_coords = |coords: &mut dyn Iterator<Item = &[f64; 3]>| {
+            coords.fold(None, |acc, c| merge(acc, [c[0], c[1], c[0], c[1]]))
+        };
+
+        match self {
+            Self::Point { coordinates, .. } => from_coords(&mut std::iter::once(coordinates)),
+            Self::LineString { coordinates, .. } => from_coords(&mut coordinates.iter()),
+            Self::Polygon { rings, .. } => from_coords(&mut rings.iter().flatten()),
+            Self::Surface { .. } => None,
+            Self::Collection { geometries, .. } => geometries
+                .iter()
+                .filter_map(|g| g.bounds_xy())
+                .fold(None, merge),
+        }
+    }
+
+    /// Get the 3D bounding box of the geometry
+    ///
+    /// Surfaces use the bounds recorded in their metadata, if any. Returns
+    /// `None` for geometries without coordinates.
+    pub fn bounding_box(&self) -> Option<BoundingBox> {
+        let merge = |a: Option<BoundingBox>, b: BoundingBox| match a {
+            Some(a) => Some(BoundingBox {
+                min: [
+                    a.min[0].min(b.min[0]),
+                    a.min[1].min(b.min[1]),
+                    a.min[2].min(b.min[2]),
+                ],
+                max: [
+                    a.max[0].max(b.max[0]),
+                    a.max[1].max(b.max[1]),
+                    a.max[2].max(b.max[2]),
+                ],
+            }),
+            None => Some(b),
+        };
+
+        match self {
+            Self::Surface { metadata, .. } => metadata.as_ref().and_then(|m| m.bounds.clone()),
+            Self::Collection { geometries, .. } => geometries
+                .iter()
+                .filter_map(|g| g.bounding_box())
+                .fold(None, merge),
+            _ => self
+                .coordinates()
+                .into_iter()
+                .fold(None, |acc, c| merge(acc, BoundingBox { min: *c, max: *c })),
+        }
+    }
+
+    /// Convert to a GeoJSON geometry object
+    ///
+    /// Collections become `GeometryCollection`s. Surfaces have no GeoJSON
+    /// equivalent and return `None`.
+    pub fn to_geojson(&self) -> Option<serde_json::Value> {
+        use serde_json::json;
+
+        match self {
+            Self::Point { coordinates, .. } => {
+                Some(json!({ "type": "Point", "coordinates": coordinates }))
+            }
+            Self::LineString { coordinates, .. } => {
+                Some(json!({ "type": "LineString", "coordinates": coordinates }))
+            }
+            Self::Polygon { rings, .. } => Some(json!({ "type": "Polygon", "coordinates": rings })),
+            Self::Surface { .. } => None,
+            Self::Collection { geometries, .. } => {
+                let geometries: Vec<_> = geometries.iter().filter_map(|g| g.to_geojson()).collect();
+                Some(json!({ "type": "GeometryCollection", "geometries": geometries }))
             }
         }
     }
@@ -204,6 +777,39 @@ impl Geometry {
         self
     }
 
+    /// Round every coordinate to a number of decimal places
+    ///
+    /// Embedded WKT and WKB copies are dropped since they would no longer
+    /// match; surfaces are left alone as their vertices live in OBJ data.
+    pub fn round_coordinates(&mut self, places: u32) {
+        let scale = 10f64.powi(places as i32);
+        let round = |c: &mut [f64; 3]| c.iter_mut().for_each(|v| *v = (*v * scale).round() / scale);
+        match self {
+            Self::Point { coordinates, .. } => round(coordinates),
+            Self::LineString {
+                coordinates,
+                wkt,
+                wkb,
+                ..
+            } => {
+                coordinates.iter_mut().for_each(round);
+                (*wkt, *wkb) = (None, None);
+            }
+            Self::Polygon {
+                rings, wkt, wkb, ..
+            } => {
+                rings.iter_mut().flatten().for_each(round);
+                (*wkt, *wkb) = (None, None);
+            }
+            Self::Surface { .. } => {}
+            Self::Collection { geometries, .. } => {
+                geometries
+                    .iter_mut()
+                    .for_each(|g| g.round_coordinates(places));
+            }
+        }
+    }
+
     /// Compute WKT and WKB representations (for 1D/2D geometries)
     pub fn compute_wkt_wkb(&mut self) -> Result<()> {
         // First compute WKT from self
@@ -227,6 +833,200 @@ impl Geometry {
     }
 }
 
+/// Count the vertices and faces of OBJ content and find the vertex bounds
+fn obj_stats(obj: &[u8]) -> SurfaceMetadata {
+    let mut stats = SurfaceMetadata {
+        vertex_count: 0,
+        face_count: 0,
+        bounds: None,
+    };
+    for line in String::from_utf8_lossy(obj).lines() {
+        let mut parts = line.split_whitespace();
+        match parts.next() {
+            Some("v") => {
+                // Skip malformed vertices rather than placing them at the origin
+                let values: Vec<f64> = parts.take(3).map_while(|part| part.parse().ok()).collect();
+                let Ok(c) = <[f64; 3]>::try_from(values) else {
+                    continue;
+                };
+                stats.vertex_count += 1;
+                stats.bounds = Some(match stats.bounds {
+                    Some(b) => BoundingBox {
+                        min: [b.min[0].min(c[0]), b.min[1].min(c[1]), b.min[2].min(c[2])],
+                        max: [b.max[0].max(c[0]), b.max[1].max(c[1]), b.max[2].max(c[2])],
+                    },
+                    None => BoundingBox { min: c, max: c },
+                });
+            }
+            Some("f") => stats.face_count += 1,
+            _ => {}
+        }
+    }
+    stats
+}
+
+/// Indices of the vertices Visvalingam-Whyatt keeps to reach `target` vertices
+///
+/// With `cyclic` the vertices form an open ring whose last vertex neighbours
+/// the first; otherwise the two end vertices are always kept. A vertex is
+/// skipped if the shortcut left by removing it would cross another kept edge
+/// or one of `obstacles`; if every candidate is skipped, fewer vertices are
+/// removed than asked.
+fn vw_keep(
+    coords: &[[f64; 3]],
+    target: usize,
+    cyclic: bool,
+    obstacles: &[Line<f64>],
+) -> Vec<usize> {
+    use geo::Intersects;
+
+    let mut kept: Vec<usize> = (0..coords.len()).collect();
+    let triangle = |a: &[f64; 3], b: &[f64; 3], c: &[f64; 3]| {
+        ((b[0] - a[0]) * (c[1] - a[1]) - (c[0] - a[0]) * (b[1] - a[1])).abs() / 2.0
+    };
+    let plan = |c: &[f64; 3]| Coord { x: c[0], y: c[1] };
+
+    while kept.len() > target {
+        let n = kept.len();
+        let edges = if cyclic { n } else { n - 1 };
+        let candidates = if cyclic { 0..n } else { 1..n - 1 };
+        let mut by_area: Vec<(usize, f64)> = candidates
+            .map(|i| {
+                let prev = kept[(i + n - 1) % n];
+                let next = kept[(i + 1) % n];
+                (i, triangle(&coords[prev], &coords[kept[i]], &coords[next]))
+            })
+            .collect();
+        by_area.sort_by(|a, b| a.1.total_cmp(&b.1));
+
+        let removable = by_area.into_iter().map(|(i, _)| i).find(|&i| {
+            let (prev, next) = ((i + n - 1) % n, (i + 1) % n);
+            let shortcut = Line::new(plan(&coords[kept[prev]]), plan(&coords[kept[next]]));
+            // Edge `j` runs from kept vertex `j` to the one after; skip the two
+            // edges being replaced and the two meeting the shortcut's ends
+            let crosses_kept = (0..edges)
+                .filter(|&j| j != prev && j != i && j != next && (j + 1) % n != prev)
+                .any(|j| {
+                    let edge = Line::new(plan(&coords[kept[j]]), plan(&coords[kept[(j + 1) % n]]));
+                    shortcut.intersects(&edge)
+                });
+            !crosses_kept && !obstacles.iter().any(|edge| shortcut.intersects(edge))
+        });
+        match removable {
+            Some(i) => {
+                kept.remove(i);
+            }
+            None => break,
+        }
+    }
+    kept
+}
+
+/// Plan (XY) edges of a ring, including the closing edge of an open ring
+fn ring_edges(ring: &[[f64; 3]]) -> Vec<Line<f64>> {
+    let plan = |c: &[f64; 3]| Coord { x: c[0], y: c[1] };
+    let mut edges: Vec<Line<f64>> = ring
+        .windows(2)
+        .map(|pair| Line::new(plan(&pair[0]), plan(&pair[1])))
+        .collect();
+    if let (Some(first), Some(last)) = (ring.first(), ring.last()) {
+        if ring.len() > 2 && first != last {
+            edges.push(Line::new(plan(last), plan(first)));
+        }
+    }
+    edges
+}
+
+/// Signed plan area of a closed ring, positive when counter-clockwise
+pub(crate) fn signed_area(ring: &[[f64; 3]]) -> f64 {
+    ring.windows(2)
+        .map(|pair| pair[0][0] * pair[1][1] - pair[1][0] * pair[0][1])
+        .sum::<f64>()
+        / 2.0
+}
+
+/// Even-odd ray casting test of a plan location against a single ring
+///
+/// The ring may be closed or open; the closing edge is implied either way.
+fn ring_contains(ring: &[[f64; 3]], x: f64, y: f64) -> bool {
+    let mut inside = false;
+    let mut j = match ring.len() {
+        0 => return false,
+        n => n - 1,
+    };
+    for i in 0..ring.len() {
+        let (xi, yi) = (ring[i][0], ring[i][1]);
+        let (xj, yj) = (ring[j][0], ring[j][1]);
+        if (yi > y) != (yj > y) && x < (xj - xi) * (y - yi) / (yj - yi) + xi {
+            inside = !inside;
+        }
+        j = i;
+    }
+    inside
+}
+
+impl std::fmt::Display for Geometry {
+    /// Summarize the geometry type and size, e.g. `Polygon(5 pts, 1 ring)`
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let plural = |n: usize| if n == 1 { "" } else { "s" };
+        match self {
+            Self::Point { coordinates, .. } => write!(
+                f,
+                "Point({}, {}, {})",
+                coordinates[0], coordinates[1], coordinates[2]
+            ),
+            Self::LineString { coordinates, .. } => {
+                write!(f, "LineString({} pts)", coordinates.len())
+            }
+            Self::Polygon { rings, .. } => {
+                let points: usize = rings.iter().map(Vec::len).sum();
+                write!(
+                    f,
+                    "Polygon({} pts, {} ring{})",
+                    points,
+                    rings.len(),
+                    plural(rings.len())
+                )
+            }
+            Self::Surface {
+                metadata: Some(meta),
+                ..
+            } => write!(
+                f,
+                "Surface({} verts, {} faces)",
+                meta.vertex_count, meta.face_count
+            ),
+            Self::Surface {
+                obj_data,
+                obj_ref: Some(path),
+                ..
+            } if obj_data.is_empty() => {
+                write!(f, "Surface(ref {})", path)
+            }
+            Self::Surface { obj_data, .. } if obj_data.is_empty() => write!(f, "Surface(empty)"),
+            // Without metadata, count from the embedded mesh itself
+            Self::Surface { .. } => match self.obj_bytes(None) {
+                Ok(obj) => {
+                    let stats = obj_stats(&obj);
+                    write!(
+                        f,
+                        "Surface({} verts, {} faces)",
+                        stats.vertex_count, stats.face_count
+                    )
+                }
+                Err(_) => write!(f, "Surface(invalid OBJ data)"),
+            },
+            Self::Collection { .. } if self.is_placeholder() => write!(f, "Placeholder"),
+            Self::Collection { geometries, .. } => write!(
+                f,
+                "Collection({} geometr{})",
+                geometries.len(),
+                if geometries.len() == 1 { "y" } else { "ies" }
+            ),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -234,7 +1034,7 @@ mod tests {
     #[test]
     fn test_point_geometry() {
         let geom = Geometry::point(100.0, 200.0, 10.0);
-        
+
         match geom {
             Geometry::Point { coordinates, .. } => {
                 assert_eq!(coordinates, [100.0, 200.0, 10.0]);
@@ -245,17 +1045,44 @@ mod tests {
 
     #[test]
     fn test_linestring_to_wkt() {
-        let geom = Geometry::linestring(vec![
-            [0.0, 0.0, 0.0],
-            [1.0, 1.0, 0.0],
-            [2.0, 0.0, 0.0],
-        ])
-        .unwrap();
+        let geom =
+            Geometry::linestring(vec![[0.0, 0.0, 0.0], [1.0, 1.0, 0.0], [2.0, 0.0, 0.0]]).unwrap();
 
         let wkt = geom.to_wkt().unwrap();
         assert!(wkt.contains("LINESTRING"));
     }
 
+    #[test]
+    fn test_measured_linestring_to_wkt() {
+        let geom = Geometry::linestring(vec![[0.0, 0.0, 5.0], [3.0, 4.0, 4.5]])
+            .unwrap()
+            .with_measures(vec![100.0, 105.0])
+            .unwrap();
+        assert_eq!(
+            geom.to_wkt().unwrap(),
+            "LINESTRING ZM(0 0 5 100,3 4 4.5 105)"
+        );
+
+        let json = serde_json::to_value(&geom).unwrap();
+        assert_eq!(json["measures"], serde_json::json!([100.0, 105.0]));
+
+        let unmeasured = Geometry::linestring(vec![[0.0, 0.0, 5.0], [3.0, 4.0, 4.5]]).unwrap();
+        assert!(serde_json::to_value(&unmeasured)
+            .unwrap()
+            .get("measures")
+            .is_none());
+        assert!(unmeasured.with_measures(vec![1.0]).is_err());
+
+        // Measures read from a file are not checked on the way in
+        let mismatched: Geometry = serde_json::from_value(serde_json::json!({
+            "type": "lineString",
+            "coordinates": [[0.0, 0.0, 5.0], [3.0, 4.0, 4.5]],
+            "measures": [100.0],
+        }))
+        .unwrap();
+        assert!(matches!(mismatched.to_wkt(), Err(Error::Geometry(_))));
+    }
+
     #[test]
     fn test_polygon_to_wkt() {
         let exterior = vec![
@@ -271,6 +1098,365 @@ mod tests {
         assert!(wkt.contains("POLYGON"));
     }
 
+    #[test]
+    fn test_contains_point() {
+        let square = vec![
+            [0.0, 0.0, 0.0],
+            [10.0, 0.0, 0.0],
+            [10.0, 10.0, 0.0],
+            [0.0, 10.0, 0.0],
+            [0.0, 0.0, 0.0],
+        ];
+        let hole = vec![
+            [4.0, 4.0, 0.0],
+            [6.0, 4.0, 0.0],
+            [6.0, 6.0, 0.0],
+            [4.0, 6.0, 0.0],
+            [4.0, 4.0, 0.0],
+        ];
+        let polygon = Geometry::polygon(square, vec![hole]).unwrap();
+
+        assert!(polygon.contains_point(2.0, 2.0));
+        assert!(!polygon.contains_point(5.0, 5.0));
+        assert!(!polygon.contains_point(15.0, 5.0));
+        assert!(!Geometry::point(2.0, 2.0, 0.0).contains_point(2.0, 2.0));
+    }
+
+    #[test]
+    fn test_cached_bounding_box_uses_surface_metadata() {
+        let mut surface =
+            Geometry::surface(b"v 0 0 0\nv 10 5 -2\nv 0 5 1\nf 1 2 3\n".to_vec(), None);
+        assert!(surface.bounding_box().is_none());
+
+        let expected = BoundingBox {
+            min: [0.0, 0.0, -2.0],
+            max: [10.0, 5.0, 1.0],
+        };
+        assert_eq!(surface.cached_bounding_box(None), Some(expected.clone()));
+
+        // The second call reads the stored metadata rather than the mesh
+        if let Geometry::Surface { obj_data, .. } = &mut surface {
+            obj_data.clear();
+        }
+        assert_eq!(surface.cached_bounding_box(None), Some(expected.clone()));
+        assert_eq!(surface.bounding_box(), Some(expected));
+
+        surface.set_obj_data(b"v 1 1 1\n".to_vec());
+        assert_eq!(
+            surface.cached_bounding_box(None),
+            Some(BoundingBox {
+                min: [1.0; 3],
+                max: [1.0; 3]
+            })
+        );
+
+        // Malformed vertices are skipped, not counted at the origin
+        surface.set_obj_data(b"v 5 5 5\nv 6 x 6\nv 7 7\nv 8 8 8\n".to_vec());
+        assert_eq!(
+            surface.cached_bounding_box(None),
+            Some(BoundingBox {
+                min: [5.0; 3],
+                max: [8.0; 3]
+            })
+        );
+        assert!(matches!(
+            surface,
+            Geometry::Surface {
+                metadata: Some(SurfaceMetadata {
+                    vertex_count: 2,
+                    ..
+                }),
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_empty_exterior_ring_to_wkt() {
+        let polygon = Geometry::Polygon {
+            rings: vec![vec![]],
+            crs: None,
+            wkt: None,
+            wkb: None,
+        };
+        assert!(matches!(polygon.to_wkt(), Err(Error::Geometry(_))));
+    }
+
+    #[test]
+    fn test_area_2d_subtracts_hole() {
+        let outer = vec![
+            [0.0, 0.0, 0.0],
+            [10.0, 0.0, 0.0],
+            [10.0, 10.0, 0.0],
+            [0.0, 10.0, 0.0],
+            [0.0, 0.0, 0.0],
+        ];
+        // Clockwise, against the counter-clockwise exterior
+        let hole = vec![
+            [4.0, 4.0, 0.0],
+            [4.0, 6.0, 0.0],
+            [6.0, 6.0, 0.0],
+            [6.0, 4.0, 0.0],
+            [4.0, 4.0, 0.0],
+        ];
+        let polygon = Geometry::polygon(outer, vec![hole]).unwrap();
+
+        assert_eq!(polygon.area_2d(), 96.0);
+        assert_eq!(polygon.ring_is_hole(0), Some(false));
+        assert_eq!(polygon.ring_is_hole(1), Some(true));
+        assert_eq!(polygon.ring_is_hole(2), None);
+        assert_eq!(Geometry::point(0.0, 0.0, 0.0).area_2d(), 0.0);
+    }
+
+    #[test]
+    fn test_simplify_vw_keeps_ring_closed() {
+        let mut ring: Vec<[f64; 3]> = (0..99)
+            .map(|i| {
+                let angle = i as f64 / 99.0 * std::f64::consts::TAU;
+                // A wobbly circle, so vertices differ in importance
+                let radius = 10.0 + (angle * 5.0).sin();
+                [radius * angle.cos(), radius * angle.sin(), 1.0]
+            })
+            .collect();
+        ring.push(ring[0]);
+        let polygon = Geometry::polygon(ring, vec![]).unwrap();
+
+        let simplified = polygon.simplify_vw(20);
+        let Geometry::Polygon { rings, .. } = &simplified else {
+            panic!("expected a polygon");
+        };
+        assert_eq!(rings[0].len(), 20);
+        assert_eq!(rings[0].first(), rings[0].last());
+        assert!((simplified.area_2d() - polygon.area_2d()).abs() / polygon.area_2d() < 0.1);
+
+        let line =
+            Geometry::linestring(vec![[0.0, 0.0, 0.0], [1.0, 0.1, 0.0], [2.0, 0.0, 0.0]]).unwrap();
+        let Geometry::LineString { coordinates, .. } = line.simplify_vw(2) else {
+            panic!("expected a linestring");
+        };
+        assert_eq!(coordinates, vec![[0.0, 0.0, 0.0], [2.0, 0.0, 0.0]]);
+    }
+
+    #[test]
+    fn test_simplify_vw_never_self_intersects() {
+        // Dropping the shallow vertex at (10, 0.1) would be cheapest, but its
+        // shortcut along y = 0 would cut through the last segment
+        let line = Geometry::linestring(vec![
+            [0.0, 0.0, 0.0],
+            [10.0, 0.1, 0.0],
+            [20.0, 0.0, 0.0],
+            [20.0, -5.0, 0.0],
+            [11.0, -5.0, 0.0],
+            [11.0, 0.05, 0.0],
+        ])
+        .unwrap();
+        let Geometry::LineString { coordinates, .. } = line.simplify_vw(5) else {
+            panic!("expected a linestring");
+        };
+        assert_eq!(coordinates.len(), 5);
+        assert!(coordinates.contains(&[10.0, 0.1, 0.0]));
+        assert!(!coordinates.contains(&[20.0, -5.0, 0.0]));
+    }
+
+    #[test]
+    fn test_simplify_vw_measures() {
+        let coordinates = vec![[0.0, 0.0, 0.0], [1.0, 0.1, 0.0], [2.0, 0.0, 0.0]];
+        let line = Geometry::linestring(coordinates.clone())
+            .unwrap()
+            .with_measures(vec![100.0, 101.0, 102.0])
+            .unwrap();
+        let Geometry::LineString { measures, .. } = line.simplify_vw(2) else {
+            panic!("expected a linestring");
+        };
+        assert_eq!(measures, Some(vec![100.0, 102.0]));
+
+        // A parsed file can carry too few measures; they are dropped, not indexed
+        let short: Geometry = serde_json::from_value(serde_json::json!({
+            "type": "lineString",
+            "coordinates": coordinates,
+            "measures": [100.0]
+        }))
+        .unwrap();
+        let Geometry::LineString {
+            coordinates,
+            measures,
+            ..
+        } = short.simplify_vw(2)
+        else {
+            panic!("expected a linestring");
+        };
+        assert_eq!(coordinates.len(), 2);
+        assert_eq!(measures, None);
+    }
+
+    #[test]
+    fn test_from_wkt_2d() {
+        let Geometry::Point { coordinates, .. } = Geometry::from_wkt("POINT(1 2)").unwrap() else {
+            panic!("expected a point");
+        };
+        assert_eq!(coordinates, [1.0, 2.0, 0.0]);
+
+        let polygon =
+            Geometry::from_wkt("POLYGON((0 0,10 0,10 10,0 10,0 0),(2 2,2 4,4 4,4 2,2 2))").unwrap();
+        let Geometry::Polygon { rings, .. } = &polygon else {
+            panic!("expected a polygon");
+        };
+        assert_eq!(rings.len(), 2);
+        assert_eq!(rings[1][1], [2.0, 4.0, 0.0]);
+        assert_eq!(
+            polygon.to_wkt().unwrap(),
+            "POLYGON((0 0,10 0,10 10,0 10,0 0),(2 2,2 4,4 4,4 2,2 2))"
+        );
+
+        let err = Geometry::from_wkt("MULTIPOLYGON(((0 0,1 0,1 1,0 0)))").unwrap_err();
+        assert!(err.to_string().contains("MULTIPOLYGON"));
+    }
+
+    #[test]
+    fn test_from_wkt_3d() {
+        let Geometry::LineString {
+            coordinates,
+            measures,
+            ..
+        } = Geometry::from_wkt("LINESTRING Z(0 0 5,10 0 -2.5)").unwrap()
+        else {
+            panic!("expected a linestring");
+        };
+        assert_eq!(coordinates, vec![[0.0, 0.0, 5.0], [10.0, 0.0, -2.5]]);
+        assert_eq!(measures, None);
+
+        let measured = Geometry::linestring(vec![[0.0, 0.0, 1.0], [3.0, 4.0, 2.0]])
+            .unwrap()
+            .with_measures(vec![0.0, 5.0])
+            .unwrap();
+        let parsed = Geometry::from_wkt(&measured.to_wkt().unwrap()).unwrap();
+        assert_eq!(parsed.to_wkt().unwrap(), "LINESTRING ZM(0 0 1 0,3 4 2 5)");
+    }
+
+    #[test]
+    fn test_area_and_length() {
+        let square = Geometry::polygon(
+            vec![
+                [0.0, 0.0, 0.0],
+                [10.0, 0.0, 0.0],
+                [10.0, 10.0, 0.0],
+                [0.0, 10.0, 0.0],
+                [0.0, 0.0, 0.0],
+            ],
+            vec![],
+        )
+        .unwrap();
+        assert_eq!(square.area().unwrap(), 100.0);
+
+        // A clockwise hole in the counter-clockwise exterior subtracts
+        let with_hole = Geometry::polygon(
+            vec![
+                [0.0, 0.0, 0.0],
+                [10.0, 0.0, 0.0],
+                [10.0, 10.0, 0.0],
+                [0.0, 10.0, 0.0],
+                [0.0, 0.0, 0.0],
+            ],
+            vec![vec![
+                [2.0, 2.0, 0.0],
+                [2.0, 4.0, 0.0],
+                [4.0, 4.0, 0.0],
+                [4.0, 2.0, 0.0],
+                [2.0, 2.0, 0.0],
+            ]],
+        )
+        .unwrap();
+        assert_eq!(with_hole.area().unwrap(), 96.0);
+
+        // Holes subtract whichever way they are wound
+        let same_wound_hole = Geometry::polygon(
+            vec![
+                [0.0, 0.0, 0.0],
+                [10.0, 0.0, 0.0],
+                [10.0, 10.0, 0.0],
+                [0.0, 10.0, 0.0],
+                [0.0, 0.0, 0.0],
+            ],
+            vec![vec![
+                [2.0, 2.0, 0.0],
+                [4.0, 2.0, 0.0],
+                [4.0, 4.0, 0.0],
+                [2.0, 4.0, 0.0],
+                [2.0, 2.0, 0.0],
+            ]],
+        )
+        .unwrap();
+        assert_eq!(same_wound_hole.area().unwrap(), 96.0);
+
+        let path = Geometry::linestring(vec![
+            [0.0, 0.0, 0.0],
+            [3.0, 0.0, 0.0],
+            [3.0, 4.0, 0.0],
+            [0.0, 0.0, 0.0],
+        ])
+        .unwrap();
+        assert_eq!(path.length().unwrap(), 12.0);
+
+        assert!(matches!(path.area(), Err(Error::Geometry(_))));
+        assert!(matches!(square.length(), Err(Error::Geometry(_))));
+    }
+
+    #[test]
+    fn test_obj_ref_outside_base_dir_is_refused() {
+        let base = std::env::temp_dir();
+        for reference in ["../secret.obj", "/etc/passwd", "meshes/../../secret.obj"] {
+            let surface = Geometry::surface_ref(reference, None);
+            let err = surface.obj_bytes(Some(&base)).unwrap_err();
+            assert!(
+                err.to_string().contains("outside the document directory"),
+                "{}",
+                reference
+            );
+        }
+    }
+
+    #[test]
+    fn test_display() {
+        let point = Geometry::point(100.0, 200.0, 10.5);
+        assert_eq!(point.to_string(), "Point(100, 200, 10.5)");
+
+        let polygon = Geometry::polygon(
+            vec![
+                [0.0, 0.0, 0.0],
+                [10.0, 0.0, 0.0],
+                [10.0, 10.0, 0.0],
+                [0.0, 10.0, 0.0],
+                [0.0, 0.0, 0.0],
+            ],
+            vec![],
+        )
+        .unwrap();
+        assert_eq!(polygon.to_string(), "Polygon(5 pts, 1 ring)");
+
+        let surface = Geometry::surface(
+            Vec::new(),
+            Some(SurfaceMetadata {
+                vertex_count: 1024,
+                face_count: 2048,
+                bounds: None,
+            }),
+        );
+        assert_eq!(surface.to_string(), "Surface(1024 verts, 2048 faces)");
+
+        let embedded = Geometry::surface(b"v 0 0 0\nv 1 0 0\nv 0 1 0\nf 1 2 3\n".to_vec(), None);
+        assert_eq!(embedded.to_string(), "Surface(3 verts, 1 faces)");
+        assert_eq!(
+            Geometry::surface(Vec::new(), None).to_string(),
+            "Surface(empty)"
+        );
+    }
+
+    #[test]
+    fn test_placeholder() {
+        assert!(Geometry::placeholder().is_placeholder());
+        assert!(!Geometry::point(0.0, 0.0, 0.0).is_placeholder());
+    }
+
     #[test]
     fn test_crs() {
         let geom = Geometry::point(0.0, 0.0, 0.0).with_crs("EPSG:27700");
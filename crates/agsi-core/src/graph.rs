@@ -0,0 +1,183 @@
+//! Reference graph of models, components and materials
+
+use serde::Serialize;
+
+use crate::Document;
+
+/// Kind of object a graph node stands for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum NodeKind {
+    Model,
+    Component,
+    Material,
+}
+
+/// An object in the reference graph
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct GraphNode {
+    /// Unique key, e.g. `MODEL001/component/COMP001`; materials and
+    /// components are qualified by their model and kind, since IDs are only
+    /// unique within one model and one kind of object
+    pub key: String,
+    pub kind: NodeKind,
+    pub label: String,
+}
+
+/// A reference from one node to another, by key
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct GraphEdge {
+    pub from: String,
+    pub to: String,
+}
+
+/// Models, their components and the materials the components use
+///
+/// Edges run model → component and component → material. Components that
+/// reference a missing material get an edge to a node labelled as missing.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct ReferenceGraph {
+    pub nodes: Vec<GraphNode>,
+    pub edges: Vec<GraphEdge>,
+}
+
+impl ReferenceGraph {
+    /// Build the graph of a document
+    pub fn of(doc: &Document) -> Self {
+        let mut graph = Self::default();
+
+        for model in &doc.agsi_model {
+            graph.nodes.push(GraphNode {
+                key: model.id.clone(),
+                kind: NodeKind::Model,
+                label: model.name.clone(),
+            });
+            for material in &model.materials {
+                graph.nodes.push(GraphNode {
+                    key: format!("{}/material/{}", model.id, material.id),
+                    kind: NodeKind::Material,
+                    label: material.name.clone(),
+                });
+            }
+
+            for component in &model.components {
+                let key = format!("{}/component/{}", model.id, component.id);
+                graph.nodes.push(GraphNode {
+                    key: key.clone(),
+                    kind: NodeKind::Component,
+                    label: component.name.clone(),
+                });
+                graph.edges.push(GraphEdge {
+                    from: model.id.clone(),
+                    to: key.clone(),
+                });
+
+                let material_key = format!("{}/material/{}", model.id, component.material_id);
+                if model.get_material(&component.material_id).is_none()
+                    && !graph.nodes.iter().any(|n| n.key == material_key)
+                {
+                    graph.nodes.push(GraphNode {
+                        key: material_key.clone(),
+                        kind: NodeKind::Material,
+                        label: format!("{} (missing)", component.material_id),
+                    });
+                }
+                graph.edges.push(GraphEdge {
+                    from: key,
+                    to: material_key,
+                });
+            }
+        }
+
+        graph
+    }
+
+    /// Render the graph as a Graphviz DOT digraph
+    pub fn to_dot(&self) -> String {
+        let quote = |s: &str| format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""));
+
+        let mut dot = String::from("digraph agsi {\n    rankdir=LR;\n");
+        for node in &self.nodes {
+            let shape = match node.kind {
+                NodeKind::Model => "folder",
+                NodeKind::Component => "box",
+                NodeKind::Material => "ellipse",
+            };
+            dot.push_str(&format!(
+                "    {} [label={}, shape={}];\n",
+                quote(&node.key),
+                quote(&node.label),
+                shape
+            ));
+        }
+        for edge in &self.edges {
+            dot.push_str(&format!(
+                "    {} -> {};\n",
+                quote(&edge.from),
+                quote(&edge.to)
+            ));
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::Geometry;
+    use crate::material::{Material, MaterialType};
+    use crate::model::{ComponentType, GroundModel, ModelComponent, ModelDimension, ModelType};
+
+    #[test]
+    fn test_dot_has_component_to_material_edge() {
+        let mut model = GroundModel::new(
+            "MODEL001",
+            "Site",
+            ModelType::Stratigraphic,
+            ModelDimension::TwoD,
+        );
+        model.add_material(Material::new("MAT001", "Clay", MaterialType::Soil));
+        model.add_component(ModelComponent::new(
+            "COMP001",
+            "Clay layer",
+            ComponentType::Layer,
+            "MAT001",
+            Geometry::point(0.0, 0.0, 0.0),
+        ));
+        let mut doc = Document::new("DOC001");
+        doc.add_model(model);
+
+        let graph = doc.reference_graph();
+        assert_eq!(graph.nodes.len(), 3);
+
+        let dot = graph.to_dot();
+        assert!(dot.starts_with("digraph agsi {"));
+        assert!(dot.contains("\"MODEL001/component/COMP001\" -> \"MODEL001/material/MAT001\";"));
+        assert!(dot.contains("\"MODEL001\" -> \"MODEL001/component/COMP001\";"));
+    }
+
+    #[test]
+    fn test_component_and_material_sharing_an_id_stay_apart() {
+        let mut model = GroundModel::new(
+            "MODEL001",
+            "Site",
+            ModelType::Stratigraphic,
+            ModelDimension::TwoD,
+        );
+        model.add_material(Material::new("CLAY", "Clay", MaterialType::Soil));
+        model.add_component(ModelComponent::new(
+            "CLAY",
+            "Clay layer",
+            ComponentType::Layer,
+            "CLAY",
+            Geometry::point(0.0, 0.0, 0.0),
+        ));
+        let mut doc = Document::new("DOC001");
+        doc.add_model(model);
+
+        let graph = doc.reference_graph();
+        assert_eq!(graph.nodes.len(), 3);
+        assert!(graph.edges.iter().all(|e| e.from != e.to));
+    }
+}
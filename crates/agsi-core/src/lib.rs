@@ -13,24 +13,40 @@
 //! - Material-centric design allowing independent use of components
 
 pub mod agsi_model;
+pub mod cache;
+pub mod coverage;
+pub mod design;
+pub mod diff;
 pub mod document;
 pub mod error;
 pub mod geometry;
+pub mod graph;
 pub mod material;
+pub mod merge;
+pub mod migrations;
 pub mod model;
 pub mod project;
+pub mod repair;
 pub mod serialization;
+pub mod spatial;
+pub mod statistics;
 pub mod validation;
+pub mod validation_profile;
+
+#[cfg(test)]
+mod fixtures;
 
 // Export AGSi schema-compliant structures
 pub use agsi_model::{
-    AgsiRoot, AgsSchema, AgsFile, AgsProject, AgsiModel, AgsiModelElement,
-    AgsiDataParameterValue, AgsiParameterCode, AgsiDataPropertyValue,
-    AgsiDataPropertySummary, AgsiModelBoundary,
+    AgsFile, AgsProject, AgsSchema, AgsiDataParameterValue, AgsiDataPropertyFromFile,
+    AgsiDataPropertySummary, AgsiDataPropertyValue, AgsiModel, AgsiModelBoundary, AgsiModelElement,
+    AgsiParameterCode, AgsiRoot, Alignment,
 };
 
 // Export legacy structures for backward compatibility
-pub use document::Document;
+pub use document::{
+    Attachment, Document, JsonOptions, PrecisionReport, PruneReport, RedactOptions, SchemaVariant,
+};
 pub use error::{Error, Result};
 pub use material::{Material, MaterialProperty};
 pub use model::{GroundModel, ModelComponent};
@@ -2,6 +2,9 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use validator::Validate;
 
+use crate::document::serialize_sorted;
+use crate::error::Error;
+
 /// A material representing geotechnical properties
 ///
 /// Materials can be used independently and are the fundamental building blocks
@@ -31,7 +34,7 @@ pub struct Material {
     pub properties: Vec<MaterialProperty>,
 
     /// Additional metadata
-    #[serde(default)]
+    #[serde(default, serialize_with = "serialize_sorted")]
     pub metadata: HashMap<String, serde_json::Value>,
 }
 
@@ -51,7 +54,7 @@ pub enum MaterialType {
 
 /// A property of a material with value and optional unit
 #[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[serde(rename_all = "camelCase", try_from = "RawMaterialProperty")]
 pub struct MaterialProperty {
     /// Property name (e.g., "density", "cohesion", "friction_angle")
     pub name: String,
@@ -69,9 +72,43 @@ pub struct MaterialProperty {
     pub source: Option<PropertySource>,
 }
 
+/// A material property as read, before its value has been checked
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawMaterialProperty {
+    name: String,
+    value: serde_json::Value,
+    #[serde(default)]
+    unit: Option<String>,
+    #[serde(default)]
+    method: Option<String>,
+    #[serde(default)]
+    source: Option<PropertySource>,
+}
+
+impl TryFrom<RawMaterialProperty> for MaterialProperty {
+    type Error = Error;
+
+    fn try_from(raw: RawMaterialProperty) -> crate::Result<Self> {
+        Ok(Self {
+            value: PropertyValue::from_json(&raw.name, &raw.value)?,
+            name: raw.name,
+            unit: raw.unit,
+            method: raw.method,
+            source: raw.source,
+        })
+    }
+}
+
 /// Value types for material properties
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(untagged)]
+///
+/// Values are written untagged by default (`1.5`, `"text"`, `{"min": 1,
+/// "max": 2}`, `[1, 2]`). With the `tagged-property-values` feature they are
+/// written as internally tagged objects (`{"type": "range", "min": 1, "max": 2}`)
+/// instead, which the Avro schema does not support. Both forms are always
+/// accepted on input, and an untagged object must contain exactly `min` and
+/// `max` to be read as a range.
+#[derive(Debug, Clone, PartialEq)]
 pub enum PropertyValue {
     Number(f64),
     Text(String),
@@ -80,6 +117,115 @@ pub enum PropertyValue {
     Array(Vec<f64>),
 }
 
+/// Internally tagged form of [`PropertyValue`]
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase", deny_unknown_fields)]
+enum TaggedPropertyValue {
+    Number { value: f64 },
+    Text { value: String },
+    Boolean { value: bool },
+    Range { min: f64, max: f64 },
+    Array { values: Vec<f64> },
+}
+
+/// Untagged range object, rejecting any other keys
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RangeValue {
+    min: f64,
+    max: f64,
+}
+
+// Order matters: arrays are tried before ranges because serde will also read
+// a two-element array as a `{min, max}` struct
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum PropertyValueRepr {
+    Tagged(TaggedPropertyValue),
+    Number(f64),
+    Text(String),
+    Boolean(bool),
+    Array(Vec<f64>),
+    Range(RangeValue),
+}
+
+impl Serialize for PropertyValue {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        if cfg!(feature = "tagged-property-values") {
+            let tagged = match self.clone() {
+                Self::Number(value) => TaggedPropertyValue::Number { value },
+                Self::Text(value) => TaggedPropertyValue::Text { value },
+                Self::Boolean(value) => TaggedPropertyValue::Boolean { value },
+                Self::Range { min, max } => TaggedPropertyValue::Range { min, max },
+                Self::Array(values) => TaggedPropertyValue::Array { values },
+            };
+            return tagged.serialize(serializer);
+        }
+
+        match self {
+            Self::Number(value) => value.serialize(serializer),
+            Self::Text(value) => value.serialize(serializer),
+            Self::Boolean(value) => value.serialize(serializer),
+            Self::Range { min, max } => RangeValue {
+                min: *min,
+                max: *max,
+            }
+            .serialize(serializer),
+            Self::Array(values) => values.serialize(serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for PropertyValue {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let value = match PropertyValueRepr::deserialize(deserializer).map_err(|_| {
+            serde::de::Error::custom(
+                "expected a number, string, boolean, array of numbers, {\"min\", \"max\"} range or tagged property value",
+            )
+        })? {
+            PropertyValueRepr::Tagged(TaggedPropertyValue::Number { value })
+            | PropertyValueRepr::Number(value) => Self::Number(value),
+            PropertyValueRepr::Tagged(TaggedPropertyValue::Text { value })
+            | PropertyValueRepr::Text(value) => Self::Text(value),
+            PropertyValueRepr::Tagged(TaggedPropertyValue::Boolean { value })
+            | PropertyValueRepr::Boolean(value) => Self::Boolean(value),
+            PropertyValueRepr::Tagged(TaggedPropertyValue::Range { min, max })
+            | PropertyValueRepr::Range(RangeValue { min, max }) => Self::Range { min, max },
+            PropertyValueRepr::Tagged(TaggedPropertyValue::Array { values })
+            | PropertyValueRepr::Array(values) => Self::Array(values),
+        };
+        Ok(value)
+    }
+}
+
+impl PropertyValue {
+    /// Parse a property value from JSON, naming the property on failure
+    pub fn from_json(name: &str, value: &serde_json::Value) -> crate::Result<Self> {
+        serde_json::from_value(value.clone()).map_err(|_| {
+            Error::InvalidProperty(format!(
+                "'{}' has value {}, expected a number, string, boolean, array of numbers, \
+                 {{\"min\", \"max\"}} range or tagged property value",
+                name, value
+            ))
+        })
+    }
+
+    /// Check that any numeric content is finite (not NaN or infinite)
+    pub fn is_finite(&self) -> bool {
+        match self {
+            Self::Number(v) => v.is_finite(),
+            Self::Range { min, max } => min.is_finite() && max.is_finite(),
+            Self::Array(values) => values.iter().all(|v| v.is_finite()),
+            Self::Text(_) | Self::Boolean(_) => true,
+        }
+    }
+}
+
 /// Source of property value
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
@@ -93,7 +239,11 @@ pub enum PropertySource {
 
 impl Material {
     /// Create a new material with basic information
-    pub fn new(id: impl Into<String>, name: impl Into<String>, material_type: MaterialType) -> Self {
+    pub fn new(
+        id: impl Into<String>,
+        name: impl Into<String>,
+        material_type: MaterialType,
+    ) -> Self {
         Self {
             id: id.into(),
             name: name.into(),
@@ -162,6 +312,46 @@ impl MaterialProperty {
         }
     }
 
+    /// Create a numeric property, rejecting an empty name or non-finite value
+    pub fn try_numeric(
+        name: impl Into<String>,
+        value: f64,
+        unit: Option<String>,
+    ) -> crate::Result<Self> {
+        Self::numeric(name, value, unit).checked()
+    }
+
+    /// Create a range property, rejecting an empty name, non-finite bounds
+    /// or `min > max`
+    pub fn try_range(
+        name: impl Into<String>,
+        min: f64,
+        max: f64,
+        unit: Option<String>,
+    ) -> crate::Result<Self> {
+        let property = Self::range(name, min, max, unit).checked()?;
+        if min > max {
+            return Err(Error::InvalidProperty(format!(
+                "'{}' has range min {} greater than max {}",
+                property.name, min, max
+            )));
+        }
+        Ok(property)
+    }
+
+    fn checked(self) -> crate::Result<Self> {
+        if self.name.trim().is_empty() {
+            return Err(Error::InvalidProperty("Property name is empty".to_string()));
+        }
+        if !self.value.is_finite() {
+            return Err(Error::InvalidProperty(format!(
+                "'{}' has a NaN or infinite value",
+                self.name
+            )));
+        }
+        Ok(self)
+    }
+
     /// Set the property source
     pub fn with_source(mut self, source: PropertySource) -> Self {
         self.source = Some(source);
@@ -173,12 +363,73 @@ impl MaterialProperty {
         self.method = Some(method.into());
         self
     }
+
+    /// Clamp numeric content into `[min, max]`
+    ///
+    /// Numbers, both ends of a range and every array element are clamped.
+    /// Text and boolean values are left unchanged. Panics if `min > max` or
+    /// either bound is NaN, like [`f64::clamp`].
+    pub fn clamp(&mut self, min: f64, max: f64) {
+        match &mut self.value {
+            PropertyValue::Number(value) => *value = value.clamp(min, max),
+            PropertyValue::Range {
+                min: low,
+                max: high,
+            } => {
+                *low = low.clamp(min, max);
+                *high = high.clamp(min, max);
+            }
+            PropertyValue::Array(values) => {
+                for value in values {
+                    *value = value.clamp(min, max);
+                }
+            }
+            PropertyValue::Text(_) | PropertyValue::Boolean(_) => {}
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_try_constructors_reject_invalid_input() {
+        assert!(MaterialProperty::try_numeric("density", 1800.0, None).is_ok());
+        assert!(matches!(
+            MaterialProperty::try_numeric("density", f64::NAN, None),
+            Err(Error::InvalidProperty(_))
+        ));
+        assert!(matches!(
+            MaterialProperty::try_numeric("  ", 1800.0, None),
+            Err(Error::InvalidProperty(_))
+        ));
+        assert!(MaterialProperty::try_range("cohesion", 5.0, 10.0, None).is_ok());
+        assert!(MaterialProperty::try_range("cohesion", 10.0, 5.0, None).is_err());
+    }
+
+    #[test]
+    fn test_clamp_property() {
+        let mut friction =
+            MaterialProperty::numeric("AngleFriction", 999.0, Some("deg".to_string()));
+        friction.clamp(0.0, 60.0);
+        assert_eq!(friction.value, PropertyValue::Number(60.0));
+
+        let mut range = MaterialProperty::range("AngleFriction", -5.0, 30.0, None);
+        range.clamp(0.0, 60.0);
+        assert_eq!(
+            range.value,
+            PropertyValue::Range {
+                min: 0.0,
+                max: 30.0
+            }
+        );
+
+        let mut text = MaterialProperty::text("Description", "999");
+        text.clamp(0.0, 60.0);
+        assert_eq!(text.value, PropertyValue::Text("999".to_string()));
+    }
+
     #[test]
     fn test_create_material() {
         let material = Material::new("MAT001", "Dense Sand", MaterialType::Soil)
@@ -201,7 +452,7 @@ mod tests {
     #[test]
     fn test_property_range() {
         let prop = MaterialProperty::range("cohesion", 10.0, 25.0, Some("kPa".to_string()));
-        
+
         match prop.value {
             PropertyValue::Range { min, max } => {
                 assert_eq!(min, 10.0);
@@ -210,4 +461,93 @@ mod tests {
             _ => panic!("Expected range value"),
         }
     }
+
+    #[test]
+    fn test_property_value_parsing_is_unambiguous() {
+        let parse = |json: &str| serde_json::from_str::<PropertyValue>(json);
+
+        // Legacy untagged forms still parse
+        assert_eq!(parse("1.5").unwrap(), PropertyValue::Number(1.5));
+        assert_eq!(
+            parse(r#"{"min": 1, "max": 2}"#).unwrap(),
+            PropertyValue::Range { min: 1.0, max: 2.0 }
+        );
+        assert_eq!(
+            parse("[1, 2]").unwrap(),
+            PropertyValue::Array(vec![1.0, 2.0])
+        );
+
+        // An arbitrary object that happens to have min/max is no longer read as a range
+        assert!(parse(r#"{"min": 1, "max": 2, "note": "from lab"}"#).is_err());
+        assert!(parse(r#"{"lower": 1}"#).is_err());
+
+        // Tagged forms say exactly what they are
+        assert_eq!(
+            parse(r#"{"type": "range", "min": 1, "max": 2}"#).unwrap(),
+            PropertyValue::Range { min: 1.0, max: 2.0 }
+        );
+        assert_eq!(
+            parse(r#"{"type": "array", "values": [1, 2]}"#).unwrap(),
+            PropertyValue::Array(vec![1.0, 2.0])
+        );
+        assert_eq!(
+            parse(r#"{"type": "text", "value": "CH"}"#).unwrap(),
+            PropertyValue::Text("CH".into())
+        );
+        assert!(parse(r#"{"type": "range", "min": 1}"#).is_err());
+    }
+
+    #[test]
+    fn test_property_value_roundtrip() {
+        let values = [
+            PropertyValue::Number(19.5),
+            PropertyValue::Text("CH".to_string()),
+            PropertyValue::Boolean(true),
+            PropertyValue::Range {
+                min: 25.0,
+                max: 35.0,
+            },
+            PropertyValue::Array(vec![1.0, 2.0]),
+        ];
+        for value in values {
+            let json = serde_json::to_string(&value).unwrap();
+            assert_eq!(serde_json::from_str::<PropertyValue>(&json).unwrap(), value);
+        }
+
+        let range = serde_json::to_value(PropertyValue::Range { min: 1.0, max: 2.0 }).unwrap();
+        if cfg!(feature = "tagged-property-values") {
+            assert_eq!(
+                range,
+                serde_json::json!({"type": "range", "min": 1.0, "max": 2.0})
+            );
+        } else {
+            assert_eq!(range, serde_json::json!({"min": 1.0, "max": 2.0}));
+        }
+    }
+
+    #[test]
+    fn test_invalid_property_value_error() {
+        let err =
+            PropertyValue::from_json("UnitWeightBulk", &serde_json::json!({"foo": 1})).unwrap_err();
+        assert!(matches!(err, Error::InvalidProperty(_)));
+        let message = err.to_string();
+        assert!(message.contains("'UnitWeightBulk'"), "{}", message);
+        assert!(message.contains(r#"{"foo":1}"#), "{}", message);
+
+        // The same message surfaces when loading a whole material
+        let json = r#"{
+            "id": "MAT001",
+            "name": "Clay",
+            "materialType": "SOIL",
+            "properties": [{"name": "UnitWeightBulk", "value": {"foo": 1}}]
+        }"#;
+        let message = serde_json::from_str::<Material>(json)
+            .unwrap_err()
+            .to_string();
+        assert!(
+            message.contains("Invalid material property: 'UnitWeightBulk'"),
+            "{}",
+            message
+        );
+    }
 }
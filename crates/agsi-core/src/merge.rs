@@ -0,0 +1,256 @@
+//! Three-way merge of documents against a common ancestor
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+use std::collections::HashSet;
+
+use crate::Document;
+
+/// A value changed differently on both sides of a merge
+///
+/// The merged document keeps `ours`; `None` means the value was absent
+/// (never added, or removed) on that side.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Conflict {
+    /// Location of the value, e.g. `agsiModel[MODEL001].materials[MAT001].name`
+    pub path: String,
+    pub base: Option<Value>,
+    pub ours: Option<Value>,
+    pub theirs: Option<Value>,
+}
+
+/// Numbers of models, materials and components in a document
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct ContentCounts {
+    pub models: usize,
+    pub materials: usize,
+    pub components: usize,
+}
+
+impl ContentCounts {
+    /// Count the contents of a document
+    pub fn of(doc: &Document) -> Self {
+        Self {
+            models: doc.agsi_model.len(),
+            materials: doc.agsi_model.iter().map(|m| m.materials.len()).sum(),
+            components: doc.agsi_model.iter().map(|m| m.components.len()).sum(),
+        }
+    }
+}
+
+/// An entry dropped while combining documents because its ID was already used
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Collision {
+    /// Index of the input the dropped entry came from
+    pub input: usize,
+    /// Location of the entry, e.g. `agsiModel[MODEL001].materials[MAT001]`
+    pub path: String,
+}
+
+/// Audit of a [`Document::combine`] run
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct MergeReport {
+    /// Contents of each input, in order
+    pub inputs: Vec<ContentCounts>,
+    /// Entries kept from an earlier input in place of a later one
+    pub collisions: Vec<Collision>,
+    /// Contents of the combined document
+    pub output: ContentCounts,
+}
+
+/// Fold the models of `doc` into `combined`, recording collisions
+pub(crate) fn combine_into(
+    combined: &mut Document,
+    input: usize,
+    doc: &Document,
+    report: &mut MergeReport,
+) {
+    for model in &doc.agsi_model {
+        let Some(target) = combined.get_model_mut(&model.id) else {
+            combined.agsi_model.push(model.clone());
+            continue;
+        };
+        for material in &model.materials {
+            if target.get_material(&material.id).is_some() {
+                report.collisions.push(Collision {
+                    input,
+                    path: format!("agsiModel[{}].materials[{}]", model.id, material.id),
+                });
+            } else {
+                target.materials.push(material.clone());
+            }
+        }
+        for component in &model.components {
+            if target.get_component(&component.id).is_some() {
+                report.collisions.push(Collision {
+                    input,
+                    path: format!("agsiModel[{}].components[{}]", model.id, component.id),
+                });
+            } else {
+                target.add_component(component.clone());
+            }
+        }
+    }
+
+    for attachment in &doc.attachments {
+        if combined.get_attachment(&attachment.id).is_some() {
+            report.collisions.push(Collision {
+                input,
+                path: format!("attachments[{}]", attachment.id),
+            });
+        } else {
+            combined.attachments.push(attachment.clone());
+        }
+    }
+}
+
+/// Field that identifies entries of a keyed array, by the array's field name
+fn array_key(field: &str) -> Option<&'static str> {
+    match field {
+        "agsiModel" | "materials" | "components" => Some("id"),
+        "properties" => Some("name"),
+        _ => None,
+    }
+}
+
+/// Merge serialized documents, collecting conflicts as they are found
+pub(crate) fn merge_values(
+    base: &Value,
+    ours: &Value,
+    theirs: &Value,
+    conflicts: &mut Vec<Conflict>,
+) -> Value {
+    merge("", "", Some(base), Some(ours), Some(theirs), conflicts).unwrap_or(Value::Null)
+}
+
+fn merge(
+    path: &str,
+    field: &str,
+    base: Option<&Value>,
+    ours: Option<&Value>,
+    theirs: Option<&Value>,
+    conflicts: &mut Vec<Conflict>,
+) -> Option<Value> {
+    if ours == theirs || theirs == base {
+        return ours.cloned();
+    }
+    if ours == base {
+        return theirs.cloned();
+    }
+
+    match (ours, theirs) {
+        (Some(Value::Object(o)), Some(Value::Object(t))) => {
+            let b = base.and_then(Value::as_object);
+            Some(Value::Object(merge_objects(path, b, o, t, conflicts)))
+        }
+        (Some(Value::Array(o)), Some(Value::Array(t))) => {
+            let b = base.and_then(Value::as_array);
+            if let Some(merged) =
+                array_key(field).and_then(|key| merge_keyed(path, key, b, o, t, conflicts))
+            {
+                return Some(Value::Array(merged));
+            }
+            conflict(path, base, ours, theirs, conflicts)
+        }
+        _ => conflict(path, base, ours, theirs, conflicts),
+    }
+}
+
+fn conflict(
+    path: &str,
+    base: Option<&Value>,
+    ours: Option<&Value>,
+    theirs: Option<&Value>,
+    conflicts: &mut Vec<Conflict>,
+) -> Option<Value> {
+    conflicts.push(Conflict {
+        path: path.to_string(),
+        base: base.cloned(),
+        ours: ours.cloned(),
+        theirs: theirs.cloned(),
+    });
+    ours.cloned()
+}
+
+fn merge_objects(
+    path: &str,
+    base: Option<&Map<String, Value>>,
+    ours: &Map<String, Value>,
+    theirs: &Map<String, Value>,
+    conflicts: &mut Vec<Conflict>,
+) -> Map<String, Value> {
+    let keys = ours
+        .keys()
+        .chain(theirs.keys().filter(|k| !ours.contains_key(*k)))
+        .chain(base.into_iter().flat_map(|b| b.keys()));
+
+    // A key dropped from the merge is never inserted, so track visits separately
+    let mut seen = HashSet::new();
+    let mut merged = Map::new();
+    for key in keys {
+        if !seen.insert(key) {
+            continue;
+        }
+        let child = if path.is_empty() {
+            key.clone()
+        } else {
+            format!("{}.{}", path, key)
+        };
+        let value = merge(
+            &child,
+            key,
+            base.and_then(|b| b.get(key)),
+            ours.get(key),
+            theirs.get(key),
+            conflicts,
+        );
+        if let Some(value) = value {
+            merged.insert(key.clone(), value);
+        }
+    }
+    merged
+}
+
+/// Merge arrays entry by entry, matching entries on `key`
+///
+/// Returns `None` if any entry lacks a string key, so the caller can fall back
+/// to treating the array as a single value.
+fn merge_keyed(
+    path: &str,
+    key: &str,
+    base: Option<&Vec<Value>>,
+    ours: &[Value],
+    theirs: &[Value],
+    conflicts: &mut Vec<Conflict>,
+) -> Option<Vec<Value>> {
+    let keyed = |items: &[Value]| -> Option<Vec<(String, Value)>> {
+        items
+            .iter()
+            .map(|item| Some((item.get(key)?.as_str()?.to_string(), item.clone())))
+            .collect()
+    };
+    let base = keyed(base.map_or(&[][..], Vec::as_slice))?;
+    let ours = keyed(ours)?;
+    let theirs = keyed(theirs)?;
+    let find = |items: &[(String, Value)], id: &str| {
+        items.iter().find(|(k, _)| k == id).map(|(_, v)| v.clone())
+    };
+
+    // Keep our order, then append entries only they added
+    let mut ids: Vec<&String> = ours.iter().map(|(k, _)| k).collect();
+    for (id, _) in theirs.iter().chain(&base) {
+        if !ids.contains(&id) {
+            ids.push(id);
+        }
+    }
+
+    let mut merged = Vec::new();
+    for id in ids {
+        let (b, o, t) = (find(&base, id), find(&ours, id), find(&theirs, id));
+        let child = format!("{}[{}]", path, id);
+        if let Some(value) = merge(&child, "", b.as_ref(), o.as_ref(), t.as_ref(), conflicts) {
+            merged.push(value);
+        }
+    }
+    Some(merged)
+}
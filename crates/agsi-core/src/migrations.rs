@@ -0,0 +1,119 @@
+use serde_json::Value;
+
+use crate::{Document, Error, Result, AGSI_VERSION};
+
+/// A single schema upgrade step
+struct Migration {
+    from: &'static str,
+    to: &'static str,
+    apply: fn(&mut Value) -> Result<()>,
+}
+
+/// Upgrade steps, in order
+const MIGRATIONS: &[Migration] = &[Migration {
+    from: "1.0.0",
+    to: "1.0.1",
+    apply: migrate_1_0_0_to_1_0_1,
+}];
+
+/// 1.0.0 → 1.0.1: fill in collections that became defaulted
+fn migrate_1_0_0_to_1_0_1(doc: &mut Value) -> Result<()> {
+    let root = doc
+        .as_object_mut()
+        .ok_or_else(|| Error::Deserialization("Document must be a JSON object".to_string()))?;
+
+    let models = root
+        .entry("agsiModel")
+        .or_insert_with(|| Value::Array(Vec::new()));
+    if let Some(models) = models.as_array_mut() {
+        for model in models.iter_mut().filter_map(Value::as_object_mut) {
+            for key in ["components", "materials"] {
+                model.entry(key).or_insert_with(|| Value::Array(Vec::new()));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Read the schema version from a raw document
+fn schema_version(doc: &Value) -> Result<String> {
+    doc.pointer("/agsSchema/version")
+        .and_then(Value::as_str)
+        .map(str::to_string)
+        .ok_or_else(|| Error::MissingField("agsSchema.version".to_string()))
+}
+
+/// Upgrade a raw JSON document to the current [`AGSI_VERSION`]
+///
+/// Returns the list of steps applied, e.g. `["1.0.0 -> 1.0.1"]`. A document
+/// already at the current version is left untouched.
+pub fn migrate_value(doc: &mut Value) -> Result<Vec<String>> {
+    let mut applied = Vec::new();
+    let mut version = schema_version(doc)?;
+
+    while version != AGSI_VERSION {
+        let step = MIGRATIONS
+            .iter()
+            .find(|m| m.from == version)
+            .ok_or_else(|| Error::InvalidSchemaVersion {
+                expected: AGSI_VERSION.to_string(),
+                found: version.clone(),
+            })?;
+
+        (step.apply)(doc)?;
+        doc["agsSchema"]["version"] = Value::String(step.to.to_string());
+        applied.push(format!("{} -> {}", step.from, step.to));
+        version = step.to.to_string();
+    }
+
+    Ok(applied)
+}
+
+/// Upgrade a JSON string to the current schema version and parse it
+pub fn migrate_json_str(json: &str) -> Result<(Document, Vec<String>)> {
+    let mut value: Value = serde_json::from_str(json)?;
+    let applied = migrate_value(&mut value)?;
+    let doc = Document::from_json_str(&value.to_string())?;
+    Ok((doc, applied))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_migrate_1_0_0() {
+        let json = r#"{
+            "agsSchema": { "version": "1.0.0" },
+            "agsFile": { "fileId": "OLD001" },
+            "agsiModel": [{
+                "id": "MODEL001",
+                "name": "Legacy",
+                "modelType": "STRATIGRAPHIC",
+                "dimension": "TWO_D"
+            }]
+        }"#;
+
+        let (doc, applied) = migrate_json_str(json).unwrap();
+        assert_eq!(doc.ags_schema.version, AGSI_VERSION);
+        assert_eq!(applied, vec!["1.0.0 -> 1.0.1".to_string()]);
+        assert_eq!(doc.agsi_model.len(), 1);
+    }
+
+    #[test]
+    fn test_migrate_current_is_noop() {
+        let json = Document::new("DOC001").to_json_string().unwrap();
+        let (_, applied) = migrate_json_str(&json).unwrap();
+        assert!(applied.is_empty());
+    }
+
+    #[test]
+    fn test_migrate_unknown_version() {
+        let json = r#"{ "agsSchema": { "version": "0.1" }, "agsFile": { "fileId": "X" } }"#;
+        assert!(matches!(
+            migrate_json_str(json),
+            Err(Error::InvalidSchemaVersion { .. })
+        ));
+    }
+}
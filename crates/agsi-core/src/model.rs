@@ -2,8 +2,11 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use validator::Validate;
 
-use crate::geometry::Geometry;
-use crate::material::Material;
+use crate::document::serialize_sorted;
+use crate::error::{Error, Result};
+use crate::geometry::{BoundingBox, Geometry};
+use crate::material::{Material, MaterialType};
+use crate::spatial::SpatialIndex;
 
 /// A ground model representing geological interpretation
 #[derive(Debug, Clone, Serialize, Deserialize, Validate)]
@@ -41,8 +44,17 @@ pub struct GroundModel {
     pub extent: Option<ModelExtent>,
 
     /// Additional metadata
-    #[serde(default)]
+    #[serde(default, serialize_with = "serialize_sorted")]
     pub metadata: HashMap<String, serde_json::Value>,
+
+    /// Optional spatial index over component footprints (not serialized)
+    #[serde(skip)]
+    spatial_index: Option<SpatialIndex>,
+
+    /// Bumped whenever the model's methods change its components, so a
+    /// spatial index built before then is ignored
+    #[serde(skip)]
+    generation: u64,
 }
 
 /// Type of ground model
@@ -95,12 +107,12 @@ pub struct ModelComponent {
     pub thickness: Option<f64>,
 
     /// Additional attributes
-    #[serde(default)]
+    #[serde(default, serialize_with = "serialize_sorted")]
     pub attributes: HashMap<String, serde_json::Value>,
 }
 
 /// Type of model component
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum ComponentType {
     Layer,
@@ -111,6 +123,37 @@ pub enum ComponentType {
     Boundary,
 }
 
+/// A single interval of a vertical column sampled from a model
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColumnInterval {
+    pub component_id: String,
+    pub material_id: String,
+    pub top: f64,
+    pub base: f64,
+}
+
+/// Section polygons of a 2D model grouped by material, ready for plotting
+///
+/// Points are `[distance, elevation]`, where distance is measured along the
+/// section line from its start.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FenceData {
+    /// Plan position distances are measured from
+    pub origin: Option<[f64; 2]>,
+    /// One entry per material with polygons, in model material order
+    pub materials: Vec<FenceMaterial>,
+}
+
+/// Section polygons of one material
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FenceMaterial {
+    pub material_id: String,
+    /// Exterior rings in component order, as `[distance, elevation]` points
+    pub polygons: Vec<Vec<[f64; 2]>>,
+}
+
 /// Spatial extent of a model
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -142,12 +185,32 @@ impl GroundModel {
             crs: None,
             extent: None,
             metadata: HashMap::new(),
+            spatial_index: None,
+            generation: 0,
         }
     }
 
     /// Add a component to the model
     pub fn add_component(&mut self, component: ModelComponent) {
-        self.components.push(component);
+        self.components_mut().push(component);
+    }
+
+    /// Get the components for editing
+    ///
+    /// Any spatial index is treated as stale afterwards, since the edits may
+    /// move footprints without changing the component count.
+    pub fn components_mut(&mut self) -> &mut Vec<ModelComponent> {
+        self.generation += 1;
+        &mut self.components
+    }
+
+    /// Add a component, failing if its material isn't in the model
+    pub fn add_component_checked(&mut self, component: ModelComponent) -> Result<()> {
+        if self.get_material(&component.material_id).is_none() {
+            return Err(Error::MaterialNotFound(component.material_id));
+        }
+        self.components_mut().push(component);
+        Ok(())
     }
 
     /// Add a material to the model
@@ -155,11 +218,73 @@ impl GroundModel {
         self.materials.push(material);
     }
 
+    /// Remove a material from the model
+    ///
+    /// Fails if any component still references the material, unless `force`
+    /// is set, in which case those references are left dangling.
+    pub fn remove_material(&mut self, id: &str, force: bool) -> Result<Material> {
+        let idx = self
+            .materials
+            .iter()
+            .position(|m| m.id == id)
+            .ok_or_else(|| Error::MaterialNotFound(id.to_string()))?;
+
+        if !force {
+            let referencing = self.get_components_by_material(id).len();
+            if referencing > 0 {
+                return Err(Error::Validation(format!(
+                    "Material '{}' is still referenced by {} component(s)",
+                    id, referencing
+                )));
+            }
+        }
+
+        Ok(self.materials.remove(idx))
+    }
+
+    /// Change a material's ID, updating every component that references it
+    pub fn rename_material(&mut self, old_id: &str, new_id: &str) -> Result<()> {
+        if old_id == new_id {
+            return Ok(());
+        }
+        if self.get_material(new_id).is_some() {
+            return Err(Error::Validation(format!(
+                "Material ID '{}' already exists",
+                new_id
+            )));
+        }
+
+        let material = self
+            .materials
+            .iter_mut()
+            .find(|m| m.id == old_id)
+            .ok_or_else(|| Error::MaterialNotFound(old_id.to_string()))?;
+        material.id = new_id.to_string();
+
+        for component in self
+            .components
+            .iter_mut()
+            .filter(|c| c.material_id == old_id)
+        {
+            component.material_id = new_id.to_string();
+        }
+
+        Ok(())
+    }
+
     /// Get a material by ID
     pub fn get_material(&self, id: &str) -> Option<&Material> {
         self.materials.iter().find(|m| m.id == id)
     }
 
+    /// Get the materials that no component references
+    pub fn unused_materials(&self) -> Vec<&Material> {
+        self.materials
+            .iter()
+            .filter(|m| !self.components.iter().any(|c| c.material_id == m.id))
+            .collect()
+    }
+
     /// Get a component by ID
     pub fn get_component(&self, id: &str) -> Option<&ModelComponent> {
         self.components.iter().find(|c| c.id == id)
@@ -173,6 +298,358 @@ impl GroundModel {
             .collect()
     }
 
+    /// Sort components deterministically for stable output
+    ///
+    /// Orders by component type, then top elevation (highest first, missing
+    /// last), then ID. Any spatial index is dropped.
+    pub fn sort_components(&mut self) {
+        self.components_mut().sort_by(|a, b| {
+            let top = match (a.top, b.top) {
+                (Some(a), Some(b)) => b.total_cmp(&a),
+                (Some(_), None) => std::cmp::Ordering::Less,
+                (None, Some(_)) => std::cmp::Ordering::Greater,
+                (None, None) => std::cmp::Ordering::Equal,
+            };
+            a.component_type
+                .cmp(&b.component_type)
+                .then(top)
+                .then_with(|| a.id.cmp(&b.id))
+        });
+    }
+
+    /// Export the components as GeoJSON features
+    ///
+    /// Each feature carries the component's ID, name, type, material and
+    /// elevations as properties. Surface geometries are written with a null
+    /// geometry.
+    pub fn to_geojson_features(&self) -> Vec<serde_json::Value> {
+        self.components
+            .iter()
+            .map(|c| {
+                serde_json::json!({
+                    "type": "Feature",
+                    "id": c.id,
+                    "geometry": c.geometry.to_geojson(),
+                    "properties": {
+                        "name": c.name,
+                        "componentType": c.component_type,
+                        "materialId": c.material_id,
+                        "top": c.top,
+                        "base": c.base,
+                    },
+                })
+            })
+            .collect()
+    }
+
+    /// Export the components as a GeoJSON FeatureCollection
+    pub fn to_geojson(&self) -> serde_json::Value {
+        serde_json::json!({
+            "type": "FeatureCollection",
+            "features": self.to_geojson_features(),
+        })
+    }
+
+    /// Build a spatial index over component footprints to speed up queries
+    ///
+    /// The index is ignored once components change through the model's
+    /// methods, including [`GroundModel::components_mut`], or if the
+    /// component count has changed since it was built. Rebuild it after
+    /// editing the `components` field directly.
+    pub fn build_spatial_index(&mut self) {
+        self.spatial_index = Some(SpatialIndex::new(&self.components, self.generation));
+    }
+
+    /// Check whether a usable spatial index is present
+    pub fn has_spatial_index(&self) -> bool {
+        self.current_index().is_some()
+    }
+
+    fn current_index(&self) -> Option<&SpatialIndex> {
+        self.spatial_index
+            .as_ref()
+            .filter(|index| index.is_current(self.generation, self.components.len()))
+    }
+
+    /// Find the material at a location
+    ///
+    /// Returns the material of the first component whose plan polygon
+    /// contains `(x, y)` (see [`Geometry::contains_point`]) and whose
+    /// elevation range contains `z`. Components without both elevations match
+    /// at any depth.
+    pub fn material_at(&self, x: f64, y: f64, z: f64) -> Option<&Material> {
+        let candidates: Vec<usize> = match self.current_index() {
+            Some(index) => index.query_point(x, y),
+            None => (0..self.components.len()).collect(),
+        };
+
+        candidates
+            .into_iter()
+            .map(|i| &self.components[i])
+            .find(|c| {
+                let in_range = match (c.top, c.base) {
+                    (Some(top), Some(base)) => base <= z && z <= top,
+                    _ => true,
+                };
+                in_range && c.geometry.contains_point(x, y)
+            })
+            .and_then(|c| self.get_material(&c.material_id))
+    }
+
+    /// Find pairs of components whose footprints and elevation ranges overlap
+    ///
+    /// Only polygon footprints are compared. Polygons that merely share an
+    /// edge do not overlap. Pairs are returned as component IDs.
+    pub fn overlapping_components(&self) -> Vec<(&str, &str)> {
+        use geo::Relate;
+
+        let index = self.current_index();
+        let bounds = self.component_bounds();
+        let footprints: Vec<_> = self
+            .components
+            .iter()
+            .map(|c| geo_types::MultiPolygon(c.geometry.plan_polygons()))
+            .collect();
+
+        let mut pairs = Vec::new();
+        for (i, a) in self.components.iter().enumerate() {
+            if footprints[i].0.is_empty() {
+                continue;
+            }
+            let candidates: Vec<usize> = match (index, &bounds[i]) {
+                (Some(index), Some(b)) => {
+                    index.query_bounds([b.min[0], b.min[1], b.max[0], b.max[1]])
+                }
+                (None, Some(a_bounds)) => (i + 1..self.components.len())
+                    .filter(|j| {
+                        bounds[*j].as_ref().is_some_and(|b| {
+                            a_bounds.min[0] <= b.max[0]
+                                && b.min[0] <= a_bounds.max[0]
+                                && a_bounds.min[1] <= b.max[1]
+                                && b.min[1] <= a_bounds.max[1]
+                        })
+                    })
+                    .collect(),
+                _ => (0..self.components.len()).collect(),
+            };
+
+            for j in candidates.into_iter().filter(|j| *j > i) {
+                let b = &self.components[j];
+                let vertical_overlap = match (a.top, a.base, b.top, b.base) {
+                    (Some(a_top), Some(a_base), Some(b_top), Some(b_base)) => {
+                        a_base.max(b_base) < a_top.min(b_top)
+                    }
+                    _ => true,
+                };
+                if !vertical_overlap || footprints[j].0.is_empty() {
+                    continue;
+                }
+
+                let matrix = footprints[i].relate(&footprints[j]);
+                if matrix.is_intersects() && !matrix.is_touches() {
+                    pairs.push((a.id.as_str(), b.id.as_str()));
+                }
+            }
+        }
+        pairs
+    }
+
+    /// Get the bounding box of every component, in component order
+    ///
+    /// Computed once so pairwise checks don't recompute bounds per pair.
+    pub fn component_bounds(&self) -> Vec<Option<BoundingBox>> {
+        self.components
+            .iter()
+            .map(ModelComponent::bounding_box)
+            .collect()
+    }
+
+    /// Approximate volume of each material, keyed by material ID
+    ///
+    /// Each component contributes its plan area times its thickness to its
+    /// material. Components without a thickness contribute nothing.
+    pub fn material_volumes(&self) -> HashMap<String, f64> {
+        let mut volumes = HashMap::new();
+        for component in &self.components {
+            *volumes.entry(component.material_id.clone()).or_insert(0.0) +=
+                component.area_2d() * component.thickness.unwrap_or(0.0);
+        }
+        volumes
+    }
+
+    /// Total volume of components whose material is a void
+    ///
+    /// Each void component contributes its plan area times its thickness.
+    /// Components without a thickness contribute nothing.
+    pub fn void_volume(&self) -> f64 {
+        self.components
+            .iter()
+            .filter(|c| {
+                self.get_material(&c.material_id)
+                    .is_some_and(|m| m.material_type == MaterialType::Void)
+            })
+            .map(|c| c.area_2d() * c.thickness.unwrap_or(0.0))
+            .sum()
+    }
+
+    /// Sample the vertical sequence of components at a plan location
+    ///
+    /// Uses each component's plan polygon to decide whether it is present at
+    /// `(x, y)` (see [`Geometry::contains_point`]) and its `top`/`base`
+    /// elevations for the interval. Components
+    /// without both elevations are skipped. Intervals are ordered top down.
+    pub fn sample_column(&self, x: f64, y: f64) -> Vec<ColumnInterval> {
+        let mut intervals: Vec<ColumnInterval> = self
+            .components
+            .iter()
+            .filter(|c| c.geometry.contains_point(x, y))
+            .filter_map(|c| {
+                Some(ColumnInterval {
+                    component_id: c.id.clone(),
+                    material_id: c.material_id.clone(),
+                    top: c.top?,
+                    base: c.base?,
+                })
+            })
+            .collect();
+
+        intervals.sort_by(|a, b| b.top.total_cmp(&a.top));
+        intervals
+    }
+
+    /// Convert a 2D cross-section into polygons per material for a fence diagram
+    ///
+    /// The section line runs between the two exterior ring vertices farthest
+    /// apart in plan, starting from the end nearer the first vertex of the
+    /// first polygon component. Each vertex becomes `[distance, z]`, with the
+    /// distance projected onto the section line from its start, so vertices
+    /// behind the start come out negative. Components without polygon
+    /// geometry are skipped.
+    pub fn fence_diagram(&self) -> FenceData {
+        let exteriors: Vec<(&ModelComponent, &Vec<[f64; 3]>)> = self
+            .components
+            .iter()
+            .filter_map(|component| match &component.geometry {
+                Geometry::Polygon { rings, .. } => rings
+                    .first()
+                    .filter(|ring| !ring.is_empty())
+                    .map(|ring| (component, ring)),
+                _ => None,
+            })
+            .collect();
+        let Some(first) = exteriors.first().map(|(_, ring)| [ring[0][0], ring[0][1]]) else {
+            return FenceData::default();
+        };
+
+        // Section ends: the pair of plan vertices farthest apart
+        let vertices: Vec<[f64; 2]> = exteriors
+            .iter()
+            .flat_map(|(_, ring)| ring.iter().map(|c| [c[0], c[1]]))
+            .collect();
+        let distance = |a: [f64; 2], b: [f64; 2]| (b[0] - a[0]).hypot(b[1] - a[1]);
+        let (mut start, mut end) = (first, first);
+        for (i, &a) in vertices.iter().enumerate() {
+            for &b in &vertices[i + 1..] {
+                if distance(a, b) > distance(start, end) {
+                    (start, end) = (a, b);
+                }
+            }
+        }
+        if distance(first, end) < distance(first, start) {
+            std::mem::swap(&mut start, &mut end);
+        }
+        let length = distance(start, end);
+        let direction = if length > 0.0 {
+            [(end[0] - start[0]) / length, (end[1] - start[1]) / length]
+        } else {
+            [0.0, 0.0]
+        };
+
+        let mut fence = FenceData {
+            origin: Some(start),
+            materials: Vec::new(),
+        };
+        for (component, exterior) in exteriors {
+            let polygon = exterior
+                .iter()
+                .map(|c| {
+                    [
+                        (c[0] - start[0]) * direction[0] + (c[1] - start[1]) * direction[1],
+                        c[2],
+                    ]
+                })
+                .collect();
+
+            match fence
+                .materials
+                .iter_mut()
+                .find(|m| m.material_id == component.material_id)
+            {
+                Some(material) => material.polygons.push(polygon),
+                None => fence.materials.push(FenceMaterial {
+                    material_id: component.material_id.clone(),
+                    polygons: vec![polygon],
+                }),
+            }
+        }
+
+        let position = |id: &str| {
+            self.materials
+                .iter()
+                .position(|m| m.id == id)
+                .unwrap_or(usize::MAX)
+        };
+        fence.materials.sort_by_key(|m| position(&m.material_id));
+        fence
+    }
+
+    /// Build a 1D model representing the column of materials at a location
+    ///
+    /// Materials referenced by the column are copied across unchanged.
+    pub fn flatten_column(&self, x: f64, y: f64) -> Result<GroundModel> {
+        let intervals = self.sample_column(x, y);
+        if intervals.is_empty() {
+            return Err(Error::Geometry(format!(
+                "No components with elevations found at ({}, {})",
+                x, y
+            )));
+        }
+
+        let mut column = GroundModel::new(
+            format!("{}_COLUMN", self.id),
+            format!("{} column at ({}, {})", self.name, x, y),
+            self.model_type,
+            ModelDimension::OneD,
+        );
+        column.crs = self.crs.clone();
+
+        for interval in intervals {
+            if column.get_material(&interval.material_id).is_none() {
+                if let Some(material) = self.get_material(&interval.material_id) {
+                    column.add_material(material.clone());
+                }
+            }
+
+            let geometry = Geometry::linestring(vec![[x, y, interval.top], [x, y, interval.base]])?;
+            let name = self
+                .get_component(&interval.component_id)
+                .map(|c| c.name.clone())
+                .unwrap_or_else(|| interval.component_id.clone());
+            column.add_component(
+                ModelComponent::new(
+                    interval.component_id,
+                    name,
+                    ComponentType::Layer,
+                    interval.material_id,
+                    geometry,
+                )
+                .with_elevations(interval.top, interval.base),
+            );
+        }
+
+        Ok(column)
+    }
+
     /// Set the coordinate reference system
     pub fn with_crs(mut self, crs: impl Into<String>) -> Self {
         self.crs = Some(crs.into());
@@ -216,6 +693,25 @@ impl ModelComponent {
         self
     }
 
+    /// Get the plan (XY) area of the component's polygon footprint
+    ///
+    /// Holes are excluded whatever their winding, unlike
+    /// [`Geometry::area_2d`]. Geometries without a plan area return `0.0`.
+    pub fn area_2d(&self) -> f64 {
+        use geo::Area;
+
+        self.geometry
+            .plan_polygons()
+            .iter()
+            .map(|p| p.unsigned_area())
+            .sum()
+    }
+
+    /// Get the 3D bounding box of the component's geometry
+    pub fn bounding_box(&self) -> Option<BoundingBox> {
+        self.geometry.bounding_box()
+    }
+
     /// Set thickness
     pub fn with_thickness(mut self, thickness: f64) -> Self {
         self.thickness = Some(thickness);
@@ -247,14 +743,7 @@ impl ModelExtent {
     }
 
     /// Create a 3D extent
-    pub fn new_3d(
-        min_x: f64,
-        max_x: f64,
-        min_y: f64,
-        max_y: f64,
-        min_z: f64,
-        max_z: f64,
-    ) -> Self {
+    pub fn new_3d(min_x: f64, max_x: f64, min_y: f64, max_y: f64, min_z: f64, max_z: f64) -> Self {
         Self {
             min_x,
             max_x,
@@ -280,7 +769,6 @@ impl ModelExtent {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::material::MaterialType;
 
     #[test]
     fn test_create_ground_model() {
@@ -298,10 +786,600 @@ mod tests {
         assert_eq!(model.materials.len(), 1);
     }
 
+    #[test]
+    fn test_add_component_checked() {
+        let mut model = GroundModel::new(
+            "MODEL001",
+            "Test",
+            ModelType::Stratigraphic,
+            ModelDimension::TwoD,
+        );
+        model.add_material(Material::new("MAT001", "Clay", MaterialType::Soil));
+
+        let bogus = ModelComponent::new(
+            "COMP001",
+            "Layer",
+            ComponentType::Layer,
+            "MAT_BOGUS",
+            Geometry::point(0.0, 0.0, 0.0),
+        );
+        assert!(matches!(
+            model.add_component_checked(bogus),
+            Err(Error::MaterialNotFound(id)) if id == "MAT_BOGUS"
+        ));
+        assert!(model.components.is_empty());
+
+        let valid = ModelComponent::new(
+            "COMP002",
+            "Layer",
+            ComponentType::Layer,
+            "MAT001",
+            Geometry::point(0.0, 0.0, 0.0),
+        );
+        assert!(model.add_component_checked(valid).is_ok());
+        assert_eq!(model.components.len(), 1);
+    }
+
+    #[test]
+    fn test_remove_material() {
+        let mut model = GroundModel::new(
+            "MODEL001",
+            "Test",
+            ModelType::Stratigraphic,
+            ModelDimension::TwoD,
+        );
+        model.add_material(Material::new("MAT001", "Clay", MaterialType::Soil));
+        model.add_material(Material::new("MAT002", "Sand", MaterialType::Soil));
+        model.add_component(ModelComponent::new(
+            "COMP001",
+            "Layer",
+            ComponentType::Layer,
+            "MAT001",
+            Geometry::point(0.0, 0.0, 0.0),
+        ));
+
+        assert!(model.remove_material("MAT001", false).is_err());
+        assert_eq!(model.materials.len(), 2);
+
+        assert_eq!(model.remove_material("MAT002", false).unwrap().id, "MAT002");
+        assert_eq!(model.remove_material("MAT001", true).unwrap().id, "MAT001");
+        assert!(model.materials.is_empty());
+        assert!(model.remove_material("MAT001", true).is_err());
+    }
+
+    #[test]
+    fn test_rename_material() {
+        let mut model = GroundModel::new(
+            "MODEL001",
+            "Test",
+            ModelType::Stratigraphic,
+            ModelDimension::TwoD,
+        );
+        model.add_material(Material::new("MAT001", "Clay", MaterialType::Soil));
+        model.add_material(Material::new("MAT002", "Sand", MaterialType::Soil));
+        for id in ["COMP001", "COMP002"] {
+            model.add_component(ModelComponent::new(
+                id,
+                "Layer",
+                ComponentType::Layer,
+                "MAT001",
+                Geometry::point(0.0, 0.0, 0.0),
+            ));
+        }
+
+        model.rename_material("MAT001", "CLAY").unwrap();
+
+        assert!(model.get_material("MAT001").is_none());
+        assert!(model.get_material("CLAY").is_some());
+        assert_eq!(model.get_components_by_material("CLAY").len(), 2);
+        assert!(model.get_components_by_material("MAT001").is_empty());
+
+        assert!(model.rename_material("CLAY", "MAT002").is_err());
+        assert!(model.rename_material("MISSING", "NEW").is_err());
+    }
+
+    #[test]
+    fn test_flatten_column() {
+        let mut model = GroundModel::new(
+            "MODEL001",
+            "3D Model",
+            ModelType::Stratigraphic,
+            ModelDimension::ThreeD,
+        );
+        model.add_material(Material::new("MAT001", "Clay", MaterialType::Soil));
+        model.add_material(Material::new("MAT002", "Chalk", MaterialType::Rock));
+        model.add_material(Material::new("MAT003", "Unused", MaterialType::Fill));
+
+        let footprint = || {
+            Geometry::polygon(
+                vec![
+                    [0.0, 0.0, 0.0],
+                    [100.0, 0.0, 0.0],
+                    [100.0, 100.0, 0.0],
+                    [0.0, 100.0, 0.0],
+                    [0.0, 0.0, 0.0],
+                ],
+                vec![],
+            )
+            .unwrap()
+        };
+        model.add_component(
+            ModelComponent::new(
+                "VOL002",
+                "Chalk",
+                ComponentType::Volume,
+                "MAT002",
+                footprint(),
+            )
+            .with_elevations(-10.0, -50.0),
+        );
+        model.add_component(
+            ModelComponent::new(
+                "VOL001",
+                "Clay",
+                ComponentType::Volume,
+                "MAT001",
+                footprint(),
+            )
+            .with_elevations(0.0, -10.0),
+        );
+
+        let column = model.flatten_column(50.0, 50.0).unwrap();
+        assert_eq!(column.dimension, ModelDimension::OneD);
+        assert_eq!(column.components.len(), 2);
+        assert_eq!(column.components[0].material_id, "MAT001");
+        assert_eq!(column.components[1].material_id, "MAT002");
+        assert_eq!(column.components[1].top, Some(-10.0));
+        assert_eq!(column.materials.len(), 2);
+
+        assert!(model.flatten_column(500.0, 500.0).is_err());
+    }
+
+    fn square(x: f64, y: f64, size: f64) -> Geometry {
+        Geometry::polygon(
+            vec![
+                [x, y, 0.0],
+                [x + size, y, 0.0],
+                [x + size, y + size, 0.0],
+                [x, y + size, 0.0],
+                [x, y, 0.0],
+            ],
+            vec![],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_unused_materials() {
+        let mut model = GroundModel::new(
+            "MODEL001",
+            "Model",
+            ModelType::Stratigraphic,
+            ModelDimension::TwoD,
+        );
+        model.add_material(Material::new("MAT001", "Clay", MaterialType::Soil));
+        model.add_material(Material::new("MAT002", "Sand", MaterialType::Soil));
+        model.add_component(ModelComponent::new(
+            "COMP001",
+            "Clay layer",
+            ComponentType::Layer,
+            "MAT001",
+            Geometry::point(0.0, 0.0, 0.0),
+        ));
+
+        let unused: Vec<_> = model
+            .unused_materials()
+            .iter()
+            .map(|m| m.id.as_str())
+            .collect();
+        assert_eq!(unused, ["MAT002"]);
+    }
+
+    #[test]
+    fn test_fence_diagram_two_layers() {
+        let mut model = GroundModel::new(
+            "SECTION",
+            "Section A-A",
+            ModelType::Stratigraphic,
+            ModelDimension::TwoD,
+        );
+        model.add_material(Material::new("MAT001", "Clay", MaterialType::Soil));
+        model.add_material(Material::new("MAT002", "Sand", MaterialType::Soil));
+
+        // Section runs along x = y, elevations in z
+        let layer = |id: &str, material: &str, top: f64, base: f64| {
+            let ring = vec![
+                [100.0, 100.0, top],
+                [130.0, 140.0, top],
+                [130.0, 140.0, base],
+                [100.0, 100.0, base],
+                [100.0, 100.0, top],
+            ];
+            ModelComponent::new(
+                id,
+                id,
+                ComponentType::Layer,
+                material,
+                Geometry::polygon(ring, vec![]).unwrap(),
+            )
+        };
+        model.add_component(layer("SAND", "MAT002", 5.0, 0.0));
+        model.add_component(layer("CLAY", "MAT001", 10.0, 5.0));
+
+        let fence = model.fence_diagram();
+        assert_eq!(fence.origin, Some([100.0, 100.0]));
+        assert_eq!(
+            fence
+                .materials
+                .iter()
+                .map(|m| m.polygons.len())
+                .sum::<usize>(),
+            2
+        );
+        assert_eq!(fence.materials[0].material_id, "MAT001");
+        assert_eq!(fence.materials[0].polygons[0][1], [50.0, 10.0]);
+    }
+
+    #[test]
+    fn test_fence_diagram_lens_listed_first() {
+        let mut model = GroundModel::new(
+            "SECTION",
+            "Section B-B",
+            ModelType::Stratigraphic,
+            ModelDimension::TwoD,
+        );
+        model.add_material(Material::new("MAT001", "Clay", MaterialType::Soil));
+        model.add_material(Material::new("MAT002", "Sand", MaterialType::Soil));
+
+        // A sand lens in the middle of the section comes before the clay layer
+        let lens = vec![
+            [40.0, 0.0, 6.0],
+            [60.0, 0.0, 6.0],
+            [60.0, 0.0, 4.0],
+            [40.0, 0.0, 4.0],
+            [40.0, 0.0, 6.0],
+        ];
+        let clay = vec![
+            [0.0, 0.0, 10.0],
+            [100.0, 0.0, 10.0],
+            [100.0, 0.0, 0.0],
+            [0.0, 0.0, 0.0],
+            [0.0, 0.0, 10.0],
+        ];
+        // The old origin, the lens's first vertex, lay in the middle of the clay
+        for (id, kind, material, ring) in [
+            ("LENS", ComponentType::Lens, "MAT002", lens),
+            ("CLAY", ComponentType::Layer, "MAT001", clay),
+        ] {
+            model.add_component(ModelComponent::new(
+                id,
+                id,
+                kind,
+                material,
+                Geometry::polygon(ring, vec![]).unwrap(),
+            ));
+        }
+
+        let fence = model.fence_diagram();
+        assert_eq!(fence.origin, Some([0.0, 0.0]));
+        let clay: Vec<f64> = fence.materials[0].polygons[0]
+            .iter()
+            .map(|p| p[0])
+            .collect();
+        assert_eq!(clay, vec![0.0, 100.0, 100.0, 0.0, 0.0]);
+        assert_eq!(fence.materials[1].polygons[0][0], [40.0, 6.0]);
+    }
+
+    #[test]
+    fn test_material_at_with_spatial_index() {
+        let mut model = GroundModel::new(
+            "MODEL001",
+            "Grid Model",
+            ModelType::Stratigraphic,
+            ModelDimension::ThreeD,
+        );
+        model.add_material(Material::new("MAT001", "Clay", MaterialType::Soil));
+        model.add_material(Material::new("MAT002", "Sand", MaterialType::Soil));
+
+        for i in 0..20 {
+            for j in 0..20 {
+                let material = if (i + j) % 2 == 0 { "MAT001" } else { "MAT002" };
+                model.add_component(
+                    ModelComponent::new(
+                        format!("C{}_{}", i, j),
+                        "Cell",
+                        ComponentType::Volume,
+                        material,
+                        square(i as f64 * 10.0, j as f64 * 10.0, 10.0),
+                    )
+                    .with_elevations(0.0, -10.0),
+                );
+            }
+        }
+
+        let points = [
+            (5.0, 5.0, -5.0),
+            (15.0, 5.0, -1.0),
+            (123.4, 56.7, -9.0),
+            (5.0, 5.0, -20.0),
+            (500.0, 5.0, -5.0),
+        ];
+        let unindexed: Vec<_> = points
+            .iter()
+            .map(|(x, y, z)| model.material_at(*x, *y, *z).map(|m| m.id.clone()))
+            .collect();
+
+        model.build_spatial_index();
+        assert!(model.has_spatial_index());
+        let indexed: Vec<_> = points
+            .iter()
+            .map(|(x, y, z)| model.material_at(*x, *y, *z).map(|m| m.id.clone()))
+            .collect();
+
+        assert_eq!(indexed, unindexed);
+        assert_eq!(indexed[0].as_deref(), Some("MAT001"));
+        assert_eq!(indexed[1].as_deref(), Some("MAT002"));
+        assert_eq!(indexed[3], None);
+        assert_eq!(indexed[4], None);
+
+        // Moving a component leaves the count alone but still invalidates the index
+        model.components_mut()[0].geometry = square(500.0, 0.0, 10.0);
+        assert!(!model.has_spatial_index());
+        assert_eq!(
+            model.material_at(505.0, 5.0, -5.0).map(|m| m.id.as_str()),
+            Some("MAT001")
+        );
+
+        // Adding a component does too
+        model.build_spatial_index();
+        model.add_component(ModelComponent::new(
+            "EXTRA",
+            "Extra",
+            ComponentType::Lens,
+            "MAT001",
+            square(0.0, 0.0, 1.0),
+        ));
+        assert!(!model.has_spatial_index());
+    }
+
+    #[test]
+    fn test_overlapping_components() {
+        let mut model = GroundModel::new(
+            "MODEL001",
+            "Test Model",
+            ModelType::Stratigraphic,
+            ModelDimension::ThreeD,
+        );
+        model.add_material(Material::new("MAT001", "Clay", MaterialType::Soil));
+        model.add_component(
+            ModelComponent::new(
+                "A",
+                "A",
+                ComponentType::Volume,
+                "MAT001",
+                square(0.0, 0.0, 10.0),
+            )
+            .with_elevations(0.0, -10.0),
+        );
+        // Overlaps A in plan and depth
+        model.add_component(
+            ModelComponent::new(
+                "B",
+                "B",
+                ComponentType::Volume,
+                "MAT001",
+                square(5.0, 5.0, 10.0),
+            )
+            .with_elevations(-5.0, -15.0),
+        );
+        // Shares an edge with A only
+        model.add_component(
+            ModelComponent::new(
+                "C",
+                "C",
+                ComponentType::Volume,
+                "MAT001",
+                square(10.0, -10.0, 10.0),
+            )
+            .with_elevations(0.0, -10.0),
+        );
+        // Below A, no vertical overlap
+        model.add_component(
+            ModelComponent::new(
+                "D",
+                "D",
+                ComponentType::Volume,
+                "MAT001",
+                square(0.0, 0.0, 10.0),
+            )
+            .with_elevations(-20.0, -30.0),
+        );
+
+        assert_eq!(model.overlapping_components(), vec![("A", "B")]);
+        model.build_spatial_index();
+        assert_eq!(model.overlapping_components(), vec![("A", "B")]);
+    }
+
+    #[test]
+    fn test_component_bounds() {
+        let mut model = GroundModel::new(
+            "MODEL001",
+            "Test Model",
+            ModelType::Stratigraphic,
+            ModelDimension::TwoD,
+        );
+        model.add_material(Material::new("MAT001", "Clay", MaterialType::Soil));
+        for (id, geometry) in [
+            ("A", square(0.0, 0.0, 10.0)),
+            ("B", Geometry::point(1.0, 2.0, 3.0)),
+            ("C", Geometry::placeholder()),
+        ] {
+            model.add_component(ModelComponent::new(
+                id,
+                id,
+                ComponentType::Layer,
+                "MAT001",
+                geometry,
+            ));
+        }
+
+        let bounds = model.component_bounds();
+        assert_eq!(bounds.len(), 3);
+        for (cached, component) in bounds.iter().zip(&model.components) {
+            assert_eq!(cached, &component.bounding_box());
+        }
+        assert_eq!(
+            bounds[1],
+            Some(BoundingBox {
+                min: [1.0, 2.0, 3.0],
+                max: [1.0, 2.0, 3.0]
+            })
+        );
+        assert!(bounds[2].is_none());
+    }
+
+    #[test]
+    fn test_void_volume() {
+        let mut model = GroundModel::new(
+            "MODEL001",
+            "Test Model",
+            ModelType::Stratigraphic,
+            ModelDimension::ThreeD,
+        );
+        model.add_material(Material::new("MAT001", "Clay", MaterialType::Soil));
+        model.add_material(Material::new("VOID001", "Cavity", MaterialType::Void));
+        model.add_component(
+            ModelComponent::new(
+                "A",
+                "A",
+                ComponentType::Layer,
+                "MAT001",
+                square(0.0, 0.0, 10.0),
+            )
+            .with_thickness(5.0),
+        );
+        model.add_component(
+            ModelComponent::new(
+                "V",
+                "V",
+                ComponentType::Volume,
+                "VOID001",
+                square(0.0, 0.0, 4.0),
+            )
+            .with_thickness(3.0),
+        );
+
+        assert_eq!(model.void_volume(), 48.0);
+    }
+
+    #[test]
+    fn test_material_volumes() {
+        let mut model = GroundModel::new(
+            "MODEL001",
+            "Test Model",
+            ModelType::Stratigraphic,
+            ModelDimension::ThreeD,
+        );
+        model.add_material(Material::new("MAT001", "Clay", MaterialType::Soil));
+        model.add_component(
+            ModelComponent::new(
+                "A",
+                "A",
+                ComponentType::Layer,
+                "MAT001",
+                square(0.0, 0.0, 10.0),
+            )
+            .with_thickness(2.0),
+        );
+
+        let volumes = model.material_volumes();
+        assert_eq!(volumes.len(), 1);
+        assert_eq!(volumes["MAT001"], 200.0);
+
+        // A hole wound the same way as the exterior still subtracts
+        let outer = vec![
+            [0.0, 0.0, 0.0],
+            [10.0, 0.0, 0.0],
+            [10.0, 10.0, 0.0],
+            [0.0, 10.0, 0.0],
+            [0.0, 0.0, 0.0],
+        ];
+        let hole = vec![
+            [4.0, 4.0, 0.0],
+            [6.0, 4.0, 0.0],
+            [6.0, 6.0, 0.0],
+            [4.0, 6.0, 0.0],
+            [4.0, 4.0, 0.0],
+        ];
+        model.components[0].geometry = Geometry::polygon(outer, vec![hole]).unwrap();
+        assert_eq!(model.material_volumes()["MAT001"], 192.0);
+    }
+
+    #[test]
+    fn test_metadata_serializes_in_sorted_order() {
+        let keys = [
+            "zeta", "alpha", "mu", "beta", "omega", "gamma", "delta", "kappa",
+        ];
+        let build = |keys: Vec<&str>| {
+            let mut model = GroundModel::new(
+                "MODEL001",
+                "Test Model",
+                ModelType::Stratigraphic,
+                ModelDimension::TwoD,
+            );
+            for key in keys {
+                model
+                    .metadata
+                    .insert(key.to_string(), serde_json::json!(key.len()));
+            }
+            model
+        };
+        let forward = build(keys.to_vec());
+        let reverse = build(keys.iter().rev().copied().collect());
+
+        let json = serde_json::to_string(&forward).unwrap();
+        assert_eq!(json, serde_json::to_string(&reverse).unwrap());
+        assert!(json.find("\"alpha\"").unwrap() < json.find("\"zeta\"").unwrap());
+    }
+
+    #[test]
+    fn test_sort_components() {
+        let mut model = GroundModel::new(
+            "MODEL001",
+            "Test Model",
+            ModelType::Stratigraphic,
+            ModelDimension::OneD,
+        );
+        let layer = |id: &str, top: f64| {
+            ModelComponent::new(
+                id,
+                id,
+                ComponentType::Layer,
+                "MAT001",
+                Geometry::point(0.0, 0.0, top),
+            )
+            .with_elevations(top, top - 5.0)
+        };
+        model.add_component(layer("B", -5.0));
+        model.add_component(ModelComponent::new(
+            "A",
+            "A",
+            ComponentType::Lens,
+            "MAT001",
+            Geometry::point(0.0, 0.0, 0.0),
+        ));
+        model.add_component(layer("C", 10.0));
+        model.add_component(layer("D", 0.0));
+
+        model.sort_components();
+        let order: Vec<&str> = model.components.iter().map(|c| c.id.as_str()).collect();
+        assert_eq!(order, vec!["C", "D", "B", "A"]);
+    }
+
     #[test]
     fn test_model_extent() {
         let extent = ModelExtent::new_2d(0.0, 100.0, 0.0, 100.0);
-        
+
         assert!(extent.contains(50.0, 50.0, None));
         assert!(!extent.contains(150.0, 50.0, None));
     }
@@ -309,7 +1387,7 @@ mod tests {
     #[test]
     fn test_model_extent_3d() {
         let extent = ModelExtent::new_3d(0.0, 100.0, 0.0, 100.0, -10.0, 10.0);
-        
+
         assert!(extent.contains(50.0, 50.0, Some(0.0)));
         assert!(!extent.contains(50.0, 50.0, Some(20.0)));
     }
@@ -1,7 +1,10 @@
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use validator::Validate;
 
+use crate::document::{format_iso8601, parse_iso8601, serialize_sorted};
+
 /// Project information for AGSi documents
 #[derive(Debug, Clone, Serialize, Deserialize, Validate)]
 #[serde(rename_all = "camelCase")]
@@ -30,7 +33,7 @@ pub struct Project {
     pub dates: Option<ProjectDates>,
 
     /// Additional metadata
-    #[serde(default)]
+    #[serde(default, serialize_with = "serialize_sorted")]
     pub metadata: HashMap<String, serde_json::Value>,
 }
 
@@ -52,7 +55,7 @@ pub struct Location {
 }
 
 /// Project date information
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ProjectDates {
     /// Project start date (ISO 8601)
@@ -105,6 +108,38 @@ impl Project {
     }
 }
 
+impl ProjectDates {
+    /// Get the start date as a typed timestamp, if set and parseable
+    pub fn start_parsed(&self) -> Option<DateTime<Utc>> {
+        self.start.as_deref().and_then(parse_iso8601)
+    }
+
+    /// Get the end date as a typed timestamp, if set and parseable
+    pub fn end_parsed(&self) -> Option<DateTime<Utc>> {
+        self.end.as_deref().and_then(parse_iso8601)
+    }
+
+    /// Get the data collection date as a typed timestamp, if set and parseable
+    pub fn data_collection_parsed(&self) -> Option<DateTime<Utc>> {
+        self.data_collection.as_deref().and_then(parse_iso8601)
+    }
+
+    /// Set the start date from a typed timestamp
+    pub fn set_start(&mut self, date: DateTime<Utc>) {
+        self.start = Some(format_iso8601(date));
+    }
+
+    /// Set the end date from a typed timestamp
+    pub fn set_end(&mut self, date: DateTime<Utc>) {
+        self.end = Some(format_iso8601(date));
+    }
+
+    /// Set the data collection date from a typed timestamp
+    pub fn set_data_collection(&mut self, date: DateTime<Utc>) {
+        self.data_collection = Some(format_iso8601(date));
+    }
+}
+
 impl Location {
     /// Create a new location
     pub fn new(name: impl Into<String>) -> Self {
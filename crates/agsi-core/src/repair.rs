@@ -0,0 +1,162 @@
+//! Automatic fixes for problems that can be corrected without judgement
+
+use serde::Serialize;
+
+use crate::geometry::{signed_area, Geometry};
+use crate::Document;
+
+/// A single fix applied by [`repair`]
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Repair {
+    /// Code of the validation check the fix addresses, e.g. `geometry-ring-closure`
+    pub code: String,
+    /// Location of the fixed value, in the same form as validation paths
+    pub path: String,
+    pub description: String,
+}
+
+impl Repair {
+    fn new(code: &str, path: impl Into<String>, description: impl Into<String>) -> Self {
+        Self {
+            code: code.to_string(),
+            path: path.into(),
+            description: description.into(),
+        }
+    }
+}
+
+/// Apply the safe subset of fixes to a document
+///
+/// Closes polygon rings that have at least three points, reverses rings with
+/// the wrong winding and swaps inverted model extent bounds. Anything that
+/// needs a decision, such as duplicate IDs or missing materials, is left for
+/// validation to report. Running it again on its own output changes nothing.
+pub fn repair(doc: &mut Document) -> Vec<Repair> {
+    let mut repairs = Vec::new();
+
+    for (model_idx, model) in doc.agsi_model.iter_mut().enumerate() {
+        if let Some(ref mut extent) = model.extent {
+            let path = format!("agsiModel[{}].extent", model_idx);
+            if extent.min_x > extent.max_x {
+                std::mem::swap(&mut extent.min_x, &mut extent.max_x);
+                repairs.push(Repair::new(
+                    "extent-range",
+                    &path,
+                    "Swapped min_x and max_x",
+                ));
+            }
+            if extent.min_y > extent.max_y {
+                std::mem::swap(&mut extent.min_y, &mut extent.max_y);
+                repairs.push(Repair::new(
+                    "extent-range",
+                    &path,
+                    "Swapped min_y and max_y",
+                ));
+            }
+            if let (Some(min_z), Some(max_z)) = (extent.min_z, extent.max_z) {
+                if min_z > max_z {
+                    extent.min_z = Some(max_z);
+                    extent.max_z = Some(min_z);
+                    repairs.push(Repair::new(
+                        "extent-range",
+                        &path,
+                        "Swapped min_z and max_z",
+                    ));
+                }
+            }
+        }
+
+        for (comp_idx, component) in model.components_mut().iter_mut().enumerate() {
+            let path = format!("agsiModel[{}].components[{}].geometry", model_idx, comp_idx);
+            repair_geometry(&mut component.geometry, &path, &mut repairs);
+        }
+    }
+
+    repairs
+}
+
+fn repair_geometry(geometry: &mut Geometry, path: &str, repairs: &mut Vec<Repair>) {
+    match geometry {
+        Geometry::Polygon {
+            rings, wkt, wkb, ..
+        } => {
+            let before = repairs.len();
+            for (ring_idx, ring) in rings.iter_mut().enumerate() {
+                let ring_path = format!("{}.rings[{}]", path, ring_idx);
+
+                let closed = ring
+                    .first()
+                    .zip(ring.last())
+                    .is_some_and(|(first, last)| first[0] == last[0] && first[1] == last[1]);
+                if !closed && ring.len() >= 3 {
+                    ring.push(ring[0]);
+                    repairs.push(Repair::new(
+                        "geometry-ring-closure",
+                        &ring_path,
+                        "Closed ring",
+                    ));
+                }
+                if ring.len() < 4 {
+                    continue;
+                }
+
+                // Exterior rings run counter-clockwise, holes clockwise (RFC 7946)
+                let area = signed_area(ring);
+                if (ring_idx == 0 && area < 0.0) || (ring_idx > 0 && area > 0.0) {
+                    ring.reverse();
+                    repairs.push(Repair::new(
+                        "geometry-winding",
+                        &ring_path,
+                        "Reversed ring winding",
+                    ));
+                }
+            }
+
+            // Cached representations no longer match the rings
+            if repairs.len() > before {
+                *wkt = None;
+                *wkb = None;
+            }
+        }
+        Geometry::Collection { geometries, .. } => {
+            for (idx, member) in geometries.iter_mut().enumerate() {
+                repair_geometry(member, &format!("{}.geometries[{}]", path, idx), repairs);
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{GroundModel, ModelDimension, ModelExtent, ModelType};
+
+    #[test]
+    fn test_repair_is_idempotent() {
+        let mut doc = Document::new("DOC001");
+        let mut model = GroundModel::new(
+            "MODEL001",
+            "Model",
+            ModelType::Stratigraphic,
+            ModelDimension::TwoD,
+        );
+        model.extent = Some(ModelExtent {
+            min_x: 10.0,
+            max_x: 0.0,
+            min_y: 0.0,
+            max_y: 10.0,
+            min_z: None,
+            max_z: None,
+        });
+        doc.add_model(model);
+
+        let repairs = repair(&mut doc);
+        assert_eq!(repairs.len(), 1);
+        assert_eq!(repairs[0].code, "extent-range");
+        assert_eq!(doc.agsi_model[0].extent.as_ref().unwrap().min_x, 0.0);
+
+        assert!(repair(&mut doc).is_empty());
+    }
+}
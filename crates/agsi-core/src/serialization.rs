@@ -1,34 +1,180 @@
 use crate::{Document, Error, Result};
 
 /// Serialization formats supported by AGSi
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Format {
     Json,
     JsonCompact,
+    Yaml,
     Avro,
     Protobuf,
+    /// Detect the format from the data when deserializing
+    Unknown,
 }
 
+/// Magic bytes at the start of an Avro object container file
+const AVRO_MAGIC: &[u8] = b"Obj\x01";
+
+/// Byte order mark some Windows tools write at the start of UTF-8 text
+const UTF8_BOM: &[u8] = b"\xEF\xBB\xBF";
+
 /// Serialize a document to bytes in the specified format
 pub fn serialize(doc: &Document, format: Format) -> Result<Vec<u8>> {
     match format {
         Format::Json => serialize_json(doc, true),
         Format::JsonCompact => serialize_json(doc, false),
+        Format::Yaml => serialize_yaml(doc),
         Format::Avro => serialize_avro(doc),
         Format::Protobuf => serialize_protobuf(doc),
+        Format::Unknown => Err(Error::Serialization(
+            "An output format must be given to serialize".to_string(),
+        )),
     }
 }
 
 /// Deserialize a document from bytes
+///
+/// With [`Format::Unknown`] the format is sniffed with [`detect_format`].
 pub fn deserialize(data: &[u8], format: Format) -> Result<Document> {
     match format {
         Format::Json | Format::JsonCompact => deserialize_json(data),
+        Format::Yaml => deserialize_yaml(data),
         Format::Avro => deserialize_avro(data),
         Format::Protobuf => deserialize_protobuf(data),
+        Format::Unknown => {
+            let detected = detect_format(data).ok_or_else(|| {
+                Error::Deserialization("Could not detect the format of the data".to_string())
+            })?;
+            deserialize(data, detected)
+        }
+    }
+}
+
+/// Guess the format of serialized data from its leading bytes
+///
+/// Recognises JSON (an object after optional whitespace or a UTF-8 BOM) and
+/// Avro container files (`Obj\x01`). Anything else, including MessagePack or
+/// CBOR maps which have no [`Format`] to read them with, gives `None`.
+pub fn detect_format(data: &[u8]) -> Option<Format> {
+    if data.starts_with(AVRO_MAGIC) {
+        return Some(Format::Avro);
+    }
+
+    let data = data.strip_prefix(UTF8_BOM).unwrap_or(data);
+    let first = *data.iter().find(|b| !b.is_ascii_whitespace())?;
+    (first == b'{').then_some(Format::Json)
+}
+
+/// A value that differs between a document and its JSON round trip
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValueMismatch {
+    /// RFC 6901 JSON pointer to the value
+    pub pointer: String,
+    /// Value before the round trip, `None` if it was absent
+    pub before: Option<serde_json::Value>,
+    /// Value after the round trip, `None` if it was lost
+    pub after: Option<serde_json::Value>,
+}
+
+/// Serialize a document to JSON, parse it back and list every value that changed
+pub fn roundtrip_diff(doc: &Document) -> Result<Vec<ValueMismatch>> {
+    let before = serde_json::to_value(doc)?;
+    let reparsed = Document::from_json_str(&serde_json::to_string(doc)?)?;
+    let after = serde_json::to_value(&reparsed)?;
+
+    let mut mismatches = Vec::new();
+    diff_values(String::new(), Some(&before), Some(&after), &mut mismatches);
+    Ok(mismatches)
+}
+
+/// Check that a document survives a JSON round trip unchanged
+///
+/// Fails with the JSON pointers of any values that changed, see
+/// [`roundtrip_diff`] for the values themselves.
+pub fn roundtrip_check(doc: &Document) -> Result<()> {
+    let mismatches = roundtrip_diff(doc)?;
+    if mismatches.is_empty() {
+        return Ok(());
+    }
+    let pointers: Vec<&str> = mismatches.iter().map(|m| m.pointer.as_str()).collect();
+    Err(Error::Serialization(format!(
+        "JSON round trip changed {} value(s): {}",
+        mismatches.len(),
+        pointers.join(", ")
+    )))
+}
+
+fn diff_values(
+    pointer: String,
+    before: Option<&serde_json::Value>,
+    after: Option<&serde_json::Value>,
+    mismatches: &mut Vec<ValueMismatch>,
+) {
+    use serde_json::Value;
+
+    let child = |key: &str| format!("{}/{}", pointer, key.replace('~', "~0").replace('/', "~1"));
+    match (before, after) {
+        (Some(Value::Object(a)), Some(Value::Object(b))) => {
+            for key in a.keys().chain(b.keys().filter(|k| !a.contains_key(*k))) {
+                diff_values(child(key), a.get(key), b.get(key), mismatches);
+            }
+        }
+        (Some(Value::Array(a)), Some(Value::Array(b))) if a.len() == b.len() => {
+            for (idx, (x, y)) in a.iter().zip(b).enumerate() {
+                diff_values(child(&idx.to_string()), Some(x), Some(y), mismatches);
+            }
+        }
+        (a, b) if a == b => {}
+        (a, b) => mismatches.push(ValueMismatch {
+            pointer,
+            before: a.cloned(),
+            after: b.cloned(),
+        }),
+    }
+}
+
+/// Turn numbers written as strings (`"35.0"`) back into JSON numbers
+///
+/// Applies to parameter `valueNumeric` fields and to material property
+/// `value` fields anywhere in the tree. Strings that don't parse as a finite
+/// number are left alone. Returns the number of values changed.
+pub fn coerce_numeric_strings(value: &mut serde_json::Value) -> usize {
+    use serde_json::Value;
+
+    fn coerce(value: &mut Value) -> usize {
+        let number = value
+            .as_str()
+            .and_then(|s| s.trim().parse::<f64>().ok())
+            .filter(|n| n.is_finite())
+            .and_then(serde_json::Number::from_f64);
+        match number {
+            Some(number) => {
+                *value = Value::Number(number);
+                1
+            }
+            None => 0,
+        }
+    }
+
+    match value {
+        Value::Object(map) => {
+            let mut count = map.get_mut("valueNumeric").map_or(0, coerce);
+            if let Some(Value::Array(properties)) = map.get_mut("properties") {
+                for property in properties.iter_mut() {
+                    count += property.get_mut("value").map_or(0, coerce);
+                }
+            }
+            count + map.values_mut().map(coerce_numeric_strings).sum::<usize>()
+        }
+        Value::Array(items) => items.iter_mut().map(coerce_numeric_strings).sum(),
+        _ => 0,
     }
 }
 
 /// Serialize to JSON
 fn serialize_json(doc: &Document, pretty: bool) -> Result<Vec<u8>> {
+    // serde_json would write NaN and infinity as null
+    doc.check_finite()?;
     let json = if pretty {
         serde_json::to_vec_pretty(doc)?
     } else {
@@ -37,57 +183,133 @@ fn serialize_json(doc: &Document, pretty: bool) -> Result<Vec<u8>> {
     Ok(json)
 }
 
-/// Deserialize from JSON
+/// Deserialize from JSON, skipping a leading UTF-8 BOM
 fn deserialize_json(data: &[u8]) -> Result<Document> {
+    let data = data.strip_prefix(UTF8_BOM).unwrap_or(data);
     let doc: Document = serde_json::from_slice(data)?;
     Ok(doc)
 }
 
-/// Serialize to Avro format
-fn serialize_avro(doc: &Document) -> Result<Vec<u8>> {
-    use apache_avro::{Schema, Writer};
-    
-    // Load schema
+/// Serialize to YAML
+fn serialize_yaml(doc: &Document) -> Result<Vec<u8>> {
+    let yaml = serde_yaml::to_string(doc)?;
+    Ok(yaml.into_bytes())
+}
+
+/// Deserialize from YAML
+fn deserialize_yaml(data: &[u8]) -> Result<Document> {
+    let doc: Document = serde_yaml::from_slice(data)?;
+    Ok(doc)
+}
+
+/// Load the bundled AGSi Avro schema
+fn avro_schema() -> Result<apache_avro::Schema> {
     let schema_str = include_str!("../../../schemas/agsi.avsc");
-    let schema = Schema::parse_str(schema_str)
-        .map_err(|e| Error::Serialization(format!("Failed to parse Avro schema: {}", e)))?;
-    
-    // Convert to Avro value
+    apache_avro::Schema::parse_str(schema_str)
+        .map_err(|e| Error::Serialization(format!("Failed to parse Avro schema: {}", e)))
+}
+
+/// Convert a document to an Avro value matching the schema
+fn to_avro_value(
+    doc: &Document,
+    schema: &apache_avro::Schema,
+) -> Result<apache_avro::types::Value> {
     let json_value = serde_json::to_value(doc)?;
     let avro_value = apache_avro::to_value(json_value)
         .map_err(|e| Error::Serialization(format!("Failed to convert to Avro: {}", e)))?;
-    
+    avro_value
+        .resolve(schema)
+        .map_err(|e| Error::Serialization(format!("Failed to match Avro schema: {}", e)))
+}
+
+/// Convert an Avro record back into a document
+fn from_avro_value(value: apache_avro::types::Value) -> Result<Document> {
+    // Convert to JSON then to Document
+    let json_value = apache_avro::from_value::<serde_json::Value>(&value)
+        .map_err(|e| Error::Deserialization(format!("Failed to convert from Avro: {}", e)))?;
+
+    let doc: Document = serde_json::from_value(json_value)?;
+    Ok(doc)
+}
+
+/// Convert a document to the Avro value written by [`Format::Avro`]
+///
+/// Useful for inspecting the intermediate representation when a document
+/// does not map cleanly onto the Avro schema.
+pub fn document_to_avro_value(doc: &Document) -> Result<apache_avro::types::Value> {
+    to_avro_value(doc, &avro_schema()?)
+}
+
+/// Convert an Avro value, as produced by [`document_to_avro_value`], back
+/// into a document
+pub fn document_from_avro_value(value: apache_avro::types::Value) -> Result<Document> {
+    from_avro_value(value)
+}
+
+/// Serialize to Avro format
+fn serialize_avro(doc: &Document) -> Result<Vec<u8>> {
+    serialize_many_avro(std::slice::from_ref(doc))
+}
+
+/// Serialize several documents as records of a single Avro container file
+pub fn serialize_many_avro(docs: &[Document]) -> Result<Vec<u8>> {
+    use apache_avro::Writer;
+
+    let schema = avro_schema()?;
+
     // Write to bytes
     let mut writer = Writer::new(&schema, Vec::new());
-    writer.append(avro_value)
-        .map_err(|e| Error::Serialization(format!("Failed to write Avro: {}", e)))?;
-    
-    writer.into_inner()
+    for doc in docs {
+        writer
+            .append(to_avro_value(doc, &schema)?)
+            .map_err(|e| Error::Serialization(format!("Failed to write Avro: {}", e)))?;
+    }
+
+    writer
+        .into_inner()
         .map_err(|e| Error::Serialization(format!("Failed to finalize Avro: {}", e)))
 }
 
 /// Deserialize from Avro format
+///
+/// The data must contain exactly one record; use [`deserialize_many_avro`]
+/// for batched files.
 fn deserialize_avro(data: &[u8]) -> Result<Document> {
     use apache_avro::Reader;
-    
+
     // Read from bytes
+    let mut reader = Reader::new(data)
+        .map_err(|e| Error::Deserialization(format!("Failed to read Avro: {}", e)))?;
+
+    let value = reader
+        .next()
+        .ok_or_else(|| Error::Deserialization("No records found in Avro data".to_string()))?
+        .map_err(|e| Error::Deserialization(format!("Failed to deserialize Avro: {}", e)))?;
+
+    if reader.next().is_some() {
+        return Err(Error::Deserialization(
+            "Avro data contains more than one record; use deserialize_many_avro".to_string(),
+        ));
+    }
+
+    from_avro_value(value)
+}
+
+/// Deserialize every record of an Avro container file
+pub fn deserialize_many_avro(data: &[u8]) -> Result<Vec<Document>> {
+    use apache_avro::Reader;
+
     let reader = Reader::new(data)
         .map_err(|e| Error::Deserialization(format!("Failed to read Avro: {}", e)))?;
-    
-    // Get first record
-    for value in reader {
-        let value = value
-            .map_err(|e| Error::Deserialization(format!("Failed to deserialize Avro: {}", e)))?;
-        
-        // Convert to JSON then to Document
-        let json_value = apache_avro::from_value::<serde_json::Value>(&value)
-            .map_err(|e| Error::Deserialization(format!("Failed to convert from Avro: {}", e)))?;
-        
-        let doc: Document = serde_json::from_value(json_value)?;
-        return Ok(doc);
-    }
-    
-    Err(Error::Deserialization("No records found in Avro data".to_string()))
+
+    reader
+        .map(|value| {
+            let value = value.map_err(|e| {
+                Error::Deserialization(format!("Failed to deserialize Avro: {}", e))
+            })?;
+            from_avro_value(value)
+        })
+        .collect()
 }
 
 /// Serialize to Protocol Buffers format
@@ -128,16 +350,148 @@ mod tests {
         // Compact should be smaller
         assert!(compact.len() < pretty.len());
     }
-    
+
+    #[test]
+    fn test_yaml_serialization() {
+        let doc = Document::new("TEST001");
+        let bytes = serialize(&doc, Format::Yaml).unwrap();
+        let deserialized = deserialize(&bytes, Format::Yaml).unwrap();
+
+        assert_eq!(doc.ags_file.file_id, deserialized.ags_file.file_id);
+    }
+
     #[test]
-    #[ignore] // Requires proper Avro schema mapping
     fn test_avro_roundtrip() {
         let doc = Document::new("TEST001");
         let bytes = serialize(&doc, Format::Avro).unwrap();
         let deserialized = deserialize(&bytes, Format::Avro).unwrap();
-        
+
         assert_eq!(doc.ags_file.file_id, deserialized.ags_file.file_id);
         assert_eq!(doc.ags_schema.version, deserialized.ags_schema.version);
     }
-}
 
+    #[test]
+    fn test_document_to_avro_value() {
+        let doc = Document::new("TEST001");
+        let value = document_to_avro_value(&doc).unwrap();
+        assert!(matches!(value, apache_avro::types::Value::Record(_)));
+
+        let back = document_from_avro_value(value).unwrap();
+        assert_eq!(back.ags_file.file_id, "TEST001");
+    }
+
+    #[test]
+    fn test_avro_many_roundtrip() {
+        let docs = vec![
+            Document::new("TEST001"),
+            Document::new("TEST002").with_author("Author"),
+            Document::new("TEST003"),
+        ];
+        let bytes = serialize_many_avro(&docs).unwrap();
+        let deserialized = deserialize_many_avro(&bytes).unwrap();
+
+        assert_eq!(deserialized.len(), 3);
+        for (doc, back) in docs.iter().zip(&deserialized) {
+            assert_eq!(doc.ags_file.file_id, back.ags_file.file_id);
+            assert_eq!(doc.ags_file.file_author, back.ags_file.file_author);
+        }
+    }
+
+    #[test]
+    fn test_roundtrip_check_populated_document() {
+        use crate::document::Attachment;
+        use crate::project::Project;
+
+        let mut doc = Document::template(true)
+            .with_author("Test Author")
+            .with_project(Project::new("PRJ001", "Project"));
+        doc.ags_schema.variant = Some("full".to_string());
+        doc.add_attachment(Attachment::embedded("REPORT", "application/pdf", b"%PDF"));
+        doc.extensions.insert(
+            "x-custom".to_string(),
+            serde_json::json!({"nested": [1, 2.5, "a"]}),
+        );
+
+        roundtrip_check(&doc).unwrap();
+    }
+
+    #[test]
+    fn test_roundtrip_check_catches_shadowed_extension() {
+        // While there are no attachments the field is skipped, so an extension
+        // of the same name is written in its place and read back as attachments
+        let mut doc = Document::new("TEST001");
+        doc.extensions.insert(
+            "attachments".to_string(),
+            serde_json::json!([{"id": "A", "mime": "text/plain", "checksum": "abc"}]),
+        );
+
+        let mismatches = roundtrip_diff(&doc).unwrap();
+        assert_eq!(mismatches.len(), 1);
+        assert_eq!(mismatches[0].pointer, "/attachments/0/checksum");
+        assert_eq!(mismatches[0].after, None);
+        assert!(roundtrip_check(&doc).is_err());
+    }
+
+    #[test]
+    fn test_detect_format() {
+        assert_eq!(
+            detect_format(b"  \n{\"agsSchema\": {}}"),
+            Some(Format::Json)
+        );
+        assert_eq!(
+            detect_format(b"Obj\x01\x04\x14avro.codec"),
+            Some(Format::Avro)
+        );
+        assert_eq!(detect_format(&[0x82, 0xa4]), None);
+        assert_eq!(detect_format(b""), None);
+
+        let doc = Document::new("TEST001");
+        for format in [Format::Json, Format::Avro] {
+            let bytes = serialize(&doc, format).unwrap();
+            let back = deserialize(&bytes, Format::Unknown).unwrap();
+            assert_eq!(back.ags_file.file_id, "TEST001");
+        }
+
+        let mut with_bom = UTF8_BOM.to_vec();
+        with_bom.extend(serialize(&doc, Format::Json).unwrap());
+        assert_eq!(detect_format(&with_bom), Some(Format::Json));
+        let back = deserialize(&with_bom, Format::Unknown).unwrap();
+        assert_eq!(back.ags_file.file_id, "TEST001");
+        let text = String::from_utf8(with_bom).unwrap();
+        assert_eq!(
+            Document::from_json_str(&text).unwrap().ags_file.file_id,
+            "TEST001"
+        );
+    }
+
+    #[test]
+    fn test_coerce_numeric_strings() {
+        use crate::AgsiRoot;
+
+        let mut root = serde_json::to_value(AgsiRoot::new("Test Project")).unwrap();
+        root["agsiModel"] = serde_json::json!([{
+            "agsiModelElement": [{
+                "agsiDataParameterValue": [
+                    {"codeID": "AngleFriction", "valueNumeric": "35.0"},
+                    {"codeID": "Remarks", "valueText": "35.0"},
+                ]
+            }]
+        }]);
+        assert!(serde_json::from_value::<AgsiRoot>(root.clone()).is_err());
+
+        assert_eq!(coerce_numeric_strings(&mut root), 1);
+        let root: AgsiRoot = serde_json::from_value(root).unwrap();
+        let parameters = &root.agsi_model[0].agsi_model_element[0].agsi_data_parameter_value;
+        assert_eq!(parameters[0].value_numeric, Some(35.0));
+        assert_eq!(parameters[1].value_text.as_deref(), Some("35.0"));
+    }
+
+    #[test]
+    fn test_avro_single_rejects_multiple_records() {
+        let docs = vec![Document::new("TEST001"), Document::new("TEST002")];
+        let bytes = serialize_many_avro(&docs).unwrap();
+
+        let err = deserialize(&bytes, Format::Avro).unwrap_err();
+        assert!(err.to_string().contains("more than one record"), "{}", err);
+    }
+}
@@ -0,0 +1,70 @@
+//! Spatial index over model component footprints
+
+use rstar::primitives::{GeomWithData, Rectangle};
+use rstar::{RTree, AABB};
+
+use crate::model::ModelComponent;
+
+type IndexedBounds = GeomWithData<Rectangle<[f64; 2]>, usize>;
+
+/// R-tree of component plan bounding boxes, keyed by component index
+///
+/// Built with [`crate::GroundModel::build_spatial_index`]. Queries return
+/// candidate indices whose bounding boxes match; callers still need to test
+/// the actual geometry.
+#[derive(Debug, Clone)]
+pub struct SpatialIndex {
+    tree: RTree<IndexedBounds>,
+    generation: u64,
+    component_count: usize,
+}
+
+impl SpatialIndex {
+    /// Build an index over the given components, as of a model generation
+    pub fn new(components: &[ModelComponent], generation: u64) -> Self {
+        let entries = components
+            .iter()
+            .enumerate()
+            .filter_map(|(i, component)| {
+                let [min_x, min_y, max_x, max_y] = component.geometry.bounds_xy()?;
+                let bounds = Rectangle::from_corners([min_x, min_y], [max_x, max_y]);
+                Some(GeomWithData::new(bounds, i))
+            })
+            .collect();
+
+        Self {
+            tree: RTree::bulk_load(entries),
+            generation,
+            component_count: components.len(),
+        }
+    }
+
+    /// Check whether the index was built at this generation over this many
+    /// components
+    pub fn is_current(&self, generation: u64, component_count: usize) -> bool {
+        self.generation == generation && self.component_count == component_count
+    }
+
+    /// Indices of components whose bounding box contains a point, ascending
+    pub fn query_point(&self, x: f64, y: f64) -> Vec<usize> {
+        let mut indices: Vec<usize> = self
+            .tree
+            .locate_all_at_point(&[x, y])
+            .map(|entry| entry.data)
+            .collect();
+        indices.sort_unstable();
+        indices
+    }
+
+    /// Indices of components whose bounding box intersects a box, ascending
+    pub fn query_bounds(&self, bounds: [f64; 4]) -> Vec<usize> {
+        let envelope = AABB::from_corners([bounds[0], bounds[1]], [bounds[2], bounds[3]]);
+        let mut indices: Vec<usize> = self
+            .tree
+            .locate_in_envelope_intersecting(&envelope)
+            .map(|entry| entry.data)
+            .collect();
+        indices.sort_unstable();
+        indices
+    }
+}
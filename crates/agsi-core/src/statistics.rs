@@ -0,0 +1,125 @@
+//! Summary statistics and characteristic values for material parameters
+
+use crate::error::{Error, Result};
+use crate::material::PropertyValue;
+use crate::model::GroundModel;
+
+/// Method used to derive a characteristic value from a sample
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CharacteristicMethod {
+    /// 5th percentile of the sample (linear interpolation)
+    Percentile5,
+    /// Sample mean minus one sample standard deviation
+    MeanMinusStd,
+}
+
+/// Collect every numeric value recorded for a parameter across a model's materials
+///
+/// Properties are matched by name against the parameter code. Single numbers
+/// and arrays contribute their values; ranges and text are ignored.
+pub fn parameter_values(model: &GroundModel, code: &str) -> Vec<f64> {
+    model
+        .materials
+        .iter()
+        .flat_map(|m| &m.properties)
+        .filter(|p| p.name == code)
+        .flat_map(|p| match &p.value {
+            PropertyValue::Number(v) => vec![*v],
+            PropertyValue::Array(values) => values.clone(),
+            _ => Vec::new(),
+        })
+        .collect()
+}
+
+/// Arithmetic mean
+pub fn mean(values: &[f64]) -> Option<f64> {
+    if values.is_empty() {
+        return None;
+    }
+    Some(values.iter().sum::<f64>() / values.len() as f64)
+}
+
+/// Sample standard deviation (n - 1 denominator)
+pub fn std_dev(values: &[f64]) -> Option<f64> {
+    if values.len() < 2 {
+        return None;
+    }
+    let mean = mean(values)?;
+    let variance =
+        values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (values.len() - 1) as f64;
+    Some(variance.sqrt())
+}
+
+/// Percentile using linear interpolation between closest ranks
+///
+/// `p` is a fraction between 0 and 1.
+pub fn percentile(values: &[f64], p: f64) -> Option<f64> {
+    if values.is_empty() || !(0.0..=1.0).contains(&p) {
+        return None;
+    }
+    let mut sorted = values.to_vec();
+    sorted.sort_by(f64::total_cmp);
+
+    let rank = p * (sorted.len() - 1) as f64;
+    let lower = rank.floor() as usize;
+    let upper = rank.ceil() as usize;
+    Some(sorted[lower] + (sorted[upper] - sorted[lower]) * (rank - lower as f64))
+}
+
+/// Compute a characteristic value from a sample
+pub fn characteristic_value(values: &[f64], method: CharacteristicMethod) -> Result<f64> {
+    let value = match method {
+        CharacteristicMethod::Percentile5 => percentile(values, 0.05),
+        CharacteristicMethod::MeanMinusStd => mean(values).zip(std_dev(values)).map(|(m, s)| m - s),
+    };
+
+    value.ok_or_else(|| {
+        Error::Validation(format!(
+            "Not enough values for a characteristic value: found {}",
+            values.len()
+        ))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::material::{Material, MaterialProperty, MaterialType};
+    use crate::model::{ModelDimension, ModelType};
+
+    #[test]
+    fn test_mean_minus_std() {
+        let values = [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+        assert_eq!(mean(&values), Some(5.0));
+
+        // Sample variance is 32 / 7
+        let expected = 5.0 - (32.0_f64 / 7.0).sqrt();
+        let value = characteristic_value(&values, CharacteristicMethod::MeanMinusStd).unwrap();
+        assert!((value - expected).abs() < 1e-12);
+
+        assert!(characteristic_value(&[1.0], CharacteristicMethod::MeanMinusStd).is_err());
+    }
+
+    #[test]
+    fn test_percentile_and_collection() {
+        let values: Vec<f64> = (0..=20).map(f64::from).collect();
+        assert_eq!(percentile(&values, 0.05), Some(1.0));
+        assert_eq!(
+            characteristic_value(&values, CharacteristicMethod::Percentile5).unwrap(),
+            1.0
+        );
+
+        let mut model =
+            GroundModel::new("M1", "Model", ModelType::Geotechnical, ModelDimension::OneD);
+        model.add_material(
+            Material::new("MAT001", "Sand", MaterialType::Soil)
+                .with_property(MaterialProperty::numeric("AngleFriction", 32.0, None))
+                .with_property(MaterialProperty::numeric("Cohesion", 0.0, None)),
+        );
+        model.add_material(
+            Material::new("MAT002", "Gravel", MaterialType::Soil)
+                .with_property(MaterialProperty::numeric("AngleFriction", 36.0, None)),
+        );
+        assert_eq!(parameter_values(&model, "AngleFriction"), vec![32.0, 36.0]);
+    }
+}
@@ -1,4 +1,8 @@
-use crate::{Document, Error, Result};
+use crate::agsi_model::AgsiRoot;
+use crate::geometry::{signed_area, Geometry};
+use crate::material::{Material, MaterialType};
+use crate::model::{ComponentType, GroundModel};
+use crate::{AgsiParameterCode, Document, Error, Result, SchemaVariant};
 use validator::Validate;
 
 /// Validation result containing errors and warnings
@@ -12,7 +16,11 @@ pub struct ValidationResult {
 /// A validation error
 #[derive(Debug, Clone)]
 pub struct ValidationError {
+    /// Identifier of the check that raised this error (e.g. `missing-material`)
+    pub code: Option<String>,
     pub path: String,
+    /// RFC 6901 JSON pointer equivalent of `path`
+    pub json_pointer: String,
     pub message: String,
     pub error_type: ValidationErrorType,
 }
@@ -20,7 +28,11 @@ pub struct ValidationError {
 /// A validation warning
 #[derive(Debug, Clone)]
 pub struct ValidationWarning {
+    /// Identifier of the check that raised this warning (e.g. `schema-version`)
+    pub code: Option<String>,
     pub path: String,
+    /// RFC 6901 JSON pointer equivalent of `path`
+    pub json_pointer: String,
     pub message: String,
 }
 
@@ -35,23 +47,98 @@ pub enum ValidationErrorType {
     Reference,
 }
 
+impl ValidationError {
+    /// Create a new validation error at a dotted path
+    pub fn new(
+        path: impl Into<String>,
+        message: impl Into<String>,
+        error_type: ValidationErrorType,
+    ) -> Self {
+        let path = path.into();
+        Self {
+            code: None,
+            json_pointer: to_json_pointer(&path),
+            path,
+            message: message.into(),
+            error_type,
+        }
+    }
+
+    /// Set the check code
+    pub fn with_code(mut self, code: impl Into<String>) -> Self {
+        self.code = Some(code.into());
+        self
+    }
+}
+
+impl ValidationWarning {
+    /// Create a new validation warning at a dotted path
+    pub fn new(path: impl Into<String>, message: impl Into<String>) -> Self {
+        let path = path.into();
+        Self {
+            code: None,
+            json_pointer: to_json_pointer(&path),
+            path,
+            message: message.into(),
+        }
+    }
+
+    /// Set the check code
+    pub fn with_code(mut self, code: impl Into<String>) -> Self {
+        self.code = Some(code.into());
+        self
+    }
+}
+
+/// Convert a dotted path (e.g. `agsiModel[0].components[1].materialId`) into
+/// an RFC 6901 JSON pointer (e.g. `/agsiModel/0/components/1/materialId`)
+pub fn to_json_pointer(path: &str) -> String {
+    let mut pointer = String::new();
+    for segment in path.split('.').filter(|s| !s.is_empty()) {
+        let mut parts = segment.split('[');
+        if let Some(name) = parts.next().filter(|n| !n.is_empty()) {
+            pointer.push('/');
+            pointer.push_str(&name.replace('~', "~0").replace('/', "~1"));
+        }
+        for index in parts {
+            pointer.push('/');
+            pointer.push_str(index.trim_end_matches(']'));
+        }
+    }
+    pointer
+}
+
+/// A custom validation rule run in addition to the built-in checks
+///
+/// Rules append any errors or warnings they find to `out`.
+pub trait ValidationRule {
+    fn check(&self, doc: &Document, out: &mut ValidationResult);
+}
+
 /// Validate a document against AGSi rules
 pub fn validate_document(doc: &Document) -> Result<ValidationResult> {
-    let mut result = ValidationResult {
-        is_valid: true,
-        errors: Vec::new(),
-        warnings: Vec::new(),
-    };
+    validate_document_with_rules(doc, &[])
+}
+
+/// Validate a document against AGSi rules plus additional custom rules
+pub fn validate_document_with_rules(
+    doc: &Document,
+    rules: &[Box<dyn ValidationRule>],
+) -> Result<ValidationResult> {
+    let mut result = ValidationResult::new();
 
     // Validate structure using validator crate
     if let Err(e) = doc.validate() {
         for (field, errors) in e.field_errors() {
             for error in errors {
-                result.errors.push(ValidationError {
-                    path: field.to_string(),
-                    message: error.to_string(),
-                    error_type: ValidationErrorType::Schema,
-                });
+                result.errors.push(
+                    ValidationError::new(
+                        field.to_string(),
+                        error.to_string(),
+                        ValidationErrorType::Schema,
+                    )
+                    .with_code("schema"),
+                );
             }
         }
         result.is_valid = false;
@@ -59,44 +146,98 @@ pub fn validate_document(doc: &Document) -> Result<ValidationResult> {
 
     // Validate schema version
     if doc.ags_schema.version != crate::AGSI_VERSION {
-        result.warnings.push(ValidationWarning {
-            path: "agsSchema.version".to_string(),
-            message: format!(
-                "Schema version {} differs from library version {}",
-                doc.ags_schema.version,
-                crate::AGSI_VERSION
-            ),
-        });
+        result.warnings.push(
+            ValidationWarning::new(
+                "agsSchema.version",
+                format!(
+                    "Schema version {} differs from library version {}",
+                    doc.ags_schema.version,
+                    crate::AGSI_VERSION
+                ),
+            )
+            .with_code("schema-version"),
+        );
     }
 
+    check_schema_variant(doc, &mut result);
+
     // Validate model references
     for (model_idx, model) in doc.agsi_model.iter().enumerate() {
         // Check for duplicate model IDs
         let duplicate_count = doc.agsi_model.iter().filter(|m| m.id == model.id).count();
         if duplicate_count > 1 {
-            result.errors.push(ValidationError {
-                path: format!("agsiModel[{}].id", model_idx),
-                message: format!("Duplicate model ID: {}", model.id),
-                error_type: ValidationErrorType::Reference,
-            });
+            result.errors.push(
+                ValidationError::new(
+                    format!("agsiModel[{}].id", model_idx),
+                    format!("Duplicate model ID: {}", model.id),
+                    ValidationErrorType::Reference,
+                )
+                .with_code("duplicate-model-id"),
+            );
             result.is_valid = false;
         }
 
+        check_model_geometry(model_idx, model, false, &mut result);
+
         // Check material references in components
         for (comp_idx, component) in model.components.iter().enumerate() {
             if model.get_material(&component.material_id).is_none() {
-                result.errors.push(ValidationError {
-                    path: format!("agsiModel[{}].components[{}].materialId", model_idx, comp_idx),
-                    message: format!(
-                        "Material ID '{}' not found in model",
-                        component.material_id
-                    ),
-                    error_type: ValidationErrorType::Reference,
-                });
+                result.errors.push(
+                    ValidationError::new(
+                        format!(
+                            "agsiModel[{}].components[{}].materialId",
+                            model_idx, comp_idx
+                        ),
+                        format!("Material ID '{}' not found in model", component.material_id),
+                        ValidationErrorType::Reference,
+                    )
+                    .with_code("missing-material"),
+                );
                 result.is_valid = false;
             }
         }
 
+        // Voids have no strength to speak of
+        for (comp_idx, component) in model.components.iter().enumerate() {
+            let Some(material) = model.get_material(&component.material_id) else {
+                continue;
+            };
+            if material.material_type != MaterialType::Void {
+                continue;
+            }
+            for prop in &material.properties {
+                let is_strength = AgsiParameterCode::from_code_id(&prop.name).is_some_and(|code| {
+                    code.category() == "Strength"
+                        || code == AgsiParameterCode::UniaxialCompressiveStrength
+                });
+                if is_strength {
+                    result.warnings.push(ValidationWarning::new(
+                        format!("agsiModel[{}].components[{}].materialId", model_idx, comp_idx),
+                        format!(
+                            "Component '{}' is a void but its material '{}' has strength parameter '{}'",
+                            component.id, material.id, prop.name
+                        ),
+                    ).with_code("void-strength-parameter"));
+                }
+            }
+        }
+
+        // Materials no component uses, skipped for material-only models
+        if !model.components.is_empty() {
+            let unused = model.unused_materials();
+            for (mat_idx, material) in model.materials.iter().enumerate() {
+                if unused.iter().any(|m| m.id == material.id) {
+                    result.warnings.push(
+                        ValidationWarning::new(
+                            format!("agsiModel[{}].materials[{}]", model_idx, mat_idx),
+                            format!("Material '{}' is not used by any component", material.id),
+                        )
+                        .with_code("unused-material"),
+                    );
+                }
+            }
+        }
+
         // Check for duplicate material IDs within a model
         for (mat_idx, material) in model.materials.iter().enumerate() {
             let duplicate_count = model
@@ -105,49 +246,767 @@ pub fn validate_document(doc: &Document) -> Result<ValidationResult> {
                 .filter(|m| m.id == material.id)
                 .count();
             if duplicate_count > 1 {
-                result.errors.push(ValidationError {
-                    path: format!("agsiModel[{}].materials[{}].id", model_idx, mat_idx),
-                    message: format!("Duplicate material ID: {}", material.id),
-                    error_type: ValidationErrorType::Reference,
-                });
+                result.errors.push(
+                    ValidationError::new(
+                        format!("agsiModel[{}].materials[{}].id", model_idx, mat_idx),
+                        format!("Duplicate material ID: {}", material.id),
+                        ValidationErrorType::Reference,
+                    )
+                    .with_code("duplicate-material-id"),
+                );
                 result.is_valid = false;
             }
+
+            // Check parameters are plausible for the material type
+            for (prop_idx, prop_name) in inconsistent_properties(material) {
+                result.warnings.push(
+                    ValidationWarning::new(
+                        format!(
+                            "agsiModel[{}].materials[{}].properties[{}].name",
+                            model_idx, mat_idx, prop_idx
+                        ),
+                        format!(
+                            "Parameter '{}' is unusual for {:?} material '{}'",
+                            prop_name, material.material_type, material.id
+                        ),
+                    )
+                    .with_code("material-parameter-mismatch"),
+                );
+            }
         }
 
         // Validate model extent if present
         if let Some(ref extent) = model.extent {
             if extent.min_x > extent.max_x {
-                result.errors.push(ValidationError {
-                    path: format!("agsiModel[{}].extent", model_idx),
-                    message: "min_x must be less than or equal to max_x".to_string(),
-                    error_type: ValidationErrorType::Range,
-                });
+                result.errors.push(
+                    ValidationError::new(
+                        format!("agsiModel[{}].extent", model_idx),
+                        "min_x must be less than or equal to max_x",
+                        ValidationErrorType::Range,
+                    )
+                    .with_code("extent-range"),
+                );
                 result.is_valid = false;
             }
             if extent.min_y > extent.max_y {
-                result.errors.push(ValidationError {
-                    path: format!("agsiModel[{}].extent", model_idx),
-                    message: "min_y must be less than or equal to max_y".to_string(),
-                    error_type: ValidationErrorType::Range,
-                });
+                result.errors.push(
+                    ValidationError::new(
+                        format!("agsiModel[{}].extent", model_idx),
+                        "min_y must be less than or equal to max_y",
+                        ValidationErrorType::Range,
+                    )
+                    .with_code("extent-range"),
+                );
                 result.is_valid = false;
             }
             if let (Some(min_z), Some(max_z)) = (extent.min_z, extent.max_z) {
                 if min_z > max_z {
-                    result.errors.push(ValidationError {
-                        path: format!("agsiModel[{}].extent", model_idx),
-                        message: "min_z must be less than or equal to max_z".to_string(),
-                        error_type: ValidationErrorType::Range,
-                    });
+                    result.errors.push(
+                        ValidationError::new(
+                            format!("agsiModel[{}].extent", model_idx),
+                            "min_z must be less than or equal to max_z",
+                            ValidationErrorType::Range,
+                        )
+                        .with_code("extent-range"),
+                    );
                     result.is_valid = false;
                 }
             }
         }
     }
 
+    #[cfg(feature = "proj")]
+    check_crs_codes(doc, &mut result);
+
+    // Run custom rules
+    for rule in rules {
+        rule.check(doc, &mut result);
+    }
+    if !result.errors.is_empty() {
+        result.is_valid = false;
+    }
+
     Ok(result)
 }
 
+/// Validate only the geometry of a document
+///
+/// Runs the geometry checks from [`validate_document`] (ring closure, winding,
+/// finite coordinates, measure counts, CRS consistency, extent containment and
+/// placeholders)
+/// and skips everything else.
+pub fn validate_geometry(doc: &Document) -> Result<ValidationResult> {
+    let mut result = ValidationResult::new();
+
+    for (model_idx, model) in doc.agsi_model.iter().enumerate() {
+        check_model_geometry(model_idx, model, true, &mut result);
+    }
+    result.is_valid = result.errors.is_empty();
+
+    Ok(result)
+}
+
+/// Check the field constraints of an AGSi root and everything it contains
+///
+/// Covers the schema and file blocks, and every model, element, parameter
+/// value and property summary, so e.g. a negative `valueStd` deep in a
+/// summary is reported with its full path.
+pub fn validate_root(root: &AgsiRoot) -> Result<ValidationResult> {
+    // Field names as they appear in the JSON
+    fn json_name(field: &str) -> String {
+        if field == "code_id" {
+            return "codeID".to_string();
+        }
+        let mut parts = field.split('_');
+        let mut name = parts.next().unwrap_or_default().to_string();
+        for part in parts {
+            let mut chars = part.chars();
+            if let Some(first) = chars.next() {
+                name.extend(first.to_uppercase());
+                name.push_str(chars.as_str());
+            }
+        }
+        name
+    }
+
+    fn collect(path: &str, errors: &validator::ValidationErrors, out: &mut ValidationResult) {
+        let mut fields: Vec<_> = errors.errors().iter().collect();
+        fields.sort_by_key(|(field, _)| **field);
+        for (field, kind) in fields {
+            let field_path = match path {
+                "" => json_name(field),
+                _ => format!("{}.{}", path, json_name(field)),
+            };
+            match kind {
+                validator::ValidationErrorsKind::Field(errors) => {
+                    for error in errors {
+                        out.errors.push(
+                            ValidationError::new(
+                                field_path.clone(),
+                                error.to_string(),
+                                ValidationErrorType::Schema,
+                            )
+                            .with_code("schema"),
+                        );
+                    }
+                }
+                validator::ValidationErrorsKind::Struct(nested) => {
+                    collect(&field_path, nested, out)
+                }
+                validator::ValidationErrorsKind::List(items) => {
+                    for (idx, nested) in items {
+                        collect(&format!("{}[{}]", field_path, idx), nested, out);
+                    }
+                }
+            }
+        }
+    }
+
+    let mut result = ValidationResult::new();
+
+    if let Err(e) = root.validate() {
+        collect("", &e, &mut result);
+    }
+    result.is_valid = result.errors.is_empty();
+
+    Ok(result)
+}
+
+/// Warn when elements in the same model share a display colour
+///
+/// An optional lint for visualisation: identical `colour_rgb` values make
+/// neighbouring elements indistinguishable on maps. Colours are compared
+/// case-insensitively and never produce errors.
+pub fn validate_colours(root: &AgsiRoot) -> Result<ValidationResult> {
+    let mut result = ValidationResult::new();
+
+    for (model_idx, model) in root.agsi_model.iter().enumerate() {
+        let mut by_colour: std::collections::BTreeMap<String, Vec<&str>> = Default::default();
+        for element in &model.agsi_model_element {
+            if let Some(colour) = &element.colour_rgb {
+                by_colour
+                    .entry(colour.to_uppercase())
+                    .or_default()
+                    .push(element.element_id.as_deref().unwrap_or("<unnamed>"));
+            }
+        }
+
+        for (colour, ids) in by_colour.into_iter().filter(|(_, ids)| ids.len() > 1) {
+            result.warnings.push(
+                ValidationWarning::new(
+                    format!("agsiModel[{}].agsiModelElement", model_idx),
+                    format!("Elements {} share colour {}", ids.join(", "), colour),
+                )
+                .with_code("duplicate-colour"),
+            );
+        }
+    }
+
+    Ok(result)
+}
+
+/// Check elements' from-file property references
+///
+/// A reference that can't be read or has no file ID is an error. Without a
+/// `documentSetId` on the model the file can't be resolved within the
+/// document set, so the reference is flagged as external.
+pub fn validate_property_files(root: &AgsiRoot) -> Result<ValidationResult> {
+    let mut result = ValidationResult::new();
+
+    for (model_idx, model) in root.agsi_model.iter().enumerate() {
+        for (elem_idx, element) in model.agsi_model_element.iter().enumerate() {
+            let path = format!(
+                "agsiModel[{}].agsiModelElement[{}].agsiDataPropertyFromFile",
+                model_idx, elem_idx
+            );
+            match element.property_from_file() {
+                None => {}
+                Some(Err(e)) => {
+                    result.errors.push(
+                        ValidationError::new(
+                            path,
+                            format!("Unreadable from-file property reference: {}", e),
+                            ValidationErrorType::Format,
+                        )
+                        .with_code("property-file-invalid"),
+                    );
+                }
+                Some(Ok(reference)) if reference.file_id.is_empty() => {
+                    result.errors.push(
+                        ValidationError::new(
+                            path,
+                            "From-file property reference has an empty file ID",
+                            ValidationErrorType::Reference,
+                        )
+                        .with_code("property-file-invalid"),
+                    );
+                }
+                Some(Ok(reference)) if model.document_set_id.is_none() => {
+                    result.warnings.push(
+                        ValidationWarning::new(
+                            path,
+                            format!(
+                            "File '{}' is external: the model has no document set to resolve it in",
+                            reference.file_id
+                        ),
+                        )
+                        .with_code("property-file-external"),
+                    );
+                }
+                Some(Ok(_)) => {}
+            }
+        }
+    }
+    result.is_valid = result.errors.is_empty();
+
+    Ok(result)
+}
+
+/// Share of a material's properties that may be assumed before linting warns
+pub const ASSUMED_PROPERTY_LIMIT: f64 = 0.5;
+
+/// Decimal places beyond which coordinates are flagged as excessively precise
+pub const COORDINATE_DECIMAL_LIMIT: usize = 3;
+
+/// Decimal places beyond which parameter values are flagged as excessively precise
+pub const PARAMETER_DECIMAL_LIMIT: usize = 4;
+
+/// Run full validation plus the soft "model health" lints
+///
+/// On top of [`validate_document`], warns about placeholder geometry,
+/// materials whose properties are mostly assumed, excessive coordinate or
+/// parameter precision, and models using different CRSs. The lints never add
+/// errors, so validity is the same as for [`validate_document`].
+pub fn lint_document(doc: &Document) -> Result<ValidationResult> {
+    use crate::material::PropertySource;
+
+    let mut result = validate_document(doc)?;
+
+    for (model_idx, model) in doc.agsi_model.iter().enumerate() {
+        for (comp_idx, component) in model.components.iter().enumerate() {
+            if component.geometry.is_placeholder() {
+                result.warnings.push(
+                    ValidationWarning::new(
+                        format!("agsiModel[{}].components[{}].geometry", model_idx, comp_idx),
+                        format!(
+                            "Component '{}' still has placeholder geometry",
+                            component.id
+                        ),
+                    )
+                    .with_code("placeholder-geometry"),
+                );
+            }
+        }
+
+        for (mat_idx, material) in model.materials.iter().enumerate() {
+            let total = material.properties.len();
+            let assumed = material
+                .properties
+                .iter()
+                .filter(|p| p.source == Some(PropertySource::Assumed))
+                .count();
+            if total > 0 && assumed as f64 / total as f64 > ASSUMED_PROPERTY_LIMIT {
+                result.warnings.push(
+                    ValidationWarning::new(
+                        format!("agsiModel[{}].materials[{}].properties", model_idx, mat_idx),
+                        format!(
+                            "{} of {} properties of material '{}' are assumed",
+                            assumed, total, material.id
+                        ),
+                    )
+                    .with_code("assumed-properties"),
+                );
+            }
+        }
+    }
+
+    for (model_idx, precision) in doc.precision_report().models.iter().enumerate() {
+        if precision.coordinate_decimals > COORDINATE_DECIMAL_LIMIT
+            || precision.parameter_decimals > PARAMETER_DECIMAL_LIMIT
+        {
+            result.warnings.push(
+                ValidationWarning::new(
+                    format!("agsiModel[{}]", model_idx),
+                    format!(
+                        "Model '{}' uses {} coordinate and {} parameter decimal places",
+                        precision.model_id,
+                        precision.coordinate_decimals,
+                        precision.parameter_decimals
+                    ),
+                )
+                .with_code("excessive-precision"),
+            );
+        }
+    }
+
+    let crs: std::collections::BTreeSet<&str> = doc
+        .agsi_model
+        .iter()
+        .filter_map(|m| m.crs.as_deref())
+        .collect();
+    if crs.len() > 1 {
+        result.warnings.push(
+            ValidationWarning::new(
+                "agsiModel",
+                format!(
+                    "Models use different CRSs: {}",
+                    crs.into_iter().collect::<Vec<_>>().join(", ")
+                ),
+            )
+            .with_code("mixed-crs"),
+        );
+    }
+
+    Ok(result)
+}
+
+/// Run field validation plus the soft lints for an AGSi schema root
+///
+/// The counterpart of [`lint_document`] for files in the AGSi schema layout,
+/// which carry element display colours: adds [`validate_colours`] to
+/// [`validate_root`]. The lints never add errors.
+pub fn lint_root(root: &AgsiRoot) -> Result<ValidationResult> {
+    let mut result = validate_root(root)?;
+    result.warnings.extend(validate_colours(root)?.warnings);
+    Ok(result)
+}
+
+/// Warn about parameters repeated within an element with different values
+///
+/// Parameters sharing a code and case but disagreeing on value are usually
+/// editing mistakes. Exact repeats are left to
+/// [`AgsiModelElement::dedupe_parameters`](crate::AgsiModelElement::dedupe_parameters).
+pub fn validate_parameter_duplicates(root: &AgsiRoot) -> Result<ValidationResult> {
+    let mut result = ValidationResult::new();
+
+    for (model_idx, model) in root.agsi_model.iter().enumerate() {
+        for (elem_idx, element) in model.agsi_model_element.iter().enumerate() {
+            let parameters = &element.agsi_data_parameter_value;
+            for (idx, parameter) in parameters.iter().enumerate() {
+                let conflicting = parameters[..idx]
+                    .iter()
+                    .any(|earlier| earlier.same_case(parameter) && !earlier.same_value(parameter));
+                if conflicting {
+                    result.warnings.push(
+                        ValidationWarning::new(
+                            format!(
+                                "agsiModel[{}].agsiModelElement[{}].agsiDataParameterValue[{}]",
+                                model_idx, elem_idx, idx
+                            ),
+                            format!(
+                                "Parameter '{}' (case {}) is repeated with a different value",
+                                parameter.code_id,
+                                parameter.case_id.as_deref().unwrap_or("none")
+                            ),
+                        )
+                        .with_code("conflicting-parameter"),
+                    );
+                }
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+/// Warn about parameters using a legacy spelling of a standard code
+///
+/// The aliases in [`crate::agsi_model::PARAMETER_CODE_ALIASES`] are still
+/// understood, but the canonical code should be written back.
+pub fn validate_parameter_aliases(root: &AgsiRoot) -> Result<ValidationResult> {
+    let mut result = ValidationResult::new();
+
+    for (model_idx, model) in root.agsi_model.iter().enumerate() {
+        for (elem_idx, element) in model.agsi_model_element.iter().enumerate() {
+            for (idx, parameter) in element.agsi_data_parameter_value.iter().enumerate() {
+                if let Some(canonical) = AgsiParameterCode::from_alias(&parameter.code_id) {
+                    result.warnings.push(
+                        ValidationWarning::new(
+                            format!(
+                            "agsiModel[{}].agsiModelElement[{}].agsiDataParameterValue[{}].codeID",
+                            model_idx, elem_idx, idx
+                        ),
+                            format!(
+                                "Parameter code '{}' is an alias; use '{}'",
+                                parameter.code_id,
+                                canonical.as_code_id()
+                            ),
+                        )
+                        .with_code("parameter-code-alias"),
+                    );
+                }
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+fn check_model_geometry(
+    model_idx: usize,
+    model: &GroundModel,
+    placeholders: bool,
+    result: &mut ValidationResult,
+) {
+    for (comp_idx, component) in model.components.iter().enumerate() {
+        let path = format!("agsiModel[{}].components[{}].geometry", model_idx, comp_idx);
+
+        if component.geometry.is_placeholder() {
+            if placeholders {
+                result.warnings.push(
+                    ValidationWarning::new(
+                        path,
+                        format!(
+                            "Component '{}' still has placeholder geometry",
+                            component.id
+                        ),
+                    )
+                    .with_code("placeholder-geometry"),
+                );
+            }
+            continue;
+        }
+
+        check_geometry(&component.geometry, &path, model.crs.as_deref(), result);
+
+        let type_name = component.geometry.type_name();
+        if let Some(allowed) = allowed_geometry_types(component.component_type) {
+            if type_name != "Collection" && !allowed.contains(&type_name) {
+                result.warnings.push(
+                    ValidationWarning::new(
+                        path.clone(),
+                        format!(
+                            "{:?} component '{}' has {} geometry, expected {}",
+                            component.component_type,
+                            component.id,
+                            type_name,
+                            allowed.join(" or ")
+                        ),
+                    )
+                    .with_code("component-geometry-mismatch"),
+                );
+            }
+        }
+
+        if let (Some(extent), Some([min_x, min_y, max_x, max_y])) =
+            (&model.extent, component.geometry.bounds_xy())
+        {
+            if !extent.contains(min_x, min_y, None) || !extent.contains(max_x, max_y, None) {
+                result.warnings.push(
+                    ValidationWarning::new(
+                        path,
+                        format!(
+                            "Component '{}' extends outside the model extent",
+                            component.id
+                        ),
+                    )
+                    .with_code("geometry-outside-extent"),
+                );
+            }
+        }
+    }
+}
+
+fn check_geometry(
+    geometry: &Geometry,
+    path: &str,
+    model_crs: Option<&str>,
+    result: &mut ValidationResult,
+) {
+    if !geometry.is_finite() {
+        result.errors.push(
+            ValidationError::new(
+                path,
+                "Geometry has NaN or infinite coordinates",
+                ValidationErrorType::Range,
+            )
+            .with_code("geometry-non-finite"),
+        );
+        result.is_valid = false;
+    }
+
+    if let (Some(crs), Some(model_crs)) = (geometry.crs(), model_crs) {
+        if crs != model_crs {
+            result.warnings.push(
+                ValidationWarning::new(
+                    path,
+                    format!(
+                        "Geometry CRS '{}' differs from model CRS '{}'",
+                        crs, model_crs
+                    ),
+                )
+                .with_code("geometry-crs-mismatch"),
+            );
+        }
+    }
+
+    match geometry {
+        Geometry::Polygon { rings, .. } => {
+            for (ring_idx, ring) in rings.iter().enumerate() {
+                let ring_path = format!("{}.rings[{}]", path, ring_idx);
+                let closed = ring
+                    .first()
+                    .zip(ring.last())
+                    .is_some_and(|(first, last)| first[0] == last[0] && first[1] == last[1]);
+
+                if !closed || ring.len() < 4 {
+                    result.errors.push(
+                        ValidationError::new(
+                            ring_path,
+                            "Polygon ring must be closed with at least 4 points",
+                            ValidationErrorType::Format,
+                        )
+                        .with_code("geometry-ring-closure"),
+                    );
+                    result.is_valid = false;
+                    continue;
+                }
+
+                // Exterior rings run counter-clockwise, holes clockwise (RFC 7946)
+                let area = signed_area(ring);
+                if (ring_idx == 0 && area < 0.0) || (ring_idx > 0 && area > 0.0) {
+                    result.warnings.push(
+                        ValidationWarning::new(
+                            ring_path,
+                            if ring_idx == 0 {
+                                "Exterior ring should be counter-clockwise"
+                            } else {
+                                "Interior ring should be clockwise"
+                            },
+                        )
+                        .with_code("geometry-winding"),
+                    );
+                }
+            }
+        }
+        Geometry::LineString {
+            coordinates,
+            measures: Some(measures),
+            ..
+        } if measures.len() != coordinates.len() => {
+            result.errors.push(
+                ValidationError::new(
+                    format!("{}.measures", path),
+                    format!(
+                        "LineString has {} coordinates but {} measures",
+                        coordinates.len(),
+                        measures.len()
+                    ),
+                    ValidationErrorType::Format,
+                )
+                .with_code("geometry-measure-count"),
+            );
+            result.is_valid = false;
+        }
+        Geometry::Collection { geometries, .. } => {
+            // Members inherit the collection's CRS if they don't set their own
+            let crs = geometry.crs().or(model_crs);
+            for (idx, member) in geometries.iter().enumerate() {
+                check_geometry(
+                    member,
+                    &format!("{}.geometries[{}]", path, idx),
+                    crs,
+                    result,
+                );
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Check the document keeps to the restrictions of its declared variant
+fn check_schema_variant(doc: &Document, result: &mut ValidationResult) {
+    let Some(ref name) = doc.ags_schema.variant else {
+        return;
+    };
+    let Some(variant) = SchemaVariant::from_name(name) else {
+        result.warnings.push(
+            ValidationWarning::new(
+                "agsSchema.variant",
+                format!("Unknown schema variant '{}'", name),
+            )
+            .with_code("unknown-schema-variant"),
+        );
+        return;
+    };
+
+    match variant {
+        SchemaVariant::Full => {}
+        SchemaVariant::Minimal => {
+            if doc.ags_project.is_some() {
+                result.warnings.push(
+                    ValidationWarning::new(
+                        "agsProject",
+                        "Minimal variant documents should not include project details",
+                    )
+                    .with_code("schema-variant"),
+                );
+            }
+        }
+        SchemaVariant::Materials => {
+            for (model_idx, model) in doc.agsi_model.iter().enumerate() {
+                if !model.components.is_empty() {
+                    result.warnings.push(ValidationWarning::new(
+                        format!("agsiModel[{}].components", model_idx),
+                        format!(
+                            "Materials variant documents should only contain materials, but model '{}' has {} components",
+                            model.id,
+                            model.components.len()
+                        ),
+                    ).with_code("schema-variant"));
+                }
+            }
+        }
+    }
+}
+
+/// Warn about CRS strings that PROJ doesn't recognise (e.g. `EPSG:999999`)
+///
+/// Checks model, geometry and project location CRSs. Each distinct code is
+/// looked up once.
+#[cfg(feature = "proj")]
+fn check_crs_codes(doc: &Document, result: &mut ValidationResult) {
+    fn geometry_crs<'a>(geometry: &'a Geometry, path: String, out: &mut Vec<(String, &'a str)>) {
+        if let Some(crs) = geometry.crs() {
+            out.push((format!("{}.crs", path), crs));
+        }
+        if let Geometry::Collection { geometries, .. } = geometry {
+            for (idx, member) in geometries.iter().enumerate() {
+                geometry_crs(member, format!("{}.geometries[{}]", path, idx), out);
+            }
+        }
+    }
+
+    let mut references = Vec::new();
+    if let Some(crs) = doc
+        .ags_project
+        .as_ref()
+        .and_then(|p| p.location.as_ref())
+        .and_then(|l| l.crs.as_deref())
+    {
+        references.push(("agsProject.location.crs".to_string(), crs));
+    }
+    for (model_idx, model) in doc.agsi_model.iter().enumerate() {
+        if let Some(crs) = model.crs.as_deref() {
+            references.push((format!("agsiModel[{}].crs", model_idx), crs));
+        }
+        for (comp_idx, component) in model.components.iter().enumerate() {
+            let path = format!("agsiModel[{}].components[{}].geometry", model_idx, comp_idx);
+            geometry_crs(&component.geometry, path, &mut references);
+        }
+    }
+
+    let mut known = std::collections::HashMap::new();
+    for (path, crs) in references {
+        let recognised = *known
+            .entry(crs)
+            .or_insert_with(|| proj::Proj::new(crs).is_ok());
+        if !recognised {
+            result.warnings.push(
+                ValidationWarning::new(
+                    path,
+                    format!("CRS '{}' is not a recognised EPSG/PROJ code", crs),
+                )
+                .with_code("unknown-crs"),
+            );
+        }
+    }
+}
+
+/// Geometry types that make sense for a component's role
+///
+/// Returns `None` for component types that accept any geometry.
+fn allowed_geometry_types(component_type: ComponentType) -> Option<&'static [&'static str]> {
+    match component_type {
+        ComponentType::Layer | ComponentType::Lens => Some(&["Polygon", "LineString"]),
+        ComponentType::Fault => Some(&["LineString", "Surface"]),
+        ComponentType::Volume => Some(&["Polygon", "Surface"]),
+        _ => None,
+    }
+}
+
+/// Material types a parameter code is expected to appear on
+///
+/// Returns `None` for parameters that apply to any material type.
+fn allowed_material_types(code: AgsiParameterCode) -> Option<&'static [MaterialType]> {
+    match code {
+        AgsiParameterCode::UndrainedShearStrength
+        | AgsiParameterCode::UndrainedShearStrengthTriaxial => Some(&[
+            MaterialType::Soil,
+            MaterialType::Fill,
+            MaterialType::MadeGround,
+            MaterialType::Anthropogenic,
+        ]),
+        AgsiParameterCode::UniaxialCompressiveStrength => Some(&[
+            MaterialType::Rock,
+            MaterialType::MadeGround,
+            MaterialType::Anthropogenic,
+        ]),
+        _ => None,
+    }
+}
+
+/// Find properties whose parameter code doesn't suit the material type
+///
+/// Properties that record a test method are treated as justified and skipped.
+fn inconsistent_properties(material: &Material) -> Vec<(usize, &str)> {
+    if material.material_type == MaterialType::Unknown {
+        return Vec::new();
+    }
+
+    material
+        .properties
+        .iter()
+        .enumerate()
+        .filter(|(_, prop)| prop.method.is_none())
+        .filter_map(|(idx, prop)| {
+            let code = AgsiParameterCode::from_code_id(&prop.name)?;
+            let allowed = allowed_material_types(code)?;
+            (!allowed.contains(&material.material_type)).then_some((idx, prop.name.as_str()))
+        })
+        .collect()
+}
+
 /// Quick validation - returns error if invalid
 pub fn validate_quick(doc: &Document) -> Result<()> {
     let result = validate_document(doc)?;
@@ -161,6 +1020,15 @@ pub fn validate_quick(doc: &Document) -> Result<()> {
 }
 
 impl ValidationResult {
+    /// Create a passing result with no findings
+    pub fn new() -> Self {
+        Self {
+            is_valid: true,
+            errors: Vec::new(),
+            warnings: Vec::new(),
+        }
+    }
+
     /// Check if validation passed
     pub fn is_valid(&self) -> bool {
         self.is_valid
@@ -176,40 +1044,103 @@ impl ValidationResult {
         &self.warnings
     }
 
+    /// Serialize as a SARIF 2.1.0 log for code-scanning tools
+    ///
+    /// `artifact_uri` is the path of the validated file as it should appear in
+    /// the report. Each issue's JSON pointer is recorded as its logical location.
+    pub fn to_sarif(&self, artifact_uri: &str) -> serde_json::Value {
+        use serde_json::json;
+
+        let location = |pointer: &str| {
+            json!([{
+                "physicalLocation": {
+                    "artifactLocation": { "uri": artifact_uri }
+                },
+                "logicalLocations": [{ "fullyQualifiedName": pointer }]
+            }])
+        };
+
+        let errors = self.errors.iter().map(|e| {
+            json!({
+                "ruleId": e.code.as_deref().unwrap_or("agsi"),
+                "level": "error",
+                "message": { "text": e.message },
+                "locations": location(&e.json_pointer),
+            })
+        });
+        let warnings = self.warnings.iter().map(|w| {
+            json!({
+                "ruleId": w.code.as_deref().unwrap_or("agsi"),
+                "level": "warning",
+                "message": { "text": w.message },
+                "locations": location(&w.json_pointer),
+            })
+        });
+
+        json!({
+            "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+            "version": "2.1.0",
+            "runs": [{
+                "tool": {
+                    "driver": {
+                        "name": "agsipls",
+                        "version": env!("CARGO_PKG_VERSION"),
+                    }
+                },
+                "results": errors.chain(warnings).collect::<Vec<_>>(),
+            }]
+        })
+    }
+}
+
+impl Default for ValidationResult {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl std::fmt::Display for ValidationResult {
     /// Format as human-readable string
-    pub fn to_string(&self) -> String {
-        let mut output = String::new();
+    ///
+    /// The alternate form (`{:#}`) uses ASCII markers only, for terminals
+    /// without Unicode or output that must stay plain.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (passed, failed, bullet) = if f.alternate() {
+            ("[OK]", "[FAIL]", "-")
+        } else {
+            ("✓", "✗", "•")
+        };
 
         if self.is_valid {
-            output.push_str("✓ Validation passed\n");
+            writeln!(f, "{} Validation passed", passed)?;
         } else {
-            output.push_str("✗ Validation failed\n");
+            writeln!(f, "{} Validation failed", failed)?;
         }
 
         if !self.errors.is_empty() {
-            output.push_str(&format!("\n{} Errors:\n", self.errors.len()));
+            writeln!(f, "\n{} Errors:", self.errors.len())?;
             for error in &self.errors {
-                output.push_str(&format!("  • {} - {}\n", error.path, error.message));
+                writeln!(f, "  {} {} - {}", bullet, error.path, error.message)?;
             }
         }
 
         if !self.warnings.is_empty() {
-            output.push_str(&format!("\n{} Warnings:\n", self.warnings.len()));
+            writeln!(f, "\n{} Warnings:", self.warnings.len())?;
             for warning in &self.warnings {
-                output.push_str(&format!("  • {} - {}\n", warning.path, warning.message));
+                writeln!(f, "  {} {} - {}", bullet, warning.path, warning.message)?;
             }
         }
 
-        output
+        Ok(())
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::material::{Material, MaterialType};
-    use crate::model::{ComponentType, GroundModel, ModelComponent, ModelDimension, ModelType};
     use crate::geometry::Geometry;
+    use crate::material::MaterialProperty;
+    use crate::model::{ComponentType, GroundModel, ModelComponent, ModelDimension, ModelType};
 
     #[test]
     fn test_valid_document() {
@@ -218,6 +1149,55 @@ mod tests {
         assert!(result.is_valid());
     }
 
+    #[test]
+    fn test_display_plain_form_is_ascii() {
+        let mut result = ValidationResult::new();
+        result
+            .warnings
+            .push(ValidationWarning::new("agsSchema.version", "Old version"));
+
+        assert!(result.to_string().contains("✓ Validation passed"));
+        let plain = format!("{:#}", result);
+        assert!(plain.is_ascii());
+        assert!(plain.contains("[OK] Validation passed"));
+        assert!(plain.contains("  - agsSchema.version - Old version"));
+    }
+
+    #[test]
+    fn test_materials_variant_with_components_warns() {
+        let mut doc = Document::new("TEST001");
+        doc.ags_schema.variant = Some(SchemaVariant::Materials.name().to_string());
+        let mut model = GroundModel::new(
+            "MODEL001",
+            "Model",
+            ModelType::Stratigraphic,
+            ModelDimension::TwoD,
+        );
+        model.add_material(Material::new("MAT001", "Clay", MaterialType::Soil));
+        doc.add_model(model.clone());
+
+        let result = validate_document(&doc).unwrap();
+        assert!(!result
+            .warnings
+            .iter()
+            .any(|w| w.code.as_deref() == Some("schema-variant")));
+
+        model.add_component(ModelComponent::new(
+            "COMP001",
+            "Layer",
+            ComponentType::Layer,
+            "MAT001",
+            Geometry::point(0.0, 0.0, 0.0),
+        ));
+        doc.agsi_model[0] = model;
+        let result = validate_document(&doc).unwrap();
+        assert!(result
+            .warnings
+            .iter()
+            .any(|w| w.code.as_deref() == Some("schema-variant")));
+        assert!(result.is_valid());
+    }
+
     #[test]
     fn test_missing_material_reference() {
         let mut doc = Document::new("TEST001");
@@ -242,6 +1222,14 @@ mod tests {
         let result = validate_document(&doc).unwrap();
         assert!(!result.is_valid());
         assert!(!result.errors.is_empty());
+        assert_eq!(
+            result.errors[0].path,
+            "agsiModel[0].components[0].materialId"
+        );
+        assert_eq!(
+            result.errors[0].json_pointer,
+            "/agsiModel/0/components/0/materialId"
+        );
     }
 
     #[test]
@@ -263,4 +1251,540 @@ mod tests {
         let result = validate_document(&doc).unwrap();
         assert!(!result.is_valid());
     }
+
+    #[test]
+    fn test_soil_with_ucs_warns() {
+        let mut doc = Document::new("TEST001");
+        let mut model = GroundModel::new(
+            "MODEL001",
+            "Test",
+            ModelType::Stratigraphic,
+            ModelDimension::TwoD,
+        );
+
+        model.add_material(
+            Material::new("MAT001", "Clay", MaterialType::Soil).with_property(
+                MaterialProperty::numeric(
+                    "UniaxialCompressiveStrength",
+                    5.0,
+                    Some("MPa".to_string()),
+                ),
+            ),
+        );
+        model.add_material(
+            Material::new("MAT002", "Mudstone", MaterialType::Rock).with_property(
+                MaterialProperty::numeric(
+                    "UniaxialCompressiveStrength",
+                    5.0,
+                    Some("MPa".to_string()),
+                ),
+            ),
+        );
+
+        doc.add_model(model);
+
+        let result = validate_document(&doc).unwrap();
+        assert!(result.is_valid());
+        assert_eq!(result.warnings.len(), 1);
+        assert_eq!(
+            result.warnings[0].path,
+            "agsiModel[0].materials[0].properties[0].name"
+        );
+    }
+
+    #[test]
+    fn test_to_json_pointer() {
+        assert_eq!(to_json_pointer("agsSchema.version"), "/agsSchema/version");
+        assert_eq!(
+            to_json_pointer("agsiModel[0].materials[2].properties[1].name"),
+            "/agsiModel/0/materials/2/properties/1/name"
+        );
+        assert_eq!(to_json_pointer("a/b~c"), "/a~1b~0c");
+        assert_eq!(to_json_pointer(""), "");
+    }
+
+    struct ClayNeedsCu;
+
+    impl ValidationRule for ClayNeedsCu {
+        fn check(&self, doc: &Document, out: &mut ValidationResult) {
+            for (model_idx, model) in doc.agsi_model.iter().enumerate() {
+                for (mat_idx, material) in model.materials.iter().enumerate() {
+                    if material.name.contains("Clay")
+                        && material.get_property("UndrainedShearStrength").is_none()
+                    {
+                        out.errors.push(ValidationError::new(
+                            format!("agsiModel[{}].materials[{}].properties", model_idx, mat_idx),
+                            "Clay must have an undrained shear strength",
+                            ValidationErrorType::Required,
+                        ));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_custom_rule() {
+        let mut doc = Document::new("TEST001");
+        let mut model = GroundModel::new(
+            "MODEL001",
+            "Test",
+            ModelType::Stratigraphic,
+            ModelDimension::TwoD,
+        );
+        model.add_material(Material::new("MAT001", "London Clay", MaterialType::Soil));
+        doc.add_model(model);
+
+        assert!(validate_document(&doc).unwrap().is_valid());
+
+        let rules: Vec<Box<dyn ValidationRule>> = vec![Box::new(ClayNeedsCu)];
+        let result = validate_document_with_rules(&doc, &rules).unwrap();
+        assert!(!result.is_valid());
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!(
+            result.errors[0].path,
+            "agsiModel[0].materials[0].properties"
+        );
+    }
+
+    #[test]
+    fn test_to_sarif() {
+        let mut doc = Document::new("TEST001");
+        let mut model = GroundModel::new(
+            "MODEL001",
+            "Test",
+            ModelType::Stratigraphic,
+            ModelDimension::TwoD,
+        );
+        for id in ["COMP001", "COMP002"] {
+            model.add_component(ModelComponent::new(
+                id,
+                "Layer",
+                ComponentType::Layer,
+                "MAT_MISSING",
+                Geometry::point(0.0, 0.0, 0.0),
+            ));
+        }
+        doc.add_model(model);
+
+        let result = validate_document(&doc).unwrap();
+        let sarif = result.to_sarif("model.agsi.json");
+
+        let results = sarif["runs"][0]["results"].as_array().unwrap();
+        assert_eq!(results.len(), result.errors.len() + result.warnings.len());
+        assert_eq!(results[0]["level"], "error");
+        assert_eq!(results[0]["ruleId"], "missing-material");
+    }
+
+    #[test]
+    fn test_placeholder_geometry_is_a_lint() {
+        let mut doc = Document::new("TEST001");
+        let mut model = GroundModel::new(
+            "MODEL001",
+            "Test",
+            ModelType::Stratigraphic,
+            ModelDimension::TwoD,
+        );
+        model.add_material(Material::new("MAT001", "Clay", MaterialType::Soil));
+        model.add_component(ModelComponent::new(
+            "COMP001",
+            "Layer 1",
+            ComponentType::Layer,
+            "MAT001",
+            Geometry::placeholder(),
+        ));
+        doc.add_model(model);
+
+        let result = validate_document(&doc).unwrap();
+        assert!(result.is_valid());
+        assert!(result.warnings.is_empty());
+
+        let result = lint_document(&doc).unwrap();
+        assert!(result.is_valid());
+        assert_eq!(result.warnings.len(), 1);
+        assert_eq!(
+            result.warnings[0].code.as_deref(),
+            Some("placeholder-geometry")
+        );
+    }
+
+    #[test]
+    fn test_point_geometry_on_volume_warns() {
+        let mut doc = Document::new("TEST001");
+        let mut model = GroundModel::new(
+            "MODEL001",
+            "Test",
+            ModelType::Stratigraphic,
+            ModelDimension::TwoD,
+        );
+        model.add_material(Material::new("MAT001", "Clay", MaterialType::Soil));
+        model.add_component(ModelComponent::new(
+            "COMP001",
+            "Cavity",
+            ComponentType::Volume,
+            "MAT001",
+            Geometry::point(0.0, 0.0, 0.0),
+        ));
+        doc.add_model(model);
+
+        let result = validate_document(&doc).unwrap();
+        assert!(result.is_valid());
+        assert_eq!(result.warnings.len(), 1);
+        assert_eq!(
+            result.warnings[0].code.as_deref(),
+            Some("component-geometry-mismatch")
+        );
+        assert_eq!(
+            result.warnings[0].path,
+            "agsiModel[0].components[0].geometry"
+        );
+    }
+
+    #[test]
+    fn test_void_with_strength_parameter_warns() {
+        let mut doc = Document::new("TEST001");
+        let mut model = GroundModel::new(
+            "MODEL001",
+            "Test",
+            ModelType::Stratigraphic,
+            ModelDimension::TwoD,
+        );
+        model.add_material(
+            Material::new("VOID001", "Tunnel", MaterialType::Void).with_property(
+                MaterialProperty::numeric("Cohesion", 5.0, Some("kPa".to_string())),
+            ),
+        );
+        model.add_component(ModelComponent::new(
+            "COMP001",
+            "Tunnel",
+            ComponentType::Volume,
+            "VOID001",
+            Geometry::placeholder(),
+        ));
+        doc.add_model(model);
+
+        let result = validate_document(&doc).unwrap();
+        assert!(result.is_valid());
+        let codes: Vec<_> = result
+            .warnings
+            .iter()
+            .filter_map(|w| w.code.as_deref())
+            .collect();
+        assert_eq!(codes, vec!["void-strength-parameter"]);
+    }
+
+    #[test]
+    fn test_unused_material_warns() {
+        let mut doc = Document::new("TEST001");
+        let mut model = GroundModel::new(
+            "MODEL001",
+            "Test",
+            ModelType::Stratigraphic,
+            ModelDimension::TwoD,
+        );
+        model.add_material(Material::new("MAT001", "Clay", MaterialType::Soil));
+        model.add_material(Material::new("MAT002", "Sand", MaterialType::Soil));
+        model.add_component(ModelComponent::new(
+            "COMP001",
+            "Clay layer",
+            ComponentType::Layer,
+            "MAT001",
+            Geometry::point(0.0, 0.0, 0.0),
+        ));
+        doc.add_model(model);
+
+        // A material-only model is a library, so nothing in it is unused
+        let mut library = GroundModel::new(
+            "MODEL002",
+            "Library",
+            ModelType::Stratigraphic,
+            ModelDimension::TwoD,
+        );
+        library.add_material(Material::new("MAT003", "Gravel", MaterialType::Soil));
+        doc.add_model(library);
+
+        let result = validate_document(&doc).unwrap();
+        assert!(result.is_valid());
+        let unused: Vec<_> = result
+            .warnings
+            .iter()
+            .filter(|w| w.code.as_deref() == Some("unused-material"))
+            .collect();
+        assert_eq!(unused.len(), 1);
+        assert_eq!(unused[0].path, "agsiModel[0].materials[1]");
+        assert!(unused[0].message.contains("MAT002"));
+    }
+
+    #[cfg(feature = "proj")]
+    #[test]
+    fn test_unknown_crs_code_warns() {
+        let mut doc = Document::new("TEST001");
+        for (id, crs) in [("MODEL001", "EPSG:27700"), ("MODEL002", "EPSG:999999")] {
+            let mut model =
+                GroundModel::new(id, "Test", ModelType::Stratigraphic, ModelDimension::TwoD);
+            model.crs = Some(crs.to_string());
+            doc.add_model(model);
+        }
+
+        let result = validate_document(&doc).unwrap();
+        let unknown: Vec<_> = result
+            .warnings
+            .iter()
+            .filter(|w| w.code.as_deref() == Some("unknown-crs"))
+            .collect();
+        assert_eq!(unknown.len(), 1);
+        assert_eq!(unknown[0].path, "agsiModel[1].crs");
+    }
+
+    #[test]
+    fn test_validate_root_reaches_property_summaries() {
+        use crate::agsi_model::{AgsiDataPropertySummary, AgsiModel, AgsiModelElement};
+
+        let mut element = AgsiModelElement::new();
+        element
+            .agsi_data_property_summary
+            .push(AgsiDataPropertySummary::new("Cohesion").with_std(4.0, 3));
+        let mut model = AgsiModel::new();
+        model.add_element(element);
+        let mut root = AgsiRoot::new("Test");
+        root.add_model(model);
+        assert!(validate_root(&root).unwrap().is_valid());
+
+        root.agsi_model[0].agsi_model_element[0].agsi_data_property_summary[0].value_std =
+            Some(-1.0);
+        let result = validate_root(&root).unwrap();
+        assert!(!result.is_valid());
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!(
+            result.errors[0].path,
+            "agsiModel[0].agsiModelElement[0].agsiDataPropertySummary[0].valueStd"
+        );
+    }
+
+    #[test]
+    fn test_validate_colours_flags_shared_colour() {
+        use crate::agsi_model::{AgsiModel, AgsiModelElement};
+
+        let mut model = AgsiModel::new();
+        for (id, colour) in [
+            ("EL001", "#FF0000"),
+            ("EL002", "#ff0000"),
+            ("EL003", "#00FF00"),
+        ] {
+            let mut element = AgsiModelElement::new();
+            element.element_id = Some(id.to_string());
+            element.colour_rgb = Some(colour.to_string());
+            model.add_element(element);
+        }
+        let mut root = AgsiRoot::new("Test");
+        root.add_model(model);
+
+        let result = validate_colours(&root).unwrap();
+        assert!(result.is_valid());
+        assert_eq!(result.warnings.len(), 1);
+        assert_eq!(result.warnings[0].code.as_deref(), Some("duplicate-colour"));
+        assert!(result.warnings[0].message.contains("EL001, EL002"));
+    }
+
+    #[test]
+    fn test_lint_root_includes_colour_collisions() {
+        use crate::agsi_model::{AgsiModel, AgsiModelElement};
+
+        let mut model = AgsiModel::new();
+        for id in ["EL001", "EL002"] {
+            let mut element = AgsiModelElement::new();
+            element.element_id = Some(id.to_string());
+            element.colour_rgb = Some("#FF0000".to_string());
+            model.add_element(element);
+        }
+        let mut root = AgsiRoot::new("Test");
+        root.add_model(model);
+
+        let result = lint_root(&root).unwrap();
+        assert!(result.is_valid());
+        assert_eq!(result.warnings.len(), 1);
+        assert_eq!(result.warnings[0].code.as_deref(), Some("duplicate-colour"));
+    }
+
+    #[test]
+    fn test_validate_property_files() {
+        use crate::agsi_model::{AgsiDataPropertyFromFile, AgsiModel, AgsiModelElement};
+
+        let mut element = AgsiModelElement::new();
+        element.set_property_from_file(&AgsiDataPropertyFromFile {
+            data_id: None,
+            file_id: "LAB-RESULTS".to_string(),
+            columns: Vec::new(),
+            description: None,
+            remarks: None,
+        });
+        let mut model = AgsiModel::new();
+        model.add_element(element);
+        let mut root = AgsiRoot::new("Test Project");
+        root.add_model(model);
+
+        let result = validate_property_files(&root).unwrap();
+        assert!(result.is_valid());
+        assert_eq!(
+            result.warnings[0].code.as_deref(),
+            Some("property-file-external")
+        );
+
+        root.agsi_model[0].document_set_id = Some("DOCSET001".to_string());
+        let result = validate_property_files(&root).unwrap();
+        assert!(result.warnings.is_empty());
+    }
+
+    #[test]
+    fn test_validate_parameter_aliases() {
+        use crate::agsi_model::{AgsiDataParameterValue, AgsiModel, AgsiModelElement};
+
+        let element = AgsiModelElement::new()
+            .with_parameter(AgsiDataParameterValue::numeric("Cu", 75.0))
+            .with_parameter(AgsiDataParameterValue::numeric("FrictionAngle", 30.0))
+            .with_parameter(AgsiDataParameterValue::numeric("AngleFriction", 30.0));
+        let mut model = AgsiModel::new();
+        model.add_element(element);
+        let mut root = AgsiRoot::new("Test");
+        root.add_model(model);
+
+        let result = validate_parameter_aliases(&root).unwrap();
+        assert_eq!(result.warnings.len(), 2);
+        assert!(result
+            .warnings
+            .iter()
+            .all(|w| w.code.as_deref() == Some("parameter-code-alias")));
+        assert_eq!(
+            result.warnings[0].path,
+            "agsiModel[0].agsiModelElement[0].agsiDataParameterValue[0].codeID"
+        );
+        assert!(result.warnings[0]
+            .message
+            .contains("'UndrainedShearStrength'"));
+        assert!(result.warnings[1].message.contains("'AngleFriction'"));
+    }
+
+    #[test]
+    fn test_validate_parameter_duplicates() {
+        use crate::agsi_model::{AgsiDataParameterValue, AgsiModel, AgsiModelElement};
+
+        let mut model = AgsiModel::new();
+        model.add_element(
+            AgsiModelElement::new()
+                .with_parameter(AgsiDataParameterValue::numeric("AngleFriction", 32.0))
+                .with_parameter(AgsiDataParameterValue::numeric("AngleFriction", 32.0))
+                .with_parameter(AgsiDataParameterValue::numeric("AngleFriction", 28.0))
+                .with_parameter(
+                    AgsiDataParameterValue::numeric("AngleFriction", 28.0).with_case("Design"),
+                ),
+        );
+        let mut root = AgsiRoot::new("Test");
+        root.add_model(model);
+
+        let result = validate_parameter_duplicates(&root).unwrap();
+        assert!(result.is_valid());
+        assert_eq!(result.warnings.len(), 1);
+        assert_eq!(
+            result.warnings[0].code.as_deref(),
+            Some("conflicting-parameter")
+        );
+        assert_eq!(
+            result.warnings[0].path,
+            "agsiModel[0].agsiModelElement[0].agsiDataParameterValue[2]"
+        );
+    }
+
+    #[test]
+    fn test_validate_geometry_flags_unclosed_ring() {
+        let mut doc = Document::new("TEST001");
+        let mut model = GroundModel::new(
+            "MODEL001",
+            "Test",
+            ModelType::Stratigraphic,
+            ModelDimension::TwoD,
+        );
+        // Missing material would fail full validation but is ignored here
+        model.add_component(ModelComponent::new(
+            "COMP001",
+            "Layer 1",
+            ComponentType::Layer,
+            "MAT_MISSING",
+            Geometry::polygon(
+                vec![
+                    [0.0, 0.0, 0.0],
+                    [10.0, 0.0, 0.0],
+                    [10.0, 10.0, 0.0],
+                    [0.0, 10.0, 0.0],
+                ],
+                vec![],
+            )
+            .unwrap(),
+        ));
+        doc.add_model(model);
+
+        let result = validate_geometry(&doc).unwrap();
+        assert!(!result.is_valid());
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!(
+            result.errors[0].code.as_deref(),
+            Some("geometry-ring-closure")
+        );
+        assert_eq!(
+            result.errors[0].path,
+            "agsiModel[0].components[0].geometry.rings[0]"
+        );
+
+        // Closing the ring clockwise leaves only a winding warning
+        doc.agsi_model[0].components[0].geometry = Geometry::polygon(
+            vec![
+                [0.0, 0.0, 0.0],
+                [0.0, 10.0, 0.0],
+                [10.0, 10.0, 0.0],
+                [10.0, 0.0, 0.0],
+                [0.0, 0.0, 0.0],
+            ],
+            vec![],
+        )
+        .unwrap();
+        let result = validate_geometry(&doc).unwrap();
+        assert!(result.is_valid());
+        assert_eq!(result.warnings[0].code.as_deref(), Some("geometry-winding"));
+    }
+
+    #[test]
+    fn test_measure_count_mismatch_is_an_error() {
+        let mut doc = Document::new("TEST001");
+        let mut model = GroundModel::new(
+            "MODEL001",
+            "Test",
+            ModelType::Stratigraphic,
+            ModelDimension::TwoD,
+        );
+        // `with_measures` refuses this, but a parsed file can still carry it
+        let line: Geometry = serde_json::from_value(serde_json::json!({
+            "type": "lineString",
+            "coordinates": [[0.0, 0.0, 0.0], [10.0, 0.0, 0.0], [20.0, 0.0, 0.0]],
+            "measures": [0.0, 10.0]
+        }))
+        .unwrap();
+        model.add_component(ModelComponent::new(
+            "COMP001",
+            "Boundary",
+            ComponentType::Boundary,
+            "MAT_MISSING",
+            line,
+        ));
+        doc.add_model(model);
+
+        let result = validate_geometry(&doc).unwrap();
+        assert!(!result.is_valid());
+        assert_eq!(
+            result.errors[0].code.as_deref(),
+            Some("geometry-measure-count")
+        );
+        assert_eq!(
+            result.errors[0].path,
+            "agsiModel[0].components[0].geometry.measures"
+        );
+    }
 }
@@ -0,0 +1,144 @@
+use serde::Deserialize;
+use std::collections::HashMap;
+
+use crate::material::PropertyValue;
+use crate::validation::{ValidationResult, ValidationWarning};
+use crate::{Document, Error, Result};
+
+/// Default file name for a validation profile
+pub const PROFILE_FILE_NAME: &str = ".agsi-validate.toml";
+
+/// Client-specific validation settings loaded from `.agsi-validate.toml`
+///
+/// ```toml
+/// disabled = ["schema-version"]
+///
+/// [ranges.UndrainedShearStrength]
+/// min = 0.0
+/// max = 500.0
+/// ```
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct ValidationProfile {
+    /// Check codes whose errors and warnings are suppressed
+    pub disabled: Vec<String>,
+
+    /// Plausible bounds for numeric properties, keyed by property name
+    pub ranges: HashMap<String, PlausibleRange>,
+}
+
+/// Plausible bounds for a numeric property
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+pub struct PlausibleRange {
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+}
+
+impl ValidationProfile {
+    /// Parse a profile from TOML
+    pub fn from_toml_str(content: &str) -> Result<Self> {
+        toml::from_str(content)
+            .map_err(|e| Error::Deserialization(format!("Invalid validation profile: {}", e)))
+    }
+
+    /// Load a profile from a TOML file
+    pub fn from_file(path: impl AsRef<std::path::Path>) -> Result<Self> {
+        let content = std::fs::read_to_string(path)?;
+        Self::from_toml_str(&content)
+    }
+
+    /// Check whether a check code is enabled
+    pub fn is_enabled(&self, code: Option<&str>) -> bool {
+        code.map_or(true, |c| !self.disabled.iter().any(|d| d == c))
+    }
+
+    /// Apply the profile to a validation result
+    ///
+    /// Adds `plausible-range` warnings for out-of-bounds properties, then drops
+    /// any errors and warnings raised by disabled checks.
+    pub fn apply(&self, doc: &Document, result: &mut ValidationResult) {
+        for (model_idx, model) in doc.agsi_model.iter().enumerate() {
+            for (mat_idx, material) in model.materials.iter().enumerate() {
+                for (prop_idx, prop) in material.properties.iter().enumerate() {
+                    let (Some(range), PropertyValue::Number(value)) =
+                        (self.ranges.get(&prop.name), &prop.value)
+                    else {
+                        continue;
+                    };
+
+                    let below = range.min.is_some_and(|min| *value < min);
+                    let above = range.max.is_some_and(|max| *value > max);
+                    if below || above {
+                        result.warnings.push(
+                            ValidationWarning::new(
+                                format!(
+                                    "agsiModel[{}].materials[{}].properties[{}].value",
+                                    model_idx, mat_idx, prop_idx
+                                ),
+                                format!(
+                                    "{} of {} on material '{}' is outside the plausible range",
+                                    prop.name, value, material.id
+                                ),
+                            )
+                            .with_code("plausible-range"),
+                        );
+                    }
+                }
+            }
+        }
+
+        result.errors.retain(|e| self.is_enabled(e.code.as_deref()));
+        result
+            .warnings
+            .retain(|w| self.is_enabled(w.code.as_deref()));
+        result.is_valid = result.errors.is_empty();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::material::{Material, MaterialProperty, MaterialType};
+    use crate::model::{GroundModel, ModelDimension, ModelType};
+    use crate::validation::validate_document;
+
+    #[test]
+    fn test_disable_schema_version_warning() {
+        let mut doc = Document::new("TEST001");
+        doc.ags_schema.version = "0.9.0".to_string();
+
+        let mut result = validate_document(&doc).unwrap();
+        assert_eq!(result.warnings.len(), 1);
+
+        let profile = ValidationProfile::from_toml_str(r#"disabled = ["schema-version"]"#).unwrap();
+        profile.apply(&doc, &mut result);
+        assert!(result.warnings.is_empty());
+    }
+
+    #[test]
+    fn test_plausible_range() {
+        let mut doc = Document::new("TEST001");
+        let mut model = GroundModel::new(
+            "MODEL001",
+            "Test",
+            ModelType::Stratigraphic,
+            ModelDimension::TwoD,
+        );
+        model.add_material(
+            Material::new("MAT001", "Clay", MaterialType::Soil).with_property(
+                MaterialProperty::numeric("UndrainedShearStrength", 900.0, Some("kPa".to_string())),
+            ),
+        );
+        doc.add_model(model);
+
+        let profile = ValidationProfile::from_toml_str(
+            "[ranges.UndrainedShearStrength]\nmin = 0.0\nmax = 500.0\n",
+        )
+        .unwrap();
+
+        let mut result = validate_document(&doc).unwrap();
+        profile.apply(&doc, &mut result);
+        assert_eq!(result.warnings.len(), 1);
+        assert_eq!(result.warnings[0].code.as_deref(), Some("plausible-range"));
+    }
+}
@@ -1,11 +1,6 @@
-use pyo3::prelude::*;
+use agsi_core::{material::MaterialType, validation, Document, Material};
 use pyo3::exceptions::PyValueError;
-use agsi_core::{
-    Document, Material, GroundModel,
-    material::MaterialType,
-    model::{ModelType, ModelDimension},
-    validation,
-};
+use pyo3::prelude::*;
 
 /// Python wrapper for AGSi Document
 #[pyclass]
@@ -41,13 +36,15 @@ impl PyDocument {
 
     /// Save document to JSON file
     fn to_json_file(&self, path: String) -> PyResult<()> {
-        self.inner.to_json_file(&path)
+        self.inner
+            .to_json_file(&path)
             .map_err(|e| PyValueError::new_err(format!("Failed to save: {}", e)))
     }
 
     /// Convert document to JSON string
     fn to_json_string(&self) -> PyResult<String> {
-        self.inner.to_json_string()
+        self.inner
+            .to_json_string()
             .map_err(|e| PyValueError::new_err(format!("Failed to serialize: {}", e)))
     }
 
@@ -76,9 +73,11 @@ impl PyDocument {
 
     /// String representation
     fn __repr__(&self) -> String {
-        format!("Document(file_id='{}', models={})", 
-            self.inner.ags_file.file_id, 
-            self.inner.agsi_model.len())
+        format!(
+            "Document(file_id='{}', models={})",
+            self.inner.ags_file.file_id,
+            self.inner.agsi_model.len()
+        )
     }
 }
 
@@ -107,7 +106,8 @@ impl PyValidationResult {
 
     /// Get errors as list of strings
     fn errors(&self) -> Vec<String> {
-        self.inner.errors()
+        self.inner
+            .errors()
             .iter()
             .map(|e| format!("{}: {}", e.path, e.message))
             .collect()
@@ -115,7 +115,8 @@ impl PyValidationResult {
 
     /// Get warnings as list of strings
     fn warnings(&self) -> Vec<String> {
-        self.inner.warnings()
+        self.inner
+            .warnings()
             .iter()
             .map(|w| format!("{}: {}", w.path, w.message))
             .collect()
@@ -124,11 +125,16 @@ impl PyValidationResult {
     /// String representation
     fn __repr__(&self) -> String {
         if self.inner.is_valid() {
-            format!("ValidationResult(valid=True, warnings={})", self.inner.warnings().len())
+            format!(
+                "ValidationResult(valid=True, warnings={})",
+                self.inner.warnings().len()
+            )
         } else {
-            format!("ValidationResult(valid=False, errors={}, warnings={})", 
-                self.inner.errors().len(), 
-                self.inner.warnings().len())
+            format!(
+                "ValidationResult(valid=False, errors={}, warnings={})",
+                self.inner.errors().len(),
+                self.inner.warnings().len()
+            )
         }
     }
 }
@@ -184,10 +190,10 @@ impl PyMaterial {
 
     /// String representation
     fn __repr__(&self) -> String {
-        format!("Material(id='{}', name='{}', type='{:?}')", 
-            self.inner.id, 
-            self.inner.name, 
-            self.inner.material_type)
+        format!(
+            "Material(id='{}', name='{}', type='{:?}')",
+            self.inner.id, self.inner.name, self.inner.material_type
+        )
     }
 }
 
@@ -197,9 +203,9 @@ fn agsi_py(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<PyDocument>()?;
     m.add_class::<PyValidationResult>()?;
     m.add_class::<PyMaterial>()?;
-    
+
     m.add("__version__", env!("CARGO_PKG_VERSION"))?;
     m.add("__doc__", "Python bindings for AGSi ground model library")?;
-    
+
     Ok(())
 }
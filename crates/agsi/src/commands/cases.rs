@@ -0,0 +1,75 @@
+use agsi_core::AgsiRoot;
+use anyhow::{Context, Result};
+use std::path::PathBuf;
+
+/// Label used for values that aren't tied to a case
+const NO_CASE: &str = "(none)";
+
+pub async fn execute(file: PathBuf, code: String) -> Result<()> {
+    let json = super::read_input(&file)?;
+    let root: AgsiRoot = serde_json::from_str(&json)
+        .with_context(|| format!("Failed to load file: {}", file.display()))?;
+
+    // Cases become columns in the order they are first seen
+    let mut cases: Vec<String> = Vec::new();
+    let mut rows: Vec<(String, Vec<(String, f64)>)> = Vec::new();
+    for element in root.agsi_model.iter().flat_map(|m| &m.agsi_model_element) {
+        let values: Vec<(String, f64)> = element
+            .case_comparison(&code)
+            .into_iter()
+            .map(|(case, value)| (case.unwrap_or_else(|| NO_CASE.to_string()), value))
+            .collect();
+        if values.is_empty() {
+            continue;
+        }
+        for (case, _) in &values {
+            if !cases.contains(case) {
+                cases.push(case.clone());
+            }
+        }
+        let name = element
+            .element_name
+            .clone()
+            .or_else(|| element.element_id.clone())
+            .unwrap_or_else(|| "(unnamed)".to_string());
+        rows.push((name, values));
+    }
+    if rows.is_empty() {
+        anyhow::bail!("No numeric {} values found", code);
+    }
+
+    let width = rows
+        .iter()
+        .map(|(name, _)| name.len())
+        .max()
+        .unwrap_or(0)
+        .max(7);
+    println!("⚖️  {} by case", code);
+    print!("{:<width$}", "Element");
+    for case in &cases {
+        print!("  {:>14}", case);
+    }
+    println!();
+    for (name, values) in &rows {
+        print!("{:<width$}", name);
+        for case in &cases {
+            let cell = values
+                .iter()
+                .filter(|(c, _)| c == case)
+                .map(|(_, v)| v.to_string())
+                .collect::<Vec<_>>()
+                .join(", ");
+            print!(
+                "  {:>14}",
+                if cell.is_empty() {
+                    "-".to_string()
+                } else {
+                    cell
+                }
+            );
+        }
+        println!();
+    }
+
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use agsi_core::statistics::{self, CharacteristicMethod};
+use agsi_core::Document;
+use anyhow::{Context, Result};
+use std::path::PathBuf;
+
+pub async fn execute(
+    file: PathBuf,
+    model_id: String,
+    code: String,
+    method: CharacteristicMethod,
+) -> Result<()> {
+    println!("📐 Characteristic value of {} in model {}", code, model_id);
+
+    let doc = Document::from_json_file(&file)
+        .with_context(|| format!("Failed to load file: {}", file.display()))?;
+
+    let model = doc
+        .get_model(&model_id)
+        .with_context(|| format!("Model not found: {}", model_id))?;
+
+    let values = statistics::parameter_values(model, &code);
+    let characteristic = statistics::characteristic_value(&values, method)
+        .with_context(|| format!("Cannot compute characteristic value for {}", code))?;
+
+    println!("   Values: {}", values.len());
+    if let Some(mean) = statistics::mean(&values) {
+        println!("   Mean: {:.3}", mean);
+    }
+    if let Some(std) = statistics::std_dev(&values) {
+        println!("   Std dev: {:.3}", std);
+    }
+    let method_name = match method {
+        CharacteristicMethod::Percentile5 => "5th percentile",
+        CharacteristicMethod::MeanMinusStd => "mean - 1 std dev",
+    };
+    println!(
+        "✅ Characteristic value ({}): {:.3}",
+        method_name, characteristic
+    );
+
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use agsi_core::{validation, Document};
+use anyhow::{Context, Result};
+use std::path::PathBuf;
+
+pub async fn execute(file: PathBuf) -> Result<()> {
+    println!("📐 Checking geometry: {}", file.display());
+
+    let doc = Document::from_json_file(&file)
+        .with_context(|| format!("Failed to load file: {}", file.display()))?;
+
+    let result = validation::validate_geometry(&doc).with_context(|| "Geometry check failed")?;
+
+    if result.is_valid() {
+        println!("✅ Geometry is valid!");
+    } else {
+        println!("❌ Geometry has errors");
+    }
+
+    if !result.errors().is_empty() || !result.warnings().is_empty() {
+        println!("\n{}", result);
+    }
+
+    let component_count: usize = doc.agsi_model.iter().map(|m| m.components.len()).sum();
+    println!("\n📊 Components checked: {}", component_count);
+
+    if !result.is_valid() {
+        std::process::exit(1);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,28 @@
+use anyhow::Result;
+use clap::CommandFactory;
+use clap_complete::Shell;
+use std::io::Write;
+
+use crate::Cli;
+
+pub fn execute(shell: Shell, out: &mut dyn Write) -> Result<()> {
+    let mut cmd = Cli::command();
+    let name = cmd.get_name().to_string();
+    clap_complete::generate(shell, &mut cmd, name, out);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bash_completions() {
+        let mut out = Vec::new();
+        execute(Shell::Bash, &mut out).unwrap();
+
+        let script = String::from_utf8(out).unwrap();
+        assert!(script.contains("agsipls"));
+        assert!(script.contains("validate"));
+    }
+}
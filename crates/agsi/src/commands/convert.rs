@@ -1,21 +1,45 @@
 use agsi_core::{serialization, Document};
 use anyhow::{Context, Result};
+use std::io::Write;
 use std::path::PathBuf;
 
-pub async fn execute(input: PathBuf, output: PathBuf, format: String) -> Result<()> {
-    println!("🔄 Converting: {} -> {}", input.display(), output.display());
+pub async fn execute(input: PathBuf, output: PathBuf, format: String, lenient: bool) -> Result<()> {
+    // Keep stdout clean for the converted document when writing to it
+    let to_stdout = super::is_stdio(&output);
+    let status = |line: String| {
+        if to_stdout {
+            eprintln!("{}", line);
+        } else {
+            println!("{}", line);
+        }
+    };
+
+    status(format!(
+        "🔄 Converting: {} -> {}",
+        input.display(),
+        output.display()
+    ));
 
-    // Load document
-    let doc = Document::from_json_file(&input)
-        .with_context(|| format!("Failed to load file: {}", input.display()))?;
+    // Load document (YAML by extension, JSON otherwise)
+    let doc = if lenient {
+        let json = super::read_input_async(&input).await?;
+        Document::from_json_str_coerce_numbers(&json)
+            .with_context(|| format!("Failed to load file: {}", input.display()))?
+    } else {
+        super::read_document_async(&input).await?
+    };
 
     // Determine output format
     let format_enum = match format.to_lowercase().as_str() {
         "json" => serialization::Format::Json,
         "json-compact" => serialization::Format::JsonCompact,
+        "yaml" | "yml" => serialization::Format::Yaml,
         "avro" => serialization::Format::Avro,
         "protobuf" | "proto" | "pb" => serialization::Format::Protobuf,
-        _ => anyhow::bail!("Unsupported format: {}. Use json, avro, or protobuf", format),
+        _ => anyhow::bail!(
+            "Unsupported format: {}. Use json, yaml, avro, or protobuf",
+            format
+        ),
     };
 
     // Serialize
@@ -23,12 +47,19 @@ pub async fn execute(input: PathBuf, output: PathBuf, format: String) -> Result<
         .with_context(|| format!("Failed to serialize to {}", format))?;
 
     // Write output
-    std::fs::write(&output, data)
-        .with_context(|| format!("Failed to write to {}", output.display()))?;
+    if to_stdout {
+        std::io::stdout()
+            .write_all(&data)
+            .context("Failed to write to stdout")?;
+    } else {
+        tokio::fs::write(&output, data)
+            .await
+            .with_context(|| format!("Failed to write to {}", output.display()))?;
+    }
 
-    println!("✅ Converted successfully");
-    println!("   Format: {}", format);
-    println!("   Output: {}", output.display());
+    status("✅ Converted successfully".to_string());
+    status(format!("   Format: {}", format));
+    status(format!("   Output: {}", output.display()));
 
     Ok(())
 }
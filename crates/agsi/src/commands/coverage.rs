@@ -0,0 +1,36 @@
+use agsi_core::coverage;
+use anyhow::{Context, Result};
+use std::path::PathBuf;
+
+pub async fn execute(file: PathBuf) -> Result<()> {
+    println!("📋 Field coverage for: {}", file.display());
+
+    // Read as plain JSON so both the AGSi schema layout and documents work
+    let text = super::read_input(&file)?;
+    let value: serde_json::Value = serde_json::from_str(&text)
+        .with_context(|| format!("Failed to parse JSON: {}", file.display()))?;
+
+    let report = coverage::field_coverage(&value);
+    if report.is_empty() {
+        println!("   No models, elements, materials or components found");
+        return Ok(());
+    }
+
+    let mut object = "";
+    for entry in &report {
+        if entry.object != object {
+            object = &entry.object;
+            println!("\n{}:", object);
+        }
+        let marker = if entry.is_complete() {
+            "✅"
+        } else {
+            "⚠️ "
+        };
+        println!("   {} {}", marker, entry);
+    }
+
+    let complete = report.iter().filter(|e| e.is_complete()).count();
+    println!("\n📊 {}/{} fields fully populated", complete, report.len());
+    Ok(())
+}
@@ -1,4 +1,8 @@
-use agsi_core::{Document, Material, material::MaterialType, GroundModel, model::{ModelType, ModelDimension}};
+use agsi_core::{
+    material::MaterialType,
+    model::{ModelDimension, ModelType},
+    Document, GroundModel, Material,
+};
 use anyhow::{Context, Result};
 use std::path::PathBuf;
 
@@ -19,11 +23,19 @@ pub async fn document(id: String, output: PathBuf) -> Result<()> {
 pub async fn material(id: String, name: String, output: PathBuf) -> Result<()> {
     println!("🪨 Creating new material: {} ({})", name, id);
 
-    // Create a simple document with just the material
+    let doc = material_document(Material::new(&id, &name, MaterialType::Soil));
+
+    doc.to_json_file(&output)
+        .with_context(|| format!("Failed to write to {}", output.display()))?;
+
+    println!("✅ Material created in: {}", output.display());
+    Ok(())
+}
+
+/// Create a document holding just one material, in a temporary model
+pub(crate) fn material_document(material: Material) -> Document {
     let mut doc = Document::new(uuid::Uuid::new_v4().to_string());
-    
-    let material = Material::new(&id, &name, MaterialType::Soil);
-    
+
     let mut model = GroundModel::new(
         "temp_model",
         "Temporary model for material",
@@ -32,26 +44,16 @@ pub async fn material(id: String, name: String, output: PathBuf) -> Result<()> {
     );
     model.add_material(material);
     doc.add_model(model);
-
-    doc.to_json_file(&output)
-        .with_context(|| format!("Failed to write to {}", output.display()))?;
-
-    println!("✅ Material created in: {}", output.display());
-    Ok(())
+    doc
 }
 
 pub async fn model(id: String, name: String, output: PathBuf) -> Result<()> {
     println!("🗺️  Creating new model: {} ({})", name, id);
 
     let mut doc = Document::new(uuid::Uuid::new_v4().to_string());
-    
-    let model = GroundModel::new(
-        &id,
-        &name,
-        ModelType::Stratigraphic,
-        ModelDimension::TwoD,
-    );
-    
+
+    let model = GroundModel::new(&id, &name, ModelType::Stratigraphic, ModelDimension::TwoD);
+
     doc.add_model(model);
 
     doc.to_json_file(&output)
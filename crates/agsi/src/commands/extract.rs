@@ -1,11 +1,14 @@
-use agsi_core::Document;
+use agsi_core::{Document, GroundModel};
 use anyhow::{Context, Result};
+use serde_json::json;
 use std::path::PathBuf;
 
 pub async fn execute(
     file: PathBuf,
     model_id: Option<String>,
     output: Option<PathBuf>,
+    include_geometry: bool,
+    wrap: bool,
 ) -> Result<()> {
     println!("📤 Extracting materials from: {}", file.display());
 
@@ -33,7 +36,16 @@ pub async fn execute(
     println!("   Materials: {}", model.materials.len());
 
     // Create output
-    let output_data = serde_json::to_string_pretty(&model.materials)?;
+    let materials = if include_geometry {
+        materials_with_geometry(model)?
+    } else {
+        serde_json::to_value(&model.materials)?
+    };
+    let output_data = if wrap {
+        serde_json::to_string_pretty(&wrap_materials(model, materials))?
+    } else {
+        serde_json::to_string_pretty(&materials)?
+    };
 
     if let Some(output_path) = output {
         std::fs::write(&output_path, output_data)
@@ -45,12 +57,110 @@ pub async fn execute(
 
     // Summary
     for material in &model.materials {
-        println!("   • {} ({}) - {} properties", 
-            material.name, 
-            material.id, 
+        println!(
+            "   • {} ({}) - {} properties",
+            material.name,
+            material.id,
             material.properties.len()
         );
     }
 
     Ok(())
 }
+
+/// Wrap extracted materials with the provenance of the model they came from
+fn wrap_materials(model: &GroundModel, materials: serde_json::Value) -> serde_json::Value {
+    json!({
+        "model_id": model.id,
+        "model_name": model.name,
+        "material_count": model.materials.len(),
+        "materials": materials,
+    })
+}
+
+/// Join each material with the geometries of the components that reference it
+fn materials_with_geometry(model: &GroundModel) -> Result<serde_json::Value> {
+    let materials = model
+        .materials
+        .iter()
+        .map(|material| {
+            let mut value = serde_json::to_value(material)?;
+            let components = model
+                .get_components_by_material(&material.id)
+                .into_iter()
+                .map(|c| {
+                    json!({
+                        "componentId": c.id,
+                        "name": c.name,
+                        "geometry": c.geometry,
+                    })
+                })
+                .collect::<Vec<_>>();
+            if let Some(obj) = value.as_object_mut() {
+                obj.insert("components".to_string(), json!(components));
+            }
+            Ok(value)
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(json!(materials))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use agsi_core::geometry::Geometry;
+    use agsi_core::material::MaterialType;
+    use agsi_core::model::{ComponentType, ModelComponent, ModelDimension, ModelType};
+    use agsi_core::Material;
+
+    #[test]
+    fn test_materials_with_geometry() {
+        let mut model = GroundModel::new(
+            "MODEL001",
+            "Test",
+            ModelType::Stratigraphic,
+            ModelDimension::TwoD,
+        );
+        model.add_material(Material::new("MAT001", "Clay", MaterialType::Soil));
+        model.add_material(Material::new("MAT002", "Sand", MaterialType::Soil));
+        model.add_component(ModelComponent::new(
+            "COMP001",
+            "Clay Layer",
+            ComponentType::Layer,
+            "MAT001",
+            Geometry::point(1.0, 2.0, 3.0),
+        ));
+
+        let value = materials_with_geometry(&model).unwrap();
+
+        let clay = &value[0];
+        assert_eq!(clay["id"], "MAT001");
+        assert_eq!(clay["components"][0]["componentId"], "COMP001");
+        assert_eq!(clay["components"][0]["geometry"]["type"], "point");
+        assert_eq!(
+            clay["components"][0]["geometry"]["coordinates"],
+            json!([1.0, 2.0, 3.0])
+        );
+        assert_eq!(value[1]["components"], json!([]));
+    }
+
+    #[test]
+    fn test_wrap_materials() {
+        let mut model = GroundModel::new(
+            "MODEL001",
+            "Test",
+            ModelType::Stratigraphic,
+            ModelDimension::TwoD,
+        );
+        model.add_material(Material::new("MAT001", "Clay", MaterialType::Soil));
+
+        let materials = serde_json::to_value(&model.materials).unwrap();
+        let value = wrap_materials(&model, materials);
+
+        assert_eq!(value["model_id"], "MODEL001");
+        assert_eq!(value["model_name"], "Test");
+        assert_eq!(value["material_count"], 1);
+        assert_eq!(value["materials"][0]["id"], "MAT001");
+    }
+}
@@ -0,0 +1,84 @@
+use agsi_core::{Document, Material};
+use anyhow::{Context, Result};
+use std::path::PathBuf;
+
+/// Find a material by ID in any model, returning it with the models it's in
+fn find_material<'a>(doc: &'a Document, id: &str) -> Option<(&'a Material, Vec<&'a str>)> {
+    let mut found = doc
+        .agsi_model
+        .iter()
+        .filter_map(|model| Some((model.get_material(id)?, model.id.as_str())));
+    let (material, first_model) = found.next()?;
+    let mut models = vec![first_model];
+    models.extend(found.map(|(_, model)| model));
+    Some((material, models))
+}
+
+pub async fn execute(file: PathBuf, id: String, output: PathBuf) -> Result<()> {
+    println!("🪨 Extracting material {} from: {}", id, file.display());
+
+    let doc = Document::from_json_file(&file)
+        .with_context(|| format!("Failed to load file: {}", file.display()))?;
+
+    let (material, models) = find_material(&doc, &id)
+        .with_context(|| format!("Material not found in any model: {}", id))?;
+    println!("   Found in model: {}", models[0]);
+    if models.len() > 1 {
+        println!(
+            "   ⚠️  Also defined in: {} (using the first)",
+            models[1..].join(", ")
+        );
+    }
+
+    let extracted = super::create::material_document(material.clone());
+    extracted
+        .to_json_file(&output)
+        .with_context(|| format!("Failed to write to {}", output.display()))?;
+
+    println!(
+        "✅ Material {} ({}) written to: {}",
+        material.name,
+        material.id,
+        output.display()
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use agsi_core::material::{MaterialProperty, MaterialType};
+    use agsi_core::model::{ModelDimension, ModelType};
+    use agsi_core::{validation, GroundModel};
+
+    #[test]
+    fn test_extracted_material_document_validates() {
+        let mut doc = Document::new("DOC001");
+        for (model_id, material_id) in [("MODEL001", "MAT001"), ("MODEL002", "MAT002")] {
+            let mut model = GroundModel::new(
+                model_id,
+                "Model",
+                ModelType::Stratigraphic,
+                ModelDimension::TwoD,
+            );
+            model.add_material(
+                Material::new(material_id, "Clay", MaterialType::Soil).with_property(
+                    MaterialProperty::numeric("density", 1800.0, Some("kg/m3".to_string())),
+                ),
+            );
+            doc.add_model(model);
+        }
+
+        let (material, models) = find_material(&doc, "MAT002").unwrap();
+        assert_eq!(models, vec!["MODEL002"]);
+
+        let extracted = crate::commands::create::material_document(material.clone());
+        assert_eq!(extracted.agsi_model.len(), 1);
+        assert_eq!(extracted.agsi_model[0].materials[0].properties.len(), 1);
+        assert!(validation::validate_document(&extracted)
+            .unwrap()
+            .is_valid());
+
+        assert!(find_material(&doc, "MAT999").is_none());
+    }
+}
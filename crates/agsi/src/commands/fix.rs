@@ -0,0 +1,109 @@
+use agsi_core::repair::{self, Repair};
+use agsi_core::{validation, Document};
+use anyhow::{Context, Result};
+use serde::Serialize;
+use std::path::PathBuf;
+
+/// Audit trail of a fix run
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct FixReport {
+    errors_before: usize,
+    warnings_before: usize,
+    errors_after: usize,
+    warnings_after: usize,
+    changes: Vec<Repair>,
+}
+
+/// Repair a document in place and report what changed
+fn fix_document(doc: &mut Document) -> Result<FixReport> {
+    let before = validation::validate_document(doc).with_context(|| "Validation failed")?;
+    let changes = repair::repair(doc);
+    let after = validation::validate_document(doc).with_context(|| "Validation failed")?;
+
+    Ok(FixReport {
+        errors_before: before.errors().len(),
+        warnings_before: before.warnings().len(),
+        errors_after: after.errors().len(),
+        warnings_after: after.warnings().len(),
+        changes,
+    })
+}
+
+pub async fn execute(file: PathBuf, output: PathBuf, report: Option<PathBuf>) -> Result<()> {
+    println!("🔧 Fixing: {}", file.display());
+
+    let mut doc = Document::from_json_file(&file)
+        .with_context(|| format!("Failed to load file: {}", file.display()))?;
+    let fix_report = fix_document(&mut doc)?;
+
+    for change in &fix_report.changes {
+        println!("   • {}: {}", change.path, change.description);
+    }
+    println!(
+        "   Errors: {} → {}, warnings: {} → {}",
+        fix_report.errors_before,
+        fix_report.errors_after,
+        fix_report.warnings_before,
+        fix_report.warnings_after
+    );
+
+    doc.to_json_file(&output)
+        .with_context(|| format!("Failed to write to {}", output.display()))?;
+    println!("✅ Fixed file written to: {}", output.display());
+
+    if let Some(report) = report {
+        std::fs::write(&report, serde_json::to_string_pretty(&fix_report)?)
+            .with_context(|| format!("Failed to write to {}", report.display()))?;
+        println!("📝 Report written to: {}", report.display());
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use agsi_core::geometry::Geometry;
+    use agsi_core::material::{Material, MaterialType};
+    use agsi_core::model::{ComponentType, GroundModel, ModelComponent, ModelDimension, ModelType};
+
+    #[test]
+    fn test_fix_closes_ring_and_reports_it() {
+        let mut model = GroundModel::new(
+            "MODEL001",
+            "Model",
+            ModelType::Stratigraphic,
+            ModelDimension::TwoD,
+        );
+        model.add_material(Material::new("MAT001", "Clay", MaterialType::Soil));
+        model.add_component(ModelComponent::new(
+            "COMP001",
+            "Layer",
+            ComponentType::Layer,
+            "MAT001",
+            Geometry::Polygon {
+                rings: vec![vec![[0.0, 0.0, 0.0], [10.0, 0.0, 0.0], [10.0, 10.0, 0.0]]],
+                crs: None,
+                wkt: None,
+                wkb: None,
+            },
+        ));
+        let mut doc = Document::new("DOC001");
+        doc.add_model(model);
+
+        let report = fix_document(&mut doc).unwrap();
+        assert!(report.errors_before > 0);
+        assert_eq!(report.errors_after, 0);
+        assert_eq!(report.changes[0].code, "geometry-ring-closure");
+
+        let json = serde_json::to_value(&report).unwrap();
+        assert_eq!(
+            json["changes"][0]["path"],
+            "agsiModel[0].components[0].geometry.rings[0]"
+        );
+
+        // A second pass has nothing left to do
+        assert!(fix_document(&mut doc).unwrap().changes.is_empty());
+    }
+}
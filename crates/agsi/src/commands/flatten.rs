@@ -0,0 +1,42 @@
+use agsi_core::Document;
+use anyhow::{Context, Result};
+use std::path::PathBuf;
+
+pub async fn execute(
+    file: PathBuf,
+    model_id: String,
+    x: f64,
+    y: f64,
+    output: PathBuf,
+) -> Result<()> {
+    println!("📍 Flattening model {} at ({}, {})", model_id, x, y);
+
+    let doc = Document::from_json_file(&file)
+        .with_context(|| format!("Failed to load file: {}", file.display()))?;
+
+    let model = doc
+        .get_model(&model_id)
+        .with_context(|| format!("Model not found: {}", model_id))?;
+
+    let column = model
+        .flatten_column(x, y)
+        .with_context(|| format!("Failed to sample column at ({}, {})", x, y))?;
+
+    for component in &column.components {
+        println!(
+            "   • {} ({}): {:.2} to {:.2}",
+            component.name,
+            component.material_id,
+            component.top.unwrap_or_default(),
+            component.base.unwrap_or_default()
+        );
+    }
+
+    let mut out = doc.subset(&[], true)?;
+    out.add_model(column);
+    out.to_json_file(&output)
+        .with_context(|| format!("Failed to write to {}", output.display()))?;
+
+    println!("✅ Column written to: {}", output.display());
+    Ok(())
+}
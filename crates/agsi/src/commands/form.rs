@@ -1,8 +1,8 @@
 use agsi_core::{
-    Document, Material, MaterialProperty,
     material::{MaterialType, PropertySource, PropertyValue},
-    GroundModel, model::{ModelType, ModelDimension},
-    project::{Project, Location},
+    model::{ModelDimension, ModelType},
+    project::{Location, Project},
+    Document, GroundModel, Material, MaterialProperty,
 };
 use anyhow::{Context, Result};
 use inquire::{Confirm, Select, Text};
@@ -25,7 +25,10 @@ async fn create_document_form(output: Option<PathBuf>) -> Result<()> {
 
     // File ID
     let file_id = Text::new("Document ID:")
-        .with_default(&format!("DOC-{}", chrono::Utc::now().format("%Y%m%d-%H%M%S")))
+        .with_default(&format!(
+            "DOC-{}",
+            chrono::Utc::now().format("%Y%m%d-%H%M%S")
+        ))
         .prompt()?;
 
     // File name
@@ -48,8 +51,7 @@ async fn create_document_form(output: Option<PathBuf>) -> Result<()> {
         .with_default(false)
         .prompt()?;
 
-    let mut doc = Document::new(&file_id)
-        .with_author(&author);
+    let mut doc = Document::new(&file_id).with_author(&author);
 
     if !file_name.is_empty() {
         doc = doc.with_file_name(&file_name);
@@ -68,9 +70,11 @@ async fn create_document_form(output: Option<PathBuf>) -> Result<()> {
     let output_path = if let Some(path) = output {
         path
     } else {
-        PathBuf::from(Text::new("Output file:")
-            .with_default(&format!("{}.agsi.json", file_id))
-            .prompt()?)
+        PathBuf::from(
+            Text::new("Output file:")
+                .with_default(&format!("{}.agsi.json", file_id))
+                .prompt()?,
+        )
     };
 
     doc.to_json_file(&output_path)
@@ -83,19 +87,15 @@ async fn create_document_form(output: Option<PathBuf>) -> Result<()> {
 async fn create_project_form() -> Result<Project> {
     println!("\n🏗️  Project Information\n");
 
-    let project_id = Text::new("Project ID:")
-        .prompt()?;
+    let project_id = Text::new("Project ID:").prompt()?;
 
-    let project_name = Text::new("Project name:")
-        .prompt()?;
+    let project_name = Text::new("Project name:").prompt()?;
 
     let description = Text::new("Description (optional):")
         .with_default("")
         .prompt()?;
 
-    let client = Text::new("Client (optional):")
-        .with_default("")
-        .prompt()?;
+    let client = Text::new("Client (optional):").with_default("").prompt()?;
 
     let contractor = Text::new("Contractor (optional):")
         .with_default("")
@@ -119,9 +119,8 @@ async fn create_project_form() -> Result<Project> {
         .prompt()?;
 
     if add_location {
-        let location_name = Text::new("Location name:")
-            .prompt()?;
-        
+        let location_name = Text::new("Location name:").prompt()?;
+
         let location = Location::new(&location_name);
         project = project.with_location(location);
     }
@@ -134,17 +133,25 @@ async fn create_material_form(output: Option<PathBuf>) -> Result<()> {
 
     // Material ID
     let material_id = Text::new("Material ID:")
-        .with_default(&format!("MAT-{}", chrono::Utc::now().format("%Y%m%d-%H%M%S")))
+        .with_default(&format!(
+            "MAT-{}",
+            chrono::Utc::now().format("%Y%m%d-%H%M%S")
+        ))
         .prompt()?;
 
     // Material name
-    let material_name = Text::new("Material name:")
-        .prompt()?;
+    let material_name = Text::new("Material name:").prompt()?;
 
     // Material type
     let material_types = vec![
-        "Soil", "Rock", "Fill", "Made Ground", 
-        "Anthropogenic", "Water", "Void", "Unknown"
+        "Soil",
+        "Rock",
+        "Fill",
+        "Made Ground",
+        "Anthropogenic",
+        "Water",
+        "Void",
+        "Unknown",
     ];
     let selected_type = Select::new("Material type:", material_types).prompt()?;
 
@@ -191,7 +198,7 @@ async fn create_material_form(output: Option<PathBuf>) -> Result<()> {
             let add_more = Confirm::new("Add another property?")
                 .with_default(false)
                 .prompt()?;
-            
+
             if !add_more {
                 break;
             }
@@ -213,9 +220,11 @@ async fn create_material_form(output: Option<PathBuf>) -> Result<()> {
     let output_path = if let Some(path) = output {
         path
     } else {
-        PathBuf::from(Text::new("Output file:")
-            .with_default(&format!("{}.agsi.json", material_id))
-            .prompt()?)
+        PathBuf::from(
+            Text::new("Output file:")
+                .with_default(&format!("{}.agsi.json", material_id))
+                .prompt()?,
+        )
     };
 
     doc.to_json_file(&output_path)
@@ -228,8 +237,7 @@ async fn create_material_form(output: Option<PathBuf>) -> Result<()> {
 async fn create_property_form() -> Result<MaterialProperty> {
     println!("\n  ➕ New Property\n");
 
-    let prop_name = Text::new("  Property name (e.g., density, cohesion):")
-        .prompt()?;
+    let prop_name = Text::new("  Property name (e.g., density, cohesion):").prompt()?;
 
     let value_types = vec!["Number", "Text", "Boolean", "Range"];
     let value_type = Select::new("  Value type:", value_types).prompt()?;
@@ -274,7 +282,7 @@ async fn create_property_form() -> Result<MaterialProperty> {
 
     let sources = vec!["Tested", "Estimated", "Literature", "Assumed", "Calculated"];
     let source_str = Select::new("  Source:", sources).prompt()?;
-    
+
     let source = match source_str {
         "Tested" => PropertySource::Tested,
         "Estimated" => PropertySource::Estimated,
@@ -284,11 +292,15 @@ async fn create_property_form() -> Result<MaterialProperty> {
         _ => PropertySource::Assumed,
     };
 
-    let mut prop = MaterialProperty {
+    let prop = MaterialProperty {
         name: prop_name,
         value,
         unit: if unit.is_empty() { None } else { Some(unit) },
-        method: if method.is_empty() { None } else { Some(method) },
+        method: if method.is_empty() {
+            None
+        } else {
+            Some(method)
+        },
         source: Some(source),
     };
 
@@ -300,17 +312,23 @@ async fn create_model_form(output: Option<PathBuf>) -> Result<()> {
 
     // Model ID
     let model_id = Text::new("Model ID:")
-        .with_default(&format!("MODEL-{}", chrono::Utc::now().format("%Y%m%d-%H%M%S")))
+        .with_default(&format!(
+            "MODEL-{}",
+            chrono::Utc::now().format("%Y%m%d-%H%M%S")
+        ))
         .prompt()?;
 
     // Model name
-    let model_name = Text::new("Model name:")
-        .prompt()?;
+    let model_name = Text::new("Model name:").prompt()?;
 
     // Model type
     let model_types = vec![
-        "Stratigraphic", "Structural", "Hydrogeological",
-        "Geotechnical", "Environmental", "Composite"
+        "Stratigraphic",
+        "Structural",
+        "Hydrogeological",
+        "Geotechnical",
+        "Environmental",
+        "Composite",
     ];
     let selected_type = Select::new("Model type:", model_types).prompt()?;
 
@@ -360,9 +378,11 @@ async fn create_model_form(output: Option<PathBuf>) -> Result<()> {
     let output_path = if let Some(path) = output {
         path
     } else {
-        PathBuf::from(Text::new("Output file:")
-            .with_default(&format!("{}.agsi.json", model_id))
-            .prompt()?)
+        PathBuf::from(
+            Text::new("Output file:")
+                .with_default(&format!("{}.agsi.json", model_id))
+                .prompt()?,
+        )
     };
 
     doc.to_json_file(&output_path)
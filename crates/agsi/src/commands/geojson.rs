@@ -0,0 +1,25 @@
+use agsi_core::Document;
+use anyhow::{Context, Result};
+use std::path::PathBuf;
+
+pub async fn execute(file: PathBuf, output: PathBuf) -> Result<()> {
+    println!("🗺️  Exporting GeoJSON: {}", file.display());
+
+    let doc = Document::from_json_file(&file)
+        .with_context(|| format!("Failed to load file: {}", file.display()))?;
+
+    let geojson = doc.to_geojson_all();
+    let count = geojson["features"].as_array().map_or(0, |f| f.len());
+
+    let json = serde_json::to_string_pretty(&geojson)?;
+    std::fs::write(&output, json)
+        .with_context(|| format!("Failed to write to {}", output.display()))?;
+
+    println!(
+        "✅ Wrote {} features from {} models to: {}",
+        count,
+        doc.agsi_model.len(),
+        output.display()
+    );
+    Ok(())
+}
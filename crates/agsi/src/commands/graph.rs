@@ -0,0 +1,17 @@
+use anyhow::{Context, Result};
+use std::path::PathBuf;
+
+pub async fn execute(file: PathBuf, output: PathBuf) -> Result<()> {
+    println!("🕸️  Building reference graph for: {}", file.display());
+
+    let doc = super::read_document(&file)?;
+    let graph = doc.reference_graph();
+
+    std::fs::write(&output, graph.to_dot())
+        .with_context(|| format!("Failed to write to {}", output.display()))?;
+
+    println!("   Nodes: {}", graph.nodes.len());
+    println!("   Edges: {}", graph.edges.len());
+    println!("✅ DOT graph written to: {}", output.display());
+    Ok(())
+}
@@ -0,0 +1,119 @@
+use agsi_core::geometry::Geometry;
+use agsi_core::material::{Material, MaterialType};
+use agsi_core::model::{ComponentType, GroundModel, ModelComponent, ModelDimension, ModelType};
+use agsi_core::Document;
+use anyhow::{bail, Context, Result};
+use serde::Deserialize;
+use std::path::PathBuf;
+
+/// One row of a layers table
+#[derive(Debug, Deserialize)]
+struct LayerRow {
+    material_id: String,
+    material_name: String,
+    material_type: String,
+    top: f64,
+    base: f64,
+}
+
+/// Parse a material type such as `soil` or `Made ground`
+fn parse_material_type(text: &str) -> Result<MaterialType> {
+    let name = text.trim().to_uppercase().replace([' ', '-'], "_");
+    serde_json::from_value(serde_json::Value::String(name))
+        .with_context(|| format!("Unknown material type: {}", text))
+}
+
+/// Build a 1D model from `material_id,material_name,material_type,top,base` rows
+///
+/// Each row becomes a vertical layer component; materials are added the
+/// first time their ID appears.
+fn build_model(reader: impl std::io::Read, id: &str, name: &str) -> Result<GroundModel> {
+    let mut model = GroundModel::new(id, name, ModelType::Stratigraphic, ModelDimension::OneD);
+
+    for (idx, row) in csv::Reader::from_reader(reader)
+        .deserialize::<LayerRow>()
+        .enumerate()
+    {
+        // Header is line 1
+        let line = idx + 2;
+        let row = row.with_context(|| format!("Invalid row on line {}", line))?;
+        if row.top < row.base {
+            bail!("Line {}: top {} is below base {}", line, row.top, row.base);
+        }
+
+        if model.get_material(&row.material_id).is_none() {
+            let material_type = parse_material_type(&row.material_type)
+                .with_context(|| format!("Line {}", line))?;
+            model.add_material(Material::new(
+                &row.material_id,
+                &row.material_name,
+                material_type,
+            ));
+        }
+
+        let geometry = Geometry::linestring(vec![[0.0, 0.0, row.top], [0.0, 0.0, row.base]])?;
+        model.add_component(
+            ModelComponent::new(
+                format!("LAYER{:03}", idx + 1),
+                row.material_name,
+                ComponentType::Layer,
+                row.material_id,
+                geometry,
+            )
+            .with_elevations(row.top, row.base),
+        );
+    }
+
+    Ok(model)
+}
+
+pub async fn execute(file: PathBuf, output: PathBuf) -> Result<()> {
+    println!("📥 Importing layers from: {}", file.display());
+
+    let reader = std::fs::File::open(&file)
+        .with_context(|| format!("Failed to open file: {}", file.display()))?;
+    let name = file
+        .file_stem()
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "Imported layers".to_string());
+    let model = build_model(reader, "MODEL001", &name)?;
+
+    println!("   Materials: {}", model.materials.len());
+    println!("   Layers: {}", model.components.len());
+
+    let mut doc = Document::new(&name);
+    doc.add_model(model);
+    doc.to_json_file(&output)
+        .with_context(|| format!("Failed to write to {}", output.display()))?;
+
+    println!("✅ Model written to: {}", output.display());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_model_dedupes_materials() {
+        let csv = "\
+material_id,material_name,material_type,top,base
+MG,Made ground,made ground,10.0,8.5
+CLAY,London Clay,Soil,8.5,2.0
+MG,Made ground,made ground,2.0,0.0
+";
+        let model = build_model(csv.as_bytes(), "MODEL001", "layers").unwrap();
+
+        assert_eq!(model.materials.len(), 2);
+        assert_eq!(
+            model.get_material("MG").unwrap().material_type,
+            MaterialType::MadeGround
+        );
+        assert_eq!(model.components.len(), 3);
+        assert_eq!(model.components[1].material_id, "CLAY");
+        assert_eq!(
+            (model.components[1].top, model.components[1].base),
+            (Some(8.5), Some(2.0))
+        );
+    }
+}
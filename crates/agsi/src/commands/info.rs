@@ -1,90 +1,162 @@
 use agsi_core::Document;
-use anyhow::{Context, Result};
+use anyhow::Result;
+use std::io::Write;
 use std::path::PathBuf;
 
-pub async fn execute(file: PathBuf, show_materials: bool, show_models: bool) -> Result<()> {
-    let doc = Document::from_json_file(&file)
-        .with_context(|| format!("Failed to load file: {}", file.display()))?;
+pub async fn execute(files: Vec<PathBuf>, show_materials: bool, show_models: bool) -> Result<()> {
+    write_report(
+        &mut std::io::stdout().lock(),
+        &files,
+        show_materials,
+        show_models,
+    )
+}
+
+/// Write a section per file, followed by totals when there are several
+fn write_report(
+    out: &mut impl Write,
+    files: &[PathBuf],
+    show_materials: bool,
+    show_models: bool,
+) -> Result<()> {
+    let (mut models, mut materials, mut components) = (0, 0, 0);
+    for (idx, file) in files.iter().enumerate() {
+        let doc = super::read_document(file)?;
+        if files.len() > 1 {
+            if idx > 0 {
+                writeln!(out)?;
+            }
+            writeln!(out, "📁 {}", file.display())?;
+        }
+        write_document(out, &doc, show_materials, show_models)?;
+
+        models += doc.agsi_model.len();
+        materials += doc
+            .agsi_model
+            .iter()
+            .map(|m| m.materials.len())
+            .sum::<usize>();
+        components += doc
+            .agsi_model
+            .iter()
+            .map(|m| m.components.len())
+            .sum::<usize>();
+    }
+
+    if files.len() > 1 {
+        writeln!(out, "\n📦 Total across {} files:", files.len())?;
+        writeln!(out, "   Models: {}", models)?;
+        writeln!(out, "   Materials: {}", materials)?;
+        writeln!(out, "   Components: {}", components)?;
+    }
+
+    Ok(())
+}
+
+fn write_document(
+    out: &mut impl Write,
+    doc: &Document,
+    show_materials: bool,
+    show_models: bool,
+) -> Result<()> {
+    writeln!(out, "📄 AGSi Document Information")?;
+    writeln!(out, "═══════════════════════════════════════")?;
 
-    println!("📄 AGSi Document Information");
-    println!("═══════════════════════════════════════");
-    
     // File info
-    println!("\n🗂️  File Information:");
-    println!("   ID: {}", doc.ags_file.file_id);
+    writeln!(out, "\n🗂️  File Information:")?;
+    writeln!(out, "   ID: {}", doc.ags_file.file_id)?;
     if let Some(ref name) = doc.ags_file.file_name {
-        println!("   Name: {}", name);
+        writeln!(out, "   Name: {}", name)?;
     }
     if let Some(ref author) = doc.ags_file.file_author {
-        println!("   Author: {}", author);
+        writeln!(out, "   Author: {}", author)?;
     }
     if let Some(ref software) = doc.ags_file.file_software {
-        println!("   Software: {}", software);
+        writeln!(out, "   Software: {}", software)?;
     }
-    println!("   Schema Version: {}", doc.ags_schema.version);
+    writeln!(out, "   Schema Version: {}", doc.ags_schema.version)?;
 
     // Project info
     if let Some(ref project) = doc.ags_project {
-        println!("\n🏗️  Project:");
-        println!("   ID: {}", project.id);
-        println!("   Name: {}", project.name);
+        writeln!(out, "\n🏗️  Project:")?;
+        writeln!(out, "   ID: {}", project.id)?;
+        writeln!(out, "   Name: {}", project.name)?;
         if let Some(ref desc) = project.description {
-            println!("   Description: {}", desc);
+            writeln!(out, "   Description: {}", desc)?;
         }
         if let Some(ref client) = project.client {
-            println!("   Client: {}", client);
+            writeln!(out, "   Client: {}", client)?;
         }
         if let Some(ref location) = project.location {
-            println!("   Location: {}", location.name);
+            writeln!(out, "   Location: {}", location.name)?;
         }
     }
 
     // Models summary
-    println!("\n🗺️  Models: {}", doc.agsi_model.len());
-    
+    writeln!(out, "\n🗺️  Models: {}", doc.agsi_model.len())?;
+
     let total_materials: usize = doc.agsi_model.iter().map(|m| m.materials.len()).sum();
     let total_components: usize = doc.agsi_model.iter().map(|m| m.components.len()).sum();
-    
-    println!("   Total Materials: {}", total_materials);
-    println!("   Total Components: {}", total_components);
+
+    writeln!(out, "   Total Materials: {}", total_materials)?;
+    writeln!(out, "   Total Components: {}", total_components)?;
 
     // Detailed models
-    if show_models || (!show_materials && !show_models) {
+    if show_models || !show_materials {
         for model in &doc.agsi_model {
-            println!("\n   • Model: {} ({})", model.name, model.id);
-            println!("     Type: {:?}", model.model_type);
-            println!("     Dimension: {:?}", model.dimension);
-            println!("     Materials: {}", model.materials.len());
-            println!("     Components: {}", model.components.len());
-            
+            writeln!(out, "\n   • Model: {} ({})", model.name, model.id)?;
+            writeln!(out, "     Type: {:?}", model.model_type)?;
+            writeln!(out, "     Dimension: {:?}", model.dimension)?;
+            writeln!(out, "     Materials: {}", model.materials.len())?;
+            writeln!(out, "     Components: {}", model.components.len())?;
+
             if let Some(ref crs) = model.crs {
-                println!("     CRS: {}", crs);
+                writeln!(out, "     CRS: {}", crs)?;
             }
-            
+
             if let Some(ref extent) = model.extent {
-                println!("     Extent: [{:.2}, {:.2}] x [{:.2}, {:.2}]",
-                    extent.min_x, extent.max_x, extent.min_y, extent.max_y);
+                writeln!(
+                    out,
+                    "     Extent: [{:.2}, {:.2}] x [{:.2}, {:.2}]",
+                    extent.min_x, extent.max_x, extent.min_y, extent.max_y
+                )?;
+            }
+
+            for component in &model.components {
+                writeln!(
+                    out,
+                    "       - {} ({}): {}",
+                    component.name, component.id, component.geometry
+                )?;
             }
         }
     }
 
     // Detailed materials
     if show_materials {
-        for (_model_idx, model) in doc.agsi_model.iter().enumerate() {
+        for model in &doc.agsi_model {
             if !model.materials.is_empty() {
-                println!("\n   Materials in model {} ({}):", model.name, model.id);
+                writeln!(
+                    out,
+                    "\n   Materials in model {} ({}):",
+                    model.name, model.id
+                )?;
                 for material in &model.materials {
-                    println!("     • {} ({}) - {:?}",
-                        material.name,
-                        material.id,
-                        material.material_type
-                    );
-                    
+                    writeln!(
+                        out,
+                        "     • {} ({}) - {:?}",
+                        material.name, material.id, material.material_type
+                    )?;
+
                     if !material.properties.is_empty() {
-                        println!("       Properties: {}", material.properties.len());
+                        writeln!(out, "       Properties: {}", material.properties.len())?;
                         for prop in &material.properties {
-                            let unit = prop.unit.as_ref().map(|u| format!(" {}", u)).unwrap_or_default();
-                            println!("         - {}: {:?}{}", prop.name, prop.value, unit);
+                            let unit = prop
+                                .unit
+                                .as_ref()
+                                .map(|u| format!(" {}", u))
+                                .unwrap_or_default();
+                            writeln!(out, "         - {}: {:?}{}", prop.name, prop.value, unit)?;
                         }
                     }
                 }
@@ -94,3 +166,33 @@ pub async fn execute(file: PathBuf, show_materials: bool, show_models: bool) ->
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_info_over_two_files() {
+        let dir = std::env::temp_dir();
+        let files: Vec<PathBuf> = ["DOC001", "DOC002"]
+            .iter()
+            .map(|id| {
+                let path = dir.join(format!("agsi-info-{}-{}.json", id, std::process::id()));
+                Document::new(*id).to_json_file(&path).unwrap();
+                path
+            })
+            .collect();
+
+        let mut out = Vec::new();
+        let result = write_report(&mut out, &files, false, false);
+        for file in &files {
+            std::fs::remove_file(file).ok();
+        }
+        result.unwrap();
+
+        let text = String::from_utf8(out).unwrap();
+        assert!(text.contains("ID: DOC001"));
+        assert!(text.contains("ID: DOC002"));
+        assert!(text.contains("Total across 2 files"));
+    }
+}
@@ -0,0 +1,108 @@
+use agsi_core::{validation, AgsiRoot, Document};
+use anyhow::{Context, Result};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+pub async fn execute(file: PathBuf) -> Result<()> {
+    println!("🩺 Linting AGSi file: {}", file.display());
+
+    let json = super::read_input(&file)?;
+
+    // Files in the AGSi schema layout get the schema lints, colour collisions included
+    let result = match Document::from_json_str(&json) {
+        Ok(doc) => validation::lint_document(&doc),
+        Err(err) => match serde_json::from_str::<AgsiRoot>(&json) {
+            Ok(root) => validation::lint_root(&root),
+            Err(_) => {
+                return Err(err).with_context(|| format!("Failed to load file: {}", file.display()))
+            }
+        },
+    }
+    .with_context(|| "Lint failed")?;
+
+    // Group findings by category, errors before warnings
+    let mut categories: BTreeMap<&str, Vec<String>> = BTreeMap::new();
+    for error in result.errors() {
+        categories
+            .entry(category(error.code.as_deref()))
+            .or_default()
+            .push(format!("❌ {}: {}", error.path, error.message));
+    }
+    for warning in result.warnings() {
+        categories
+            .entry(category(warning.code.as_deref()))
+            .or_default()
+            .push(format!("⚠️  {}: {}", warning.path, warning.message));
+    }
+
+    for (name, findings) in &categories {
+        println!("\n{} ({}):", name, findings.len());
+        for finding in findings {
+            println!("   {}", finding);
+        }
+    }
+
+    println!("\n📊 Summary:");
+    println!("   Errors: {}", result.errors().len());
+    println!("   Warnings: {}", result.warnings().len());
+
+    if result.is_valid() && result.warnings().is_empty() {
+        println!("\n✅ No issues found");
+    }
+
+    if !result.is_valid() {
+        std::process::exit(1);
+    }
+
+    Ok(())
+}
+
+/// Report heading for a finding code
+fn category(code: Option<&str>) -> &'static str {
+    match code.unwrap_or_default() {
+        c if c.starts_with("geometry-")
+            || c.ends_with("-geometry")
+            || c.ends_with("-geometry-mismatch")
+            || c.ends_with("-crs") =>
+        {
+            "Geometry"
+        }
+        c if c.starts_with("material-")
+            || c.ends_with("-material")
+            || c.ends_with("-parameter")
+            || c.starts_with("parameter-")
+            || c.starts_with("property-file-")
+            || c == "assumed-properties"
+            || c == "plausible-range" =>
+        {
+            "Materials"
+        }
+        "excessive-precision" => "Precision",
+        c if c.starts_with("duplicate-") || c == "extent-range" => "Structure",
+        c if c.starts_with("schema") || c == "unknown-schema-variant" => "Schema",
+        _ => "Other",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_every_emitted_code_has_a_category() {
+        let sources = [
+            include_str!("../../../agsi-core/src/validation.rs"),
+            include_str!("../../../agsi-core/src/validation_profile.rs"),
+        ];
+        let codes: Vec<&str> = sources
+            .iter()
+            .flat_map(|source| source.split("with_code(\"").skip(1))
+            .filter_map(|rest| rest.split('"').next())
+            .collect();
+        assert!(codes.len() > 20);
+
+        for code in codes {
+            assert_ne!(category(Some(code)), "Other", "{}", code);
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use agsi_core::merge::ContentCounts;
+use agsi_core::Document;
+use anyhow::{Context, Result};
+use std::path::PathBuf;
+
+pub async fn execute(files: Vec<PathBuf>, output: PathBuf) -> Result<()> {
+    println!("🔗 Merging {} files", files.len());
+
+    let mut docs = Vec::with_capacity(files.len());
+    for (idx, file) in files.iter().enumerate() {
+        let doc = super::read_document(file)?;
+        let counts = ContentCounts::of(&doc);
+        println!(
+            "   [{}/{}] {}: {} models, {} materials, {} components",
+            idx + 1,
+            files.len(),
+            file.display(),
+            counts.models,
+            counts.materials,
+            counts.components
+        );
+        docs.push(doc);
+    }
+
+    let (merged, report) = Document::combine(&docs)?;
+    merged
+        .to_json_file(&output)
+        .with_context(|| format!("Failed to write to {}", output.display()))?;
+
+    println!("\n📊 Summary:");
+    println!("   Models: {}", report.output.models);
+    println!("   Materials: {}", report.output.materials);
+    println!("   Components: {}", report.output.components);
+    if report.collisions.is_empty() {
+        println!("   Collisions: none");
+    } else {
+        println!(
+            "   Collisions: {} (earlier file kept)",
+            report.collisions.len()
+        );
+        for collision in &report.collisions {
+            println!(
+                "     • {} from {}",
+                collision.path,
+                files[collision.input].display()
+            );
+        }
+    }
+
+    println!("✅ Merged file written to: {}", output.display());
+    Ok(())
+}
@@ -0,0 +1,28 @@
+use agsi_core::{migrations, AGSI_VERSION};
+use anyhow::{Context, Result};
+use std::path::PathBuf;
+
+pub async fn execute(file: PathBuf, output: PathBuf) -> Result<()> {
+    println!("⬆️  Migrating: {} -> {}", file.display(), output.display());
+
+    let content = std::fs::read_to_string(&file)
+        .with_context(|| format!("Failed to read file: {}", file.display()))?;
+
+    let (doc, applied) = migrations::migrate_json_str(&content)
+        .with_context(|| format!("Failed to migrate file: {}", file.display()))?;
+
+    doc.to_json_file(&output)
+        .with_context(|| format!("Failed to write to {}", output.display()))?;
+
+    if applied.is_empty() {
+        println!("✅ Already at schema version {}", AGSI_VERSION);
+    } else {
+        println!("✅ Migrated to schema version {}", AGSI_VERSION);
+        for step in &applied {
+            println!("   • {}", step);
+        }
+    }
+    println!("   Output: {}", output.display());
+
+    Ok(())
+}
@@ -1,9 +1,135 @@
+pub mod cases;
+pub mod characteristic;
+pub mod check_geometry;
+pub mod completions;
 pub mod convert;
+pub mod coverage;
 pub mod create;
 pub mod diff;
 pub mod edit;
 pub mod extract;
+pub mod extract_material;
+pub mod fix;
+pub mod flatten;
 pub mod form;
+pub mod geojson;
+pub mod graph;
+pub mod import_csv;
 pub mod info;
+pub mod lint;
+pub mod merge;
+pub mod migrate;
+pub mod normalize;
+pub mod normalize_codes;
+pub mod patch;
+pub mod profile;
+pub mod prune;
+pub mod recolour;
+pub mod redact;
+pub mod repl;
 pub mod stats;
+pub mod template;
 pub mod validate;
+pub mod volumes;
+
+use agsi_core::Document;
+use anyhow::{Context, Result};
+use std::io::Read;
+use std::path::Path;
+
+/// Check whether a path argument is `-`, meaning stdin or stdout
+pub fn is_stdio(path: &Path) -> bool {
+    path.as_os_str() == "-"
+}
+
+/// Read the text of a file, or of stdin when the path is `-`
+pub fn read_input(path: &Path) -> Result<String> {
+    if is_stdio(path) {
+        let mut text = String::new();
+        std::io::stdin()
+            .read_to_string(&mut text)
+            .context("Failed to read from stdin")?;
+        Ok(text)
+    } else {
+        std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read file: {}", path.display()))
+    }
+}
+
+/// Read the text of a file without blocking the runtime, or of stdin when the path is `-`
+pub async fn read_input_async(path: &Path) -> Result<String> {
+    if is_stdio(path) {
+        return read_input(path);
+    }
+    tokio::fs::read_to_string(path)
+        .await
+        .with_context(|| format!("Failed to read file: {}", path.display()))
+}
+
+/// Load a document from a file, or from stdin when the path is `-`
+///
+/// Files with a `.yaml`/`.yml` extension are read as YAML; everything else,
+/// including stdin, is read as JSON.
+pub fn read_document(path: &Path) -> Result<Document> {
+    if is_stdio(path) {
+        return read_document_from(std::io::stdin().lock(), false);
+    }
+
+    let is_yaml = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|e| e.eq_ignore_ascii_case("yaml") || e.eq_ignore_ascii_case("yml"));
+    if is_yaml {
+        Document::from_yaml_file(path)
+    } else {
+        Document::from_json_file(path)
+    }
+    .with_context(|| format!("Failed to load file: {}", path.display()))
+}
+
+/// Load a document like [`read_document`] without blocking the runtime
+pub async fn read_document_async(path: &Path) -> Result<Document> {
+    let is_yaml = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|e| e.eq_ignore_ascii_case("yaml") || e.eq_ignore_ascii_case("yml"));
+    if is_stdio(path) || is_yaml {
+        let text = read_input_async(path).await?;
+        return read_document_from(text.as_bytes(), is_yaml);
+    }
+
+    Document::from_json_file_async(path)
+        .await
+        .with_context(|| format!("Failed to load file: {}", path.display()))
+}
+
+/// Load a document from any reader
+pub fn read_document_from(mut reader: impl Read, yaml: bool) -> Result<Document> {
+    let mut text = String::new();
+    reader
+        .read_to_string(&mut text)
+        .context("Failed to read input")?;
+    let doc = if yaml {
+        Document::from_yaml_str(&text)
+    } else {
+        Document::from_json_str(&text)
+    };
+    doc.context("Failed to parse document")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_document_from_reader() {
+        let json = Document::new("DOC001").to_json_string().unwrap();
+
+        let doc = read_document_from(json.as_bytes(), false).unwrap();
+        assert_eq!(doc.ags_file.file_id, "DOC001");
+
+        assert!(read_document_from("not json".as_bytes(), false).is_err());
+        assert!(is_stdio(Path::new("-")));
+        assert!(!is_stdio(Path::new("model.json")));
+    }
+}
@@ -0,0 +1,37 @@
+use agsi_core::Document;
+use anyhow::{Context, Result};
+use std::path::PathBuf;
+
+pub async fn execute(file: PathBuf, output: Option<PathBuf>, round: Option<u32>) -> Result<()> {
+    println!("🧹 Normalizing: {}", file.display());
+
+    let mut doc = Document::from_json_file(&file)
+        .with_context(|| format!("Failed to load file: {}", file.display()))?;
+
+    for model in &mut doc.agsi_model {
+        model.sort_components();
+    }
+
+    if let Some(places) = round {
+        let decimals = doc.precision_report().max_coordinate_decimals();
+        if decimals > places as usize {
+            doc.round_coordinates(places);
+            println!(
+                "🎯 Rounded coordinates from {} to {} decimal places",
+                decimals, places
+            );
+        } else {
+            println!(
+                "🎯 Coordinates already have at most {} decimal places",
+                decimals
+            );
+        }
+    }
+
+    let output = output.unwrap_or(file);
+    doc.to_json_file(&output)
+        .with_context(|| format!("Failed to write to {}", output.display()))?;
+
+    println!("✅ Normalized file written to: {}", output.display());
+    Ok(())
+}
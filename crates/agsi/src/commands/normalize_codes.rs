@@ -0,0 +1,84 @@
+use agsi_core::AgsiRoot;
+use anyhow::{Context, Result};
+use std::path::PathBuf;
+
+/// A parameter code changed to its canonical spelling
+#[derive(Debug, PartialEq)]
+struct CodeRewrite {
+    path: String,
+    from: String,
+    to: &'static str,
+}
+
+/// Rewrite aliased parameter codes throughout the file
+fn normalize_root(root: &mut AgsiRoot) -> Vec<CodeRewrite> {
+    let mut rewrites = Vec::new();
+    for (model_idx, model) in root.agsi_model.iter_mut().enumerate() {
+        for (elem_idx, element) in model.agsi_model_element.iter_mut().enumerate() {
+            for (idx, from, to) in element.normalize_codes() {
+                rewrites.push(CodeRewrite {
+                    path: format!(
+                        "agsiModel[{}].agsiModelElement[{}].agsiDataParameterValue[{}]",
+                        model_idx, elem_idx, idx
+                    ),
+                    from,
+                    to,
+                });
+            }
+        }
+    }
+    rewrites
+}
+
+pub async fn execute(file: PathBuf, output: PathBuf) -> Result<()> {
+    println!("🏷️  Normalizing parameter codes: {}", file.display());
+
+    let json = super::read_input(&file)?;
+    let mut root: AgsiRoot = serde_json::from_str(&json)
+        .with_context(|| format!("Failed to load file: {}", file.display()))?;
+
+    let rewrites = normalize_root(&mut root);
+    for rewrite in &rewrites {
+        println!("   • {}: {} → {}", rewrite.path, rewrite.from, rewrite.to);
+    }
+    println!("   Rewritten: {}", rewrites.len());
+
+    std::fs::write(&output, serde_json::to_string_pretty(&root)?)
+        .with_context(|| format!("Failed to write to {}", output.display()))?;
+    println!("✅ Normalized file written to: {}", output.display());
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use agsi_core::{AgsiDataParameterValue, AgsiModel, AgsiModelElement};
+
+    #[test]
+    fn test_normalize_rewrites_cu() {
+        let mut model = AgsiModel::new();
+        model.add_element(
+            AgsiModelElement::new()
+                .with_parameter(AgsiDataParameterValue::numeric("Cu", 75.0))
+                .with_parameter(AgsiDataParameterValue::text("ContaminationRisk", "Low")),
+        );
+        let mut root = AgsiRoot::new("Test");
+        root.add_model(model);
+
+        let rewrites = normalize_root(&mut root);
+        assert_eq!(
+            rewrites,
+            vec![CodeRewrite {
+                path: "agsiModel[0].agsiModelElement[0].agsiDataParameterValue[0]".to_string(),
+                from: "Cu".to_string(),
+                to: "UndrainedShearStrength",
+            }]
+        );
+
+        let parameters = &root.agsi_model[0].agsi_model_element[0].agsi_data_parameter_value;
+        assert_eq!(parameters[0].code_id, "UndrainedShearStrength");
+        assert_eq!(parameters[1].code_id, "ContaminationRisk");
+        assert!(normalize_root(&mut root).is_empty());
+    }
+}
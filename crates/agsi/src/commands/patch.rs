@@ -0,0 +1,121 @@
+use agsi_core::validation::{self, ValidationResult};
+use agsi_core::Document;
+use anyhow::{bail, Context, Result};
+use std::path::PathBuf;
+
+pub async fn execute(file: PathBuf, ops: PathBuf, output: PathBuf, force: bool) -> Result<()> {
+    println!("🩹 Patching: {}", file.display());
+
+    let doc = Document::from_json_file(&file)
+        .with_context(|| format!("Failed to load file: {}", file.display()))?;
+
+    let ops_json = std::fs::read_to_string(&ops)
+        .with_context(|| format!("Failed to read patch: {}", ops.display()))?;
+    let patch: json_patch::Patch = serde_json::from_str(&ops_json)
+        .with_context(|| format!("Invalid JSON Patch: {}", ops.display()))?;
+
+    let (patched, result) = apply_patch(&doc, &patch, force)?;
+    if !result.is_valid() {
+        println!("⚠️  Patched document is invalid, writing anyway (--force):");
+        for error in result.errors() {
+            println!("   ❌ {}: {}", error.path, error.message);
+        }
+    }
+
+    patched
+        .to_json_file(&output)
+        .with_context(|| format!("Failed to write to {}", output.display()))?;
+
+    println!(
+        "✅ Applied {} operations, written to: {}",
+        patch.0.len(),
+        output.display()
+    );
+    Ok(())
+}
+
+/// Apply an RFC 6902 patch and re-validate the result
+///
+/// Patches that leave the document invalid are rejected unless `force` is set.
+fn apply_patch(
+    doc: &Document,
+    patch: &json_patch::Patch,
+    force: bool,
+) -> Result<(Document, ValidationResult)> {
+    let mut value = serde_json::to_value(doc)?;
+    json_patch::patch(&mut value, patch).context("Failed to apply patch")?;
+
+    let patched: Document =
+        serde_json::from_value(value).context("Patched JSON is not a valid AGSi document")?;
+    let result = validation::validate_document(&patched).context("Validation failed")?;
+
+    if !result.is_valid() && !force {
+        let errors: Vec<_> = result
+            .errors()
+            .iter()
+            .map(|e| format!("{}: {}", e.path, e.message))
+            .collect();
+        bail!(
+            "Patch produces an invalid document (use --force to apply anyway):\n  {}",
+            errors.join("\n  ")
+        );
+    }
+
+    Ok((patched, result))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use agsi_core::geometry::Geometry;
+    use agsi_core::material::MaterialType;
+    use agsi_core::model::{ComponentType, ModelDimension, ModelType};
+    use agsi_core::{GroundModel, Material, ModelComponent};
+
+    fn sample() -> Document {
+        let mut doc = Document::new("DOC001");
+        let mut model = GroundModel::new(
+            "MODEL001",
+            "Test",
+            ModelType::Stratigraphic,
+            ModelDimension::OneD,
+        );
+        model.add_material(Material::new("MAT001", "Clay", MaterialType::Soil));
+        model.add_component(ModelComponent::new(
+            "COMP001",
+            "Layer 1",
+            ComponentType::Layer,
+            "MAT001",
+            Geometry::point(0.0, 0.0, 0.0),
+        ));
+        doc.add_model(model);
+        doc
+    }
+
+    fn ops(json: serde_json::Value) -> json_patch::Patch {
+        serde_json::from_value(json).unwrap()
+    }
+
+    #[test]
+    fn test_patch_renames_material() {
+        let patch = ops(serde_json::json!([
+            { "op": "replace", "path": "/agsiModel/0/materials/0/name", "value": "London Clay" }
+        ]));
+
+        let (patched, result) = apply_patch(&sample(), &patch, false).unwrap();
+        assert!(result.is_valid());
+        assert_eq!(patched.agsi_model[0].materials[0].name, "London Clay");
+    }
+
+    #[test]
+    fn test_patch_breaking_reference_is_rejected() {
+        let patch = ops(serde_json::json!([
+            { "op": "replace", "path": "/agsiModel/0/components/0/materialId", "value": "MAT999" }
+        ]));
+
+        assert!(apply_patch(&sample(), &patch, false).is_err());
+
+        let (_, result) = apply_patch(&sample(), &patch, true).unwrap();
+        assert!(!result.is_valid());
+    }
+}
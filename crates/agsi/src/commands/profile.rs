@@ -0,0 +1,41 @@
+use agsi_core::AgsiRoot;
+use anyhow::{Context, Result};
+use std::path::PathBuf;
+
+pub async fn execute(file: PathBuf, element: String, code: String) -> Result<()> {
+    let json = super::read_input(&file)?;
+    let root: AgsiRoot = serde_json::from_str(&json)
+        .with_context(|| format!("Failed to load file: {}", file.display()))?;
+
+    let found = root
+        .find_element(&element)
+        .with_context(|| format!("Element not found: {}", element))?;
+
+    let profiles: Vec<_> = found
+        .agsi_data_parameter_value
+        .iter()
+        .filter(|p| p.code_id == code)
+        .filter_map(|p| Some((p, p.profile_as_xy()?)))
+        .collect();
+    if profiles.is_empty() {
+        anyhow::bail!("No {} profile found on element {}", code, element);
+    }
+
+    for (parameter, (x, y)) in profiles {
+        let ind_var = parameter
+            .value_profile_ind_var_code_id
+            .as_deref()
+            .unwrap_or("x");
+        println!("📈 {} profile for {}", code, element);
+        if let Some(ref case_id) = parameter.case_id {
+            println!("   Case: {}", case_id);
+        }
+        println!("{:>12}  {:>12}", ind_var, code);
+        for (x, y) in x.iter().zip(&y) {
+            println!("{:>12}  {:>12}", x, y);
+        }
+        println!();
+    }
+
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use agsi_core::Document;
+use anyhow::{Context, Result};
+use std::path::PathBuf;
+
+pub async fn execute(file: PathBuf, output: Option<PathBuf>, dry_run: bool) -> Result<()> {
+    println!("✂️  Pruning: {}", file.display());
+
+    let mut doc = Document::from_json_file(&file)
+        .with_context(|| format!("Failed to load file: {}", file.display()))?;
+
+    let report = doc.prune();
+    if report.is_empty() {
+        println!("   Nothing to remove");
+    }
+    for (model_id, material_id) in &report.materials {
+        println!("   • Unused material {} in {}", material_id, model_id);
+    }
+    for model_id in &report.models {
+        println!("   • Empty model {}", model_id);
+    }
+
+    if dry_run {
+        println!("🔍 Dry run: no file written");
+        return Ok(());
+    }
+
+    let output = output.context("An output path is required unless --dry-run is given")?;
+    doc.to_json_file(&output)
+        .with_context(|| format!("Failed to write to {}", output.display()))?;
+
+    println!("✅ Pruned file written to: {}", output.display());
+    Ok(())
+}
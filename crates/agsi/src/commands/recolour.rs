@@ -0,0 +1,48 @@
+use agsi_core::AgsiRoot;
+use anyhow::{Context, Result};
+use std::path::PathBuf;
+
+/// Colours used when no palette is given, distinct on maps and sections
+const DEFAULT_PALETTE: &[&str] = &[
+    "#E6194B", "#3CB44B", "#FFE119", "#4363D8", "#F58231", "#911EB4", "#46F0F0", "#F032E6",
+    "#BCF60C", "#FABEBE", "#008080", "#E6BEFF", "#9A6324", "#FFFAC8", "#800000", "#AAFFC3",
+];
+
+pub async fn execute(
+    file: PathBuf,
+    output: PathBuf,
+    palette: Vec<String>,
+    overwrite: bool,
+) -> Result<()> {
+    println!("🎨 Assigning element colours: {}", file.display());
+
+    let json = super::read_input(&file)?;
+    let mut root: AgsiRoot = serde_json::from_str(&json)
+        .with_context(|| format!("Failed to load file: {}", file.display()))?;
+
+    let palette: Vec<&str> = if palette.is_empty() {
+        DEFAULT_PALETTE.to_vec()
+    } else {
+        palette.iter().map(String::as_str).collect()
+    };
+
+    for model in &mut root.agsi_model {
+        let exhausted = if overwrite {
+            model.reassign_colours(&palette)
+        } else {
+            model.auto_assign_colours(&palette)
+        };
+        if exhausted {
+            println!(
+                "   ⚠️  {}: palette ran out, some colours are reused",
+                model.model_name.as_deref().unwrap_or("<unnamed model>")
+            );
+        }
+    }
+
+    std::fs::write(&output, serde_json::to_string_pretty(&root)?)
+        .with_context(|| format!("Failed to write to {}", output.display()))?;
+    println!("✅ Recoloured file written to: {}", output.display());
+
+    Ok(())
+}
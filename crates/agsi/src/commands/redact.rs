@@ -0,0 +1,26 @@
+use agsi_core::{Document, RedactOptions};
+use anyhow::{Context, Result};
+use std::path::PathBuf;
+
+pub async fn execute(
+    file: PathBuf,
+    output: PathBuf,
+    keep_project: bool,
+    keep_attachments: bool,
+) -> Result<()> {
+    println!("🕶️  Redacting: {}", file.display());
+
+    let mut doc = Document::from_json_file(&file)
+        .with_context(|| format!("Failed to load file: {}", file.display()))?;
+
+    doc.redact(RedactOptions {
+        keep_project,
+        keep_attachments,
+    });
+
+    doc.to_json_file(&output)
+        .with_context(|| format!("Failed to write to {}", output.display()))?;
+
+    println!("✅ Redacted file written to: {}", output.display());
+    Ok(())
+}
@@ -0,0 +1,189 @@
+use agsi_core::Document;
+use anyhow::{bail, Context, Result};
+use rustyline::error::ReadlineError;
+use std::path::PathBuf;
+
+const HELP: &str = "\
+Commands:
+  info                 Summarise the document
+  materials            List materials by model
+  get <path>           Show a value, e.g. get MAT001.name
+  set <path> <value>   Change a value, e.g. set MAT001.name \"Stiff clay\"
+  save                 Write the document back to its file
+  help                 Show this help
+  quit                 Leave the REPL";
+
+/// What the REPL should do after a command
+#[derive(Debug, PartialEq)]
+enum Outcome {
+    Continue(String),
+    Quit,
+}
+
+/// A document held in memory between REPL commands
+struct Session {
+    doc: Document,
+    path: PathBuf,
+    /// Set when the document has been edited since it was loaded or saved
+    dirty: bool,
+    /// Set when there are unsaved changes and the next quit goes ahead anyway
+    confirm_quit: bool,
+}
+
+impl Session {
+    fn new(doc: Document, path: PathBuf) -> Self {
+        Self {
+            doc,
+            path,
+            dirty: false,
+            confirm_quit: false,
+        }
+    }
+
+    /// Run one command line and return the text to print
+    fn dispatch(&mut self, line: &str) -> Result<Outcome> {
+        let line = line.trim();
+        let (command, rest) = line
+            .split_once(char::is_whitespace)
+            .map_or((line, ""), |(command, rest)| (command, rest.trim()));
+
+        let confirming_quit = std::mem::take(&mut self.confirm_quit);
+
+        let output = match command {
+            "" => String::new(),
+            "help" => HELP.to_string(),
+            "info" => {
+                let materials: usize = self.doc.agsi_model.iter().map(|m| m.materials.len()).sum();
+                let components: usize =
+                    self.doc.agsi_model.iter().map(|m| m.components.len()).sum();
+                format!(
+                    "{}: {} models, {} materials, {} components",
+                    self.doc.ags_file.file_id,
+                    self.doc.agsi_model.len(),
+                    materials,
+                    components
+                )
+            }
+            "materials" => self
+                .doc
+                .agsi_model
+                .iter()
+                .flat_map(|model| {
+                    model.materials.iter().map(move |material| {
+                        format!(
+                            "{}  {} ({:?}) in {}",
+                            material.id, material.name, material.material_type, model.id
+                        )
+                    })
+                })
+                .collect::<Vec<_>>()
+                .join("\n"),
+            "get" => {
+                let value = self
+                    .doc
+                    .get_by_path(rest)
+                    .with_context(|| format!("Path not found: {}", rest))?;
+                serde_json::to_string_pretty(&value)?
+            }
+            "set" => {
+                let (path, raw) = rest
+                    .split_once(char::is_whitespace)
+                    .context("Usage: set <path> <value>")?;
+                // Values are read as JSON, falling back to a bare string
+                let raw = raw.trim();
+                let value = serde_json::from_str(raw)
+                    .unwrap_or_else(|_| serde_json::Value::String(raw.to_string()));
+                self.doc
+                    .set_by_path(path, value)
+                    .with_context(|| format!("Failed to set {}", path))?;
+                self.dirty = true;
+                format!("✅ {} updated", path)
+            }
+            "save" => {
+                self.doc
+                    .to_json_file(&self.path)
+                    .with_context(|| format!("Failed to write to {}", self.path.display()))?;
+                self.dirty = false;
+                format!("💾 Saved to {}", self.path.display())
+            }
+            "quit" | "exit" if self.dirty && !confirming_quit => {
+                self.confirm_quit = true;
+                "⚠️ Unsaved changes: quit again to discard them, or save first".to_string()
+            }
+            "quit" | "exit" => return Ok(Outcome::Quit),
+            other => bail!("Unknown command: {} (try help)", other),
+        };
+        Ok(Outcome::Continue(output))
+    }
+}
+
+pub async fn execute(file: PathBuf) -> Result<()> {
+    let doc = Document::from_json_file(&file)
+        .with_context(|| format!("Failed to load file: {}", file.display()))?;
+    println!("🐚 Loaded {} (type help for commands)", file.display());
+
+    let mut session = Session::new(doc, file);
+    let mut editor = rustyline::DefaultEditor::new()?;
+    loop {
+        let line = match editor.readline("agsi> ") {
+            Ok(line) => {
+                editor.add_history_entry(line.as_str())?;
+                line
+            }
+            // Ctrl-C and Ctrl-D quit, with the same unsaved-changes check
+            Err(ReadlineError::Interrupted | ReadlineError::Eof) => "quit".to_string(),
+            Err(e) => return Err(e.into()),
+        };
+
+        match session.dispatch(&line) {
+            Ok(Outcome::Continue(output)) if !output.is_empty() => println!("{}", output),
+            Ok(Outcome::Continue(_)) => {}
+            Ok(Outcome::Quit) => break,
+            Err(e) => eprintln!("❌ {:#}", e),
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use agsi_core::material::{Material, MaterialType};
+    use agsi_core::model::{GroundModel, ModelDimension, ModelType};
+
+    #[test]
+    fn test_dispatch_set_get_and_save() {
+        let mut doc = Document::new("DOC001");
+        let mut model = GroundModel::new(
+            "MODEL001",
+            "Model",
+            ModelType::Stratigraphic,
+            ModelDimension::TwoD,
+        );
+        model.add_material(Material::new("MAT001", "Clay", MaterialType::Soil));
+        doc.add_model(model);
+
+        let path = std::env::temp_dir().join(format!("agsi-repl-{}.json", std::process::id()));
+        let mut session = Session::new(doc, path.clone());
+
+        let set = session.dispatch(r#"set MAT001.name "Stiff clay""#).unwrap();
+        assert_eq!(set, Outcome::Continue("✅ MAT001.name updated".to_string()));
+        assert_eq!(
+            session.dispatch("get MAT001.name").unwrap(),
+            Outcome::Continue("\"Stiff clay\"".to_string())
+        );
+        assert!(session.dispatch("get MAT999.name").is_err());
+        assert!(session.dispatch("frobnicate").is_err());
+        assert!(session.dirty);
+        assert_ne!(session.dispatch("quit").unwrap(), Outcome::Quit);
+
+        session.dispatch("save").unwrap();
+        assert!(!session.dirty);
+        let saved = Document::from_json_file(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+        assert_eq!(saved.agsi_model[0].materials[0].name, "Stiff clay");
+
+        assert_eq!(session.dispatch("quit").unwrap(), Outcome::Quit);
+    }
+}
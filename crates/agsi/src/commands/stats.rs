@@ -1,14 +1,44 @@
 use agsi_core::Document;
-use anyhow::{Context, Result};
-use std::path::PathBuf;
+use anyhow::Result;
+use std::path::{Path, PathBuf};
 
-pub async fn execute(file: PathBuf) -> Result<()> {
+pub async fn execute(files: Vec<PathBuf>) -> Result<()> {
+    let (mut models, mut materials, mut components) = (0, 0, 0);
+    for (idx, file) in files.iter().enumerate() {
+        if idx > 0 {
+            println!();
+        }
+        let doc = super::read_document(file)?;
+        print_stats(file, &doc)?;
+
+        models += doc.agsi_model.len();
+        materials += doc
+            .agsi_model
+            .iter()
+            .map(|m| m.materials.len())
+            .sum::<usize>();
+        components += doc
+            .agsi_model
+            .iter()
+            .map(|m| m.components.len())
+            .sum::<usize>();
+    }
+
+    if files.len() > 1 {
+        println!();
+        println!("📦 Total across {} files:", files.len());
+        println!("   Models: {}", models);
+        println!("   Materials: {}", materials);
+        println!("   Components: {}", components);
+    }
+
+    Ok(())
+}
+
+fn print_stats(file: &Path, doc: &Document) -> Result<()> {
     println!("📊 Statistics for: {}", file.display());
     println!();
 
-    let doc = Document::from_json_file(&file)
-        .with_context(|| format!("Failed to load file: {}", file.display()))?;
-
     // Overall stats
     println!("📄 Document:");
     println!("   ID: {}", doc.ags_file.file_id);
@@ -22,13 +52,18 @@ pub async fn execute(file: PathBuf) -> Result<()> {
     println!("🗺️  Models: {}", doc.agsi_model.len());
     for model in &doc.agsi_model {
         println!("   • {} ({})", model.name, model.id);
-        println!("     Type: {:?}, Dimension: {:?}", model.model_type, model.dimension);
+        println!(
+            "     Type: {:?}, Dimension: {:?}",
+            model.model_type, model.dimension
+        );
         println!("     Materials: {}", model.materials.len());
         println!("     Components: {}", model.components.len());
-        
+
         if let Some(ref extent) = model.extent {
-            println!("     Extent: [{:.2}, {:.2}] x [{:.2}, {:.2}]",
-                extent.min_x, extent.max_x, extent.min_y, extent.max_y);
+            println!(
+                "     Extent: [{:.2}, {:.2}] x [{:.2}, {:.2}]",
+                extent.min_x, extent.max_x, extent.min_y, extent.max_y
+            );
         }
     }
     println!();
@@ -36,26 +71,28 @@ pub async fn execute(file: PathBuf) -> Result<()> {
     // Material statistics
     let total_materials: usize = doc.agsi_model.iter().map(|m| m.materials.len()).sum();
     println!("🪨 Materials: {}", total_materials);
-    
+
     if total_materials > 0 {
         let mut material_types = std::collections::HashMap::new();
         let mut property_counts = Vec::new();
-        
+
         for model in &doc.agsi_model {
             for material in &model.materials {
-                *material_types.entry(format!("{:?}", material.material_type))
+                *material_types
+                    .entry(format!("{:?}", material.material_type))
                     .or_insert(0) += 1;
                 property_counts.push(material.properties.len());
             }
         }
-        
+
         println!("   Types:");
         for (mat_type, count) in material_types {
             println!("     • {}: {}", mat_type, count);
         }
-        
+
         if !property_counts.is_empty() {
-            let avg_props: f64 = property_counts.iter().sum::<usize>() as f64 / property_counts.len() as f64;
+            let avg_props: f64 =
+                property_counts.iter().sum::<usize>() as f64 / property_counts.len() as f64;
             let min_props = property_counts.iter().min().unwrap();
             let max_props = property_counts.iter().max().unwrap();
             println!("   Properties per material:");
@@ -69,21 +106,38 @@ pub async fn execute(file: PathBuf) -> Result<()> {
     // Component statistics
     let total_components: usize = doc.agsi_model.iter().map(|m| m.components.len()).sum();
     println!("🔧 Components: {}", total_components);
-    
+
     if total_components > 0 {
         let mut component_types = std::collections::HashMap::new();
-        
+
         for model in &doc.agsi_model {
             for component in &model.components {
-                *component_types.entry(format!("{:?}", component.component_type))
+                *component_types
+                    .entry(format!("{:?}", component.component_type))
                     .or_insert(0) += 1;
             }
         }
-        
+
         println!("   Types:");
         for (comp_type, count) in component_types {
             println!("     • {}: {}", comp_type, count);
         }
+
+        println!("   Geometry:");
+        for (geom_type, count) in doc.geometry_type_histogram() {
+            println!("     • {}: {}", geom_type, count);
+        }
+    }
+    println!();
+
+    // Precision statistics
+    let report = doc.precision_report();
+    println!("🎯 Precision (max decimal places):");
+    for model in &report.models {
+        println!(
+            "   • {}: coordinates {}, parameters {}",
+            model.model_id, model.coordinate_decimals, model.parameter_decimals
+        );
     }
 
     Ok(())
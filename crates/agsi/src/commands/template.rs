@@ -0,0 +1,22 @@
+use agsi_core::Document;
+use anyhow::{Context, Result};
+use std::path::PathBuf;
+
+pub async fn execute(with_example_materials: bool, output: PathBuf) -> Result<()> {
+    println!("📝 Generating AGSi template");
+
+    let doc = Document::template(with_example_materials).with_file_name(
+        output
+            .file_name()
+            .unwrap_or_default()
+            .to_string_lossy()
+            .to_string(),
+    );
+
+    doc.to_json_file(&output)
+        .with_context(|| format!("Failed to write to {}", output.display()))?;
+
+    println!("✅ Template written to: {}", output.display());
+    println!("   See the \"remarks\" entries for what to fill in");
+    Ok(())
+}
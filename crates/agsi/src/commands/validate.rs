@@ -1,27 +1,87 @@
+use agsi_core::validation_profile::{ValidationProfile, PROFILE_FILE_NAME};
 use agsi_core::{validation, Document};
 use anyhow::{Context, Result};
 use std::path::PathBuf;
 
-pub async fn execute(file: PathBuf, detailed: bool) -> Result<()> {
-    println!("🔍 Validating AGSi file: {}", file.display());
+use crate::ValidateFormat;
+
+pub async fn execute(
+    file: PathBuf,
+    detailed: bool,
+    profile: Option<PathBuf>,
+    format: ValidateFormat,
+    plain: bool,
+    lenient: bool,
+) -> Result<()> {
+    let text = format == ValidateFormat::Text;
+    let icon = |emoji: &'static str, ascii: &'static str| if plain { ascii } else { emoji };
+
+    if text {
+        println!(
+            "{} Validating AGSi file: {}",
+            icon("🔍", "*"),
+            file.display()
+        );
+    }
 
     // Load document
-    let doc = Document::from_json_file(&file)
-        .with_context(|| format!("Failed to load file: {}", file.display()))?;
+    let json = super::read_input_async(&file).await?;
+    let doc = if lenient {
+        Document::from_json_str_coerce_numbers(&json)
+    } else {
+        Document::from_json_str(&json)
+    }
+    .with_context(|| format!("Failed to load file: {}", file.display()))?;
 
     // Validate
-    let result = validation::validate_document(&doc)
-        .with_context(|| "Validation failed")?;
+    let mut result = validation::validate_document(&doc).with_context(|| "Validation failed")?;
+
+    // Apply validation profile
+    let profile_path = profile.or_else(|| {
+        let default = PathBuf::from(PROFILE_FILE_NAME);
+        default.exists().then_some(default)
+    });
+    if let Some(path) = profile_path {
+        let profile = ValidationProfile::from_file(&path)
+            .with_context(|| format!("Failed to load profile: {}", path.display()))?;
+        if text {
+            println!("   Profile: {}", path.display());
+        }
+        profile.apply(&doc, &mut result);
+    }
+
+    if format == ValidateFormat::Sarif {
+        let sarif = result.to_sarif(&file.to_string_lossy());
+        println!("{}", serde_json::to_string_pretty(&sarif)?);
+        if !result.is_valid() {
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
 
     // Display results
     if result.is_valid() {
-        println!("✅ Document is valid!");
+        println!("{} Document is valid!", icon("✅", "[OK]"));
     } else {
-        println!("❌ Document has validation errors");
+        println!("{} Document has validation errors", icon("❌", "[FAIL]"));
     }
 
     if detailed || !result.errors().is_empty() || !result.warnings().is_empty() {
-        println!("\n{}", result.to_string());
+        if plain {
+            println!("\n{:#}", result);
+        } else {
+            println!("\n{}", result);
+        }
+    }
+
+    if detailed {
+        let unknown = Document::unknown_fields(&json)?;
+        if !unknown.is_empty() {
+            println!("\n{} Fields not in the schema:", icon("🔎", "*"));
+            for field in unknown {
+                println!("   - {}", field);
+            }
+        }
     }
 
     // Summary
@@ -29,7 +89,7 @@ pub async fn execute(file: PathBuf, detailed: bool) -> Result<()> {
     let material_count: usize = doc.agsi_model.iter().map(|m| m.materials.len()).sum();
     let component_count: usize = doc.agsi_model.iter().map(|m| m.components.len()).sum();
 
-    println!("\n📊 Summary:");
+    println!("\n{} Summary:", icon("📊", "*"));
     println!("   Models: {}", model_count);
     println!("   Materials: {}", material_count);
     println!("   Components: {}", component_count);
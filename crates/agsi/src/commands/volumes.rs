@@ -0,0 +1,27 @@
+use anyhow::{Context, Result};
+use std::path::PathBuf;
+
+pub async fn execute(file: PathBuf, model_id: String) -> Result<()> {
+    println!("📦 Material volumes in model {}", model_id);
+
+    let doc = super::read_document(&file)?;
+    let model = doc
+        .get_model(&model_id)
+        .with_context(|| format!("Model not found: {}", model_id))?;
+
+    let mut volumes: Vec<_> = model.material_volumes().into_iter().collect();
+    volumes.sort_by(|a, b| a.0.cmp(&b.0));
+
+    println!("{:<16}  {:<24}  {:>14}", "Material", "Name", "Volume");
+    for (material_id, volume) in &volumes {
+        let name = model
+            .get_material(material_id)
+            .map_or("", |m| m.name.as_str());
+        println!("{:<16}  {:<24}  {:>14.2}", material_id, name, volume);
+    }
+
+    let total: f64 = volumes.iter().map(|(_, v)| v).sum();
+    println!("{:<16}  {:<24}  {:>14.2}", "Total", "", total);
+
+    Ok(())
+}
@@ -3,8 +3,8 @@ use clap::{Parser, Subcommand};
 use std::path::PathBuf;
 
 mod commands;
-mod mcp;
 mod lsp;
+mod mcp;
 mod ui;
 
 #[derive(Parser)]
@@ -18,18 +18,70 @@ struct Cli {
     /// Enable verbose output
     #[arg(short, long, global = true)]
     verbose: bool,
+
+    /// Format of log output
+    #[arg(long, global = true, value_enum, default_value = "text")]
+    log_format: LogFormat,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, clap::ValueEnum)]
+enum LogFormat {
+    /// Human-readable lines
+    Text,
+    /// One JSON object per event, for log aggregators
+    Json,
 }
 
 #[derive(Subcommand)]
 enum Commands {
     /// Validate an AGSi file
     Validate {
-        /// Path to the AGSi file
+        /// Path to the AGSi file (`-` reads from stdin)
         file: PathBuf,
-        
+
         /// Show detailed validation report
         #[arg(short, long)]
         detailed: bool,
+
+        /// Validation profile (defaults to .agsi-validate.toml if present)
+        #[arg(long)]
+        profile: Option<PathBuf>,
+
+        /// Output format
+        #[arg(long, value_enum, default_value = "text")]
+        format: ValidateFormat,
+
+        /// Disable emoji and colour in text output (also set by NO_COLOR)
+        #[arg(long)]
+        no_color: bool,
+
+        /// Accept numbers written as strings (e.g. "35.0")
+        #[arg(long)]
+        lenient: bool,
+    },
+
+    /// Compute a characteristic value for a parameter across a model's materials
+    Characteristic {
+        /// Path to the AGSi file
+        file: PathBuf,
+
+        /// Model ID to read values from
+        #[arg(short, long)]
+        model: String,
+
+        /// Parameter code (e.g. AngleFriction)
+        #[arg(short, long)]
+        code: String,
+
+        /// Method used to derive the characteristic value
+        #[arg(long, value_enum, default_value = "mean-minus-std")]
+        method: CharacteristicMethodArg,
+    },
+
+    /// Check component geometry only (rings, winding, coordinates, CRS, extent)
+    CheckGeometry {
+        /// Path to the AGSi file
+        file: PathBuf,
     },
 
     /// Create a new AGSi document or component
@@ -38,6 +90,17 @@ enum Commands {
         item: CreateItem,
     },
 
+    /// Generate a skeleton AGSi file to fill in
+    Template {
+        /// Include a few example soil and rock materials
+        #[arg(long)]
+        with_example_materials: bool,
+
+        /// Output file path
+        #[arg(short, long, default_value = "template.json")]
+        output: PathBuf,
+    },
+
     /// Edit an existing AGSi file
     Edit {
         /// Path to the AGSi file
@@ -62,13 +125,130 @@ enum Commands {
         /// Output file path
         #[arg(short, long)]
         output: Option<PathBuf>,
+
+        /// Include the geometries of components referencing each material
+        #[arg(long)]
+        include_geometry: bool,
+
+        /// Wrap output with the source model ID, name and material count
+        #[arg(long)]
+        wrap: bool,
     },
 
-    /// Display information about an AGSi file
-    Info {
+    /// Extract a single material as a standalone document
+    ExtractMaterial {
+        /// Path to the AGSi file
+        file: PathBuf,
+
+        /// Material ID, searched for across all models
+        #[arg(long)]
+        id: String,
+
+        /// Output file path
+        #[arg(short, long)]
+        output: PathBuf,
+    },
+
+    /// Produce a 1D column of materials at a location in a model
+    Flatten {
+        /// Path to the AGSi file
+        file: PathBuf,
+
+        /// Model ID to sample
+        #[arg(short, long)]
+        model: String,
+
+        /// X coordinate of the column
+        #[arg(long, allow_negative_numbers = true)]
+        x: f64,
+
+        /// Y coordinate of the column
+        #[arg(long, allow_negative_numbers = true)]
+        y: f64,
+
+        /// Output file path
+        #[arg(short, long)]
+        output: PathBuf,
+    },
+
+    /// Export components from every model as one GeoJSON FeatureCollection
+    Geojson {
+        /// Path to the AGSi file
+        file: PathBuf,
+
+        /// Output GeoJSON file path
+        #[arg(short, long)]
+        output: PathBuf,
+    },
+
+    /// Write a Graphviz DOT graph of model, component and material references
+    Graph {
+        /// Path to the AGSi file (`-` reads from stdin)
+        file: PathBuf,
+
+        /// Output DOT file path
+        #[arg(short, long)]
+        output: PathBuf,
+    },
+
+    /// Run validation plus soft lints and print a model health report
+    ///
+    /// Files in the AGSi schema layout are checked against the schema and
+    /// for elements sharing a display colour.
+    Lint {
+        /// Path to the AGSi file (`-` reads from stdin)
+        file: PathBuf,
+    },
+
+    /// Validate, apply safe automatic fixes and re-validate
+    Fix {
         /// Path to the AGSi file
         file: PathBuf,
 
+        /// Output file path
+        #[arg(short, long)]
+        output: PathBuf,
+
+        /// Write a JSON report of the fixes and error counts
+        #[arg(long)]
+        report: Option<PathBuf>,
+    },
+
+    /// Report which optional AGSi fields are populated across a file
+    Coverage {
+        /// Path to the AGSi file (`-` reads from stdin)
+        file: PathBuf,
+    },
+
+    /// Combine several AGSi files into one, reporting where content came from
+    Merge {
+        /// Paths to the AGSi files, earliest taking precedence (`-` reads from stdin)
+        #[arg(required = true)]
+        files: Vec<PathBuf>,
+
+        /// Output file path
+        #[arg(short, long)]
+        output: PathBuf,
+    },
+
+    /// Build a 1D model from a CSV table of layers
+    ///
+    /// Columns: material_id, material_name, material_type, top, base
+    ImportCsv {
+        /// Path to the CSV file
+        file: PathBuf,
+
+        /// Output file path
+        #[arg(short, long)]
+        output: PathBuf,
+    },
+
+    /// Display information about one or more AGSi files
+    Info {
+        /// Paths to the AGSi files (`-` reads from stdin)
+        #[arg(required = true)]
+        files: Vec<PathBuf>,
+
         /// Show materials
         #[arg(long)]
         materials: bool,
@@ -80,16 +260,20 @@ enum Commands {
 
     /// Convert AGSi between formats
     Convert {
-        /// Input file path
+        /// Input file path (JSON, or YAML with a .yaml/.yml extension; `-` reads JSON from stdin)
         input: PathBuf,
 
-        /// Output file path
+        /// Output file path (`-` writes to stdout)
         #[arg(short, long)]
         output: PathBuf,
 
-        /// Output format (json, avro, protobuf)
+        /// Output format (json, yaml, avro, protobuf)
         #[arg(short, long, default_value = "json")]
         format: String,
+
+        /// Accept numbers written as strings (e.g. "35.0") in JSON input
+        #[arg(long)]
+        lenient: bool,
     },
 
     /// Interactive form-based creation
@@ -114,12 +298,159 @@ enum Commands {
         /// Show detailed differences
         #[arg(short, long)]
         detailed: bool,
+
+        /// Print the differences as JSON instead of text
+        #[arg(long)]
+        json: bool,
     },
 
-    /// Show statistics about an AGSi file
-    Stats {
+    /// Upgrade a file from an older AGSi schema version
+    Migrate {
+        /// Path to the AGSi file
+        file: PathBuf,
+
+        /// Output file path
+        #[arg(short, long)]
+        output: PathBuf,
+    },
+
+    /// Rewrite a file in a stable order for clean diffs
+    Normalize {
+        /// Path to the AGSi file
+        file: PathBuf,
+
+        /// Output file path (defaults to overwriting the input)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+
+        /// Round coordinates and elevations carrying more than this many decimal places
+        #[arg(long)]
+        round: Option<u32>,
+    },
+
+    /// Rewrite aliased parameter codes to their canonical spelling
+    NormalizeCodes {
+        /// Path to the AGSi file (`-` reads from stdin)
+        file: PathBuf,
+
+        /// Output file path
+        #[arg(short, long)]
+        output: PathBuf,
+    },
+
+    /// Apply an RFC 6902 JSON Patch and re-validate the result
+    Patch {
+        /// Path to the AGSi file
+        file: PathBuf,
+
+        /// JSON Patch file containing the operations
+        #[arg(long)]
+        ops: PathBuf,
+
+        /// Output file path
+        #[arg(short, long)]
+        output: PathBuf,
+
+        /// Write the result even if it fails validation
+        #[arg(long)]
+        force: bool,
+    },
+
+    /// Give model elements distinct display colours from a palette
+    Recolour {
+        /// Path to the AGSi file (`-` reads from stdin)
+        file: PathBuf,
+
+        /// Output file path
+        #[arg(short, long)]
+        output: PathBuf,
+
+        /// Comma-separated colours to assign (defaults to a built-in palette)
+        #[arg(long, value_delimiter = ',')]
+        palette: Vec<String>,
+
+        /// Replace colours elements already have instead of skipping them
+        #[arg(long)]
+        overwrite: bool,
+    },
+
+    /// Remove identifying metadata to produce a shareable copy
+    Redact {
         /// Path to the AGSi file
         file: PathBuf,
+
+        /// Output file path
+        #[arg(short, long)]
+        output: PathBuf,
+
+        /// Keep the project block (client, contractor and coordinates are still removed)
+        #[arg(long)]
+        keep_project: bool,
+
+        /// Keep attachments such as embedded factual reports
+        #[arg(long)]
+        keep_attachments: bool,
+    },
+
+    /// Remove unused materials and empty models
+    Prune {
+        /// Path to the AGSi file
+        file: PathBuf,
+
+        /// Output file path
+        #[arg(short, long, required_unless_present = "dry_run")]
+        output: Option<PathBuf>,
+
+        /// Report what would be removed without writing a file
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Print a parameter's value profile as an XY table
+    Profile {
+        /// Path to the AGSi file (`-` reads from stdin)
+        file: PathBuf,
+
+        /// Model element ID
+        #[arg(short, long)]
+        element: String,
+
+        /// Parameter code (e.g. UndrainedShearStrength)
+        #[arg(short, long)]
+        code: String,
+    },
+
+    /// Tabulate a parameter's value under each case across elements
+    Cases {
+        /// Path to the AGSi file (`-` reads from stdin)
+        file: PathBuf,
+
+        /// Parameter code (e.g. AngleFriction)
+        #[arg(short, long)]
+        code: String,
+    },
+
+    /// Open an interactive prompt for inspecting and editing a file
+    Repl {
+        /// Path to the AGSi file
+        file: PathBuf,
+    },
+
+    /// Show statistics about one or more AGSi files
+    Stats {
+        /// Paths to the AGSi files (`-` reads from stdin)
+        #[arg(required = true)]
+        files: Vec<PathBuf>,
+    },
+
+    /// Estimate the volume of each material in a model
+    Volumes {
+        /// Path to the AGSi file (`-` reads from stdin)
+        file: PathBuf,
+
+        /// Model ID to compute volumes for
+        #[arg(short, long)]
+        model: String,
     },
 
     /// Run MCP (Model Context Protocol) server
@@ -135,6 +466,14 @@ enum Commands {
         #[arg(short, long)]
         port: Option<u16>,
     },
+
+    /// Generate shell completion scripts
+    #[command(hide = true)]
+    Completions {
+        /// Shell to generate completions for
+        #[arg(value_enum)]
+        shell: clap_complete::Shell,
+    },
 }
 
 #[derive(Subcommand)]
@@ -181,6 +520,29 @@ enum CreateItem {
     },
 }
 
+#[derive(Clone, Copy, PartialEq, clap::ValueEnum)]
+enum ValidateFormat {
+    Text,
+    Sarif,
+}
+
+#[derive(Clone, Copy, clap::ValueEnum)]
+enum CharacteristicMethodArg {
+    /// 5th percentile of the values
+    P5,
+    /// Mean minus one standard deviation
+    MeanMinusStd,
+}
+
+impl From<CharacteristicMethodArg> for agsi_core::statistics::CharacteristicMethod {
+    fn from(arg: CharacteristicMethodArg) -> Self {
+        match arg {
+            CharacteristicMethodArg::P5 => Self::Percentile5,
+            CharacteristicMethodArg::MeanMinusStd => Self::MeanMinusStd,
+        }
+    }
+}
+
 #[derive(Clone, clap::ValueEnum)]
 enum FormItem {
     Document,
@@ -194,11 +556,30 @@ async fn main() -> Result<()> {
     let cli = Cli::parse();
 
     // Setup logging
-    setup_logging(cli.verbose);
+    setup_logging(&logging_config(&cli));
 
     match cli.command {
-        Commands::Validate { file, detailed } => {
-            commands::validate::execute(file, detailed).await?;
+        Commands::Validate {
+            file,
+            detailed,
+            profile,
+            format,
+            no_color,
+            lenient,
+        } => {
+            let plain = no_color || std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty());
+            commands::validate::execute(file, detailed, profile, format, plain, lenient).await?;
+        }
+        Commands::Characteristic {
+            file,
+            model,
+            code,
+            method,
+        } => {
+            commands::characteristic::execute(file, model, code, method.into()).await?;
+        }
+        Commands::CheckGeometry { file } => {
+            commands::check_geometry::execute(file).await?;
         }
         Commands::Create { item } => match item {
             CreateItem::Document { id, output } => {
@@ -211,41 +592,152 @@ async fn main() -> Result<()> {
                 commands::create::model(id, name, output).await?;
             }
         },
+        Commands::Template {
+            with_example_materials,
+            output,
+        } => {
+            commands::template::execute(with_example_materials, output).await?;
+        }
         Commands::Edit { file } => {
             commands::edit::execute(file).await?;
         }
         Commands::Tui { file } => {
-            ui::editor::launch_editor(file).await?;
+            ui::launch_editor(file).await?;
         }
         Commands::Extract {
             file,
             model,
             output,
+            include_geometry,
+            wrap,
         } => {
-            commands::extract::execute(file, model, output).await?;
+            commands::extract::execute(file, model, output, include_geometry, wrap).await?;
         }
-        Commands::Info {
+        Commands::ExtractMaterial { file, id, output } => {
+            commands::extract_material::execute(file, id, output).await?;
+        }
+        Commands::Flatten {
             file,
+            model,
+            x,
+            y,
+            output,
+        } => {
+            commands::flatten::execute(file, model, x, y, output).await?;
+        }
+        Commands::Geojson { file, output } => {
+            commands::geojson::execute(file, output).await?;
+        }
+        Commands::Graph { file, output } => {
+            commands::graph::execute(file, output).await?;
+        }
+        Commands::Lint { file } => {
+            commands::lint::execute(file).await?;
+        }
+        Commands::Fix {
+            file,
+            output,
+            report,
+        } => {
+            commands::fix::execute(file, output, report).await?;
+        }
+        Commands::Coverage { file } => {
+            commands::coverage::execute(file).await?;
+        }
+        Commands::Merge { files, output } => {
+            commands::merge::execute(files, output).await?;
+        }
+        Commands::ImportCsv { file, output } => {
+            commands::import_csv::execute(file, output).await?;
+        }
+        Commands::Info {
+            files,
             materials,
             models,
         } => {
-            commands::info::execute(file, materials, models).await?;
+            commands::info::execute(files, materials, models).await?;
         }
         Commands::Convert {
             input,
             output,
             format,
+            lenient,
         } => {
-            commands::convert::execute(input, output, format).await?;
+            commands::convert::execute(input, output, format, lenient).await?;
         }
         Commands::Form { item, output } => {
             commands::form::execute(item, output).await?;
         }
-        Commands::Diff { file1, file2, detailed } => {
-            commands::diff::execute(file1, file2, detailed).await?;
+        Commands::Diff {
+            file1,
+            file2,
+            detailed,
+            json,
+        } => {
+            commands::diff::execute(file1, file2, detailed, json).await?;
+        }
+        Commands::Migrate { file, output } => {
+            commands::migrate::execute(file, output).await?;
+        }
+        Commands::Normalize {
+            file,
+            output,
+            round,
+        } => {
+            commands::normalize::execute(file, output, round).await?;
         }
-        Commands::Stats { file } => {
-            commands::stats::execute(file).await?;
+        Commands::NormalizeCodes { file, output } => {
+            commands::normalize_codes::execute(file, output).await?;
+        }
+        Commands::Patch {
+            file,
+            ops,
+            output,
+            force,
+        } => {
+            commands::patch::execute(file, ops, output, force).await?;
+        }
+        Commands::Recolour {
+            file,
+            output,
+            palette,
+            overwrite,
+        } => {
+            commands::recolour::execute(file, output, palette, overwrite).await?;
+        }
+        Commands::Redact {
+            file,
+            output,
+            keep_project,
+            keep_attachments,
+        } => {
+            commands::redact::execute(file, output, keep_project, keep_attachments).await?;
+        }
+        Commands::Prune {
+            file,
+            output,
+            dry_run,
+        } => {
+            commands::prune::execute(file, output, dry_run).await?;
+        }
+        Commands::Profile {
+            file,
+            element,
+            code,
+        } => {
+            commands::profile::execute(file, element, code).await?;
+        }
+        Commands::Cases { file, code } => {
+            commands::cases::execute(file, code).await?;
+        }
+        Commands::Repl { file } => {
+            commands::repl::execute(file).await?;
+        }
+        Commands::Stats { files } => {
+            commands::stats::execute(files).await?;
+        }
+        Commands::Volumes { file, model } => {
+            commands::volumes::execute(file, model).await?;
         }
         Commands::Mcp { debug } => {
             if debug {
@@ -261,22 +753,67 @@ async fn main() -> Result<()> {
             }
             lsp::run_lsp_server().await?;
         }
+        Commands::Completions { shell } => {
+            commands::completions::execute(shell, &mut std::io::stdout())?;
+        }
     }
 
     Ok(())
 }
 
-fn setup_logging(verbose: bool) {
-    use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt, EnvFilter};
+/// How the global subscriber is set up, as picked by the CLI flags
+#[derive(Debug, PartialEq)]
+struct LoggingConfig {
+    filter: &'static str,
+    format: LogFormat,
+}
 
-    let filter = if verbose {
-        EnvFilter::new("agsi=debug,agsi_core=debug")
-    } else {
-        EnvFilter::new("agsi=info,agsi_core=info")
-    };
+fn logging_config(cli: &Cli) -> LoggingConfig {
+    LoggingConfig {
+        filter: if cli.verbose {
+            "agsi=debug,agsi_core=debug"
+        } else {
+            "agsi=info,agsi_core=info"
+        },
+        format: cli.log_format,
+    }
+}
+
+fn setup_logging(config: &LoggingConfig) {
+    use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt, EnvFilter};
 
+    // Only one of the two fmt layers is present
+    let json = config.format == LogFormat::Json;
     tracing_subscriber::registry()
-        .with(filter)
-        .with(tracing_subscriber::fmt::layer())
+        .with(EnvFilter::new(config.filter))
+        .with(json.then(|| tracing_subscriber::fmt::layer().json()))
+        .with((!json).then(tracing_subscriber::fmt::layer))
         .init();
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_log_format_flag_selects_json() {
+        let cli = Cli::parse_from([
+            "agsipls",
+            "stats",
+            "model.json",
+            "--log-format",
+            "json",
+            "-v",
+        ]);
+        assert_eq!(
+            logging_config(&cli),
+            LoggingConfig {
+                filter: "agsi=debug,agsi_core=debug",
+                format: LogFormat::Json,
+            }
+        );
+
+        let cli = Cli::parse_from(["agsipls", "stats", "model.json"]);
+        assert_eq!(logging_config(&cli).format, LogFormat::Text);
+    }
+}
@@ -1,35 +1,31 @@
-use agsi_core::Document;
+use agsi_core::cache::DocumentCache;
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
 use serde_json::json;
 use std::collections::HashMap;
 use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
 
-/// MCP Server for AGSi ground model operations
-///
-/// Implements the Model Context Protocol to enable LLM interaction with AGSi data
-
+#[allow(dead_code)]
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(tag = "method", content = "params")]
 enum McpRequest {
     #[serde(rename = "tools/list")]
     ListTools {},
-    
+
     #[serde(rename = "tools/call")]
     CallTool {
         name: String,
         arguments: HashMap<String, serde_json::Value>,
     },
-    
+
     #[serde(rename = "resources/list")]
     ListResources {},
-    
+
     #[serde(rename = "resources/read")]
-    ReadResource {
-        uri: String,
-    },
+    ReadResource { uri: String },
 }
 
+#[allow(dead_code)]
 #[derive(Debug, Serialize)]
 struct McpResponse {
     result: serde_json::Value,
@@ -42,15 +38,18 @@ struct Tool {
     input_schema: serde_json::Value,
 }
 
+/// MCP Server for AGSi ground model operations
+///
+/// Implements the Model Context Protocol to enable LLM interaction with AGSi data
 struct AgsiMcpServer {
     // Cache loaded documents
-    documents: HashMap<String, Document>,
+    documents: DocumentCache,
 }
 
 impl AgsiMcpServer {
     fn new() -> Self {
         Self {
-            documents: HashMap::new(),
+            documents: DocumentCache::default(),
         }
     }
 
@@ -143,12 +142,16 @@ impl AgsiMcpServer {
         }
     }
 
-    async fn validate_file(&mut self, args: HashMap<String, serde_json::Value>) -> Result<serde_json::Value> {
-        let file_path = args.get("file_path")
+    async fn validate_file(
+        &mut self,
+        args: HashMap<String, serde_json::Value>,
+    ) -> Result<serde_json::Value> {
+        let file_path = args
+            .get("file_path")
             .and_then(|v| v.as_str())
             .ok_or_else(|| anyhow::anyhow!("Missing file_path"))?;
 
-        let doc = Document::from_json_file(file_path)?;
+        let doc = self.documents.load(file_path)?;
         let result = agsi_core::validation::validate_document(&doc)?;
 
         Ok(json!({
@@ -156,6 +159,7 @@ impl AgsiMcpServer {
             "errors": result.errors().iter().map(|e| {
                 json!({
                     "path": e.path,
+                    "json_pointer": e.json_pointer,
                     "message": e.message,
                     "type": format!("{:?}", e.error_type)
                 })
@@ -163,18 +167,23 @@ impl AgsiMcpServer {
             "warnings": result.warnings().iter().map(|w| {
                 json!({
                     "path": w.path,
+                    "json_pointer": w.json_pointer,
                     "message": w.message
                 })
             }).collect::<Vec<_>>()
         }))
     }
 
-    async fn extract_materials(&mut self, args: HashMap<String, serde_json::Value>) -> Result<serde_json::Value> {
-        let file_path = args.get("file_path")
+    async fn extract_materials(
+        &mut self,
+        args: HashMap<String, serde_json::Value>,
+    ) -> Result<serde_json::Value> {
+        let file_path = args
+            .get("file_path")
             .and_then(|v| v.as_str())
             .ok_or_else(|| anyhow::anyhow!("Missing file_path"))?;
 
-        let doc = Document::from_json_file(file_path)?;
+        let doc = self.documents.load(file_path)?;
         let model_id = args.get("model_id").and_then(|v| v.as_str());
 
         let model = if let Some(id) = model_id {
@@ -211,12 +220,16 @@ impl AgsiMcpServer {
         }))
     }
 
-    async fn get_info(&mut self, args: HashMap<String, serde_json::Value>) -> Result<serde_json::Value> {
-        let file_path = args.get("file_path")
+    async fn get_info(
+        &mut self,
+        args: HashMap<String, serde_json::Value>,
+    ) -> Result<serde_json::Value> {
+        let file_path = args
+            .get("file_path")
             .and_then(|v| v.as_str())
             .ok_or_else(|| anyhow::anyhow!("Missing file_path"))?;
 
-        let doc = Document::from_json_file(file_path)?;
+        let doc = self.documents.load(file_path)?;
 
         Ok(json!({
             "file_id": doc.ags_file.file_id,
@@ -243,12 +256,16 @@ impl AgsiMcpServer {
         }))
     }
 
-    async fn query_materials(&mut self, args: HashMap<String, serde_json::Value>) -> Result<serde_json::Value> {
-        let file_path = args.get("file_path")
+    async fn query_materials(
+        &mut self,
+        args: HashMap<String, serde_json::Value>,
+    ) -> Result<serde_json::Value> {
+        let file_path = args
+            .get("file_path")
             .and_then(|v| v.as_str())
             .ok_or_else(|| anyhow::anyhow!("Missing file_path"))?;
 
-        let doc = Document::from_json_file(file_path)?;
+        let doc = self.documents.load(file_path)?;
         let material_type = args.get("material_type").and_then(|v| v.as_str());
         let property_name = args.get("property_name").and_then(|v| v.as_str());
 
@@ -259,7 +276,9 @@ impl AgsiMcpServer {
                 let mut matches = true;
 
                 if let Some(mat_type) = material_type {
-                    if format!("{:?}", material.material_type).to_uppercase() != mat_type.to_uppercase() {
+                    if format!("{:?}", material.material_type).to_uppercase()
+                        != mat_type.to_uppercase()
+                    {
                         matches = false;
                     }
                 }
@@ -302,17 +321,17 @@ pub async fn run_mcp_server() -> Result<()> {
     loop {
         line.clear();
         let n = reader.read_line(&mut line).await?;
-        
+
         if n == 0 {
             break; // EOF
         }
 
         let request: Result<serde_json::Value, _> = serde_json::from_str(&line);
-        
+
         let response = match request {
             Ok(req) => {
                 eprintln!("Received request: {:?}", req);
-                
+
                 if let Some(method) = req.get("method").and_then(|m| m.as_str()) {
                     match method {
                         "tools/list" => {
@@ -327,9 +346,11 @@ pub async fn run_mcp_server() -> Result<()> {
                         "tools/call" => {
                             let params = req.get("params").cloned().unwrap_or(json!({}));
                             let name = params.get("name").and_then(|n| n.as_str()).unwrap_or("");
-                            let arguments: HashMap<String, serde_json::Value> = 
-                                serde_json::from_value(params.get("arguments").cloned().unwrap_or(json!({})))?;
-                            
+                            let arguments: HashMap<String, serde_json::Value> =
+                                serde_json::from_value(
+                                    params.get("arguments").cloned().unwrap_or(json!({})),
+                                )?;
+
                             match server.handle_tool_call(name, arguments).await {
                                 Ok(result) => {
                                     json!({
@@ -1,7 +1,8 @@
-use agsi_core::{Document, Material, GroundModel, MaterialProperty};
-use agsi_core::material::{MaterialType, PropertySource, PropertyValue};
-use agsi_core::model::{ModelType, ModelDimension, ComponentType, ModelComponent};
 use agsi_core::geometry::Geometry;
+use agsi_core::material::MaterialType;
+use agsi_core::model::{ComponentType, ModelComponent, ModelDimension, ModelType};
+use agsi_core::validation::{validate_document, ValidationResult};
+use agsi_core::{Document, GroundModel, Material};
 use anyhow::Result;
 use crossterm::{
     event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEventKind},
@@ -12,10 +13,11 @@ use ratatui::{
     backend::CrosstermBackend,
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
-    text::{Line, Span, Text},
-    widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap, Clear},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap},
     Frame, Terminal,
 };
+use std::collections::HashSet;
 use std::path::PathBuf;
 
 pub struct TuiEditor {
@@ -27,6 +29,16 @@ pub struct TuiEditor {
     input_buffer: String,
     status_message: String,
     should_quit: bool,
+    /// Set when the document failed validation and the next 's' saves anyway
+    confirm_save: bool,
+    /// Indices of list items marked with space for bulk operations
+    selected: HashSet<usize>,
+    /// Set when the document has been edited since it was loaded or saved
+    dirty: bool,
+    /// Set when there are unsaved changes and the next 'q' quits anyway
+    confirm_quit: bool,
+    /// Set after 'x' until 'y' deletes the targeted items or another key cancels
+    confirm_delete: bool,
 }
 
 #[derive(Clone, Copy, PartialEq)]
@@ -46,6 +58,7 @@ enum CreationMode {
     SelectModelType,
     SelectModelDimension,
     SelectComponentType,
+    SetBulkAttribute,
 }
 
 #[derive(Clone, PartialEq)]
@@ -145,7 +158,7 @@ impl TuiEditor {
     pub fn new(document: Document, file_path: Option<PathBuf>) -> Self {
         let mut list_state = ListState::default();
         list_state.select(Some(0));
-        
+
         Self {
             document,
             file_path,
@@ -155,6 +168,11 @@ impl TuiEditor {
             input_buffer: String::new(),
             status_message: "Press 'q' to quit, '?' for help, 'n' to create new".to_string(),
             should_quit: false,
+            confirm_save: false,
+            selected: HashSet::new(),
+            dirty: false,
+            confirm_quit: false,
+            confirm_delete: false,
         }
     }
 
@@ -212,25 +230,50 @@ impl TuiEditor {
             return;
         }
 
+        // A pending delete takes 'y' to go ahead; any other key cancels it
+        if std::mem::take(&mut self.confirm_delete) {
+            if key == KeyCode::Char('y') {
+                self.delete_selected();
+            } else {
+                self.status_message = "Delete cancelled".to_string();
+            }
+            return;
+        }
+
+        // Any key other than 's' cancels a pending save confirmation
+        let confirming = std::mem::take(&mut self.confirm_save);
+        if confirming && key != KeyCode::Char('s') {
+            self.status_message = "Save cancelled".to_string();
+        }
+        let confirming_quit = std::mem::take(&mut self.confirm_quit);
+
         // Normal mode inputs
         match key {
-            KeyCode::Char('q') => self.should_quit = true,
-            KeyCode::Char('d') => self.view_mode = ViewMode::DocumentInfo,
-            KeyCode::Char('m') => self.view_mode = ViewMode::Models,
-            KeyCode::Char('a') => self.view_mode = ViewMode::Materials,
-            KeyCode::Char('c') => self.view_mode = ViewMode::Components,
+            KeyCode::Char('q') => {
+                if self.dirty && !confirming_quit {
+                    self.status_message =
+                        "⚠️ Unsaved changes: press 'q' again to quit without saving".to_string();
+                    self.confirm_quit = true;
+                } else {
+                    self.should_quit = true;
+                }
+            }
+            KeyCode::Char('d') => self.set_view_mode(ViewMode::DocumentInfo),
+            KeyCode::Char('m') => self.set_view_mode(ViewMode::Models),
+            KeyCode::Char('a') => self.set_view_mode(ViewMode::Materials),
+            KeyCode::Char('c') => self.set_view_mode(ViewMode::Components),
+            KeyCode::Char(' ') => self.toggle_current_selection(),
+            KeyCode::Char('x') => self.request_delete(),
+            KeyCode::Char('t') => self.start_bulk_attribute(),
             KeyCode::Char('n') => {
                 // Start creation workflow
                 self.start_creation_workflow();
             }
             KeyCode::Char('s') => {
-                if let Some(ref path) = self.file_path {
-                    match self.document.to_json_file(path) {
-                        Ok(_) => self.status_message = format!("✅ Saved to {}", path.display()),
-                        Err(e) => self.status_message = format!("❌ Error saving: {}", e),
-                    }
+                if confirming {
+                    self.save();
                 } else {
-                    self.status_message = "❌ No file path specified".to_string();
+                    self.request_save();
                 }
             }
             KeyCode::Down => {
@@ -247,25 +290,237 @@ impl TuiEditor {
                 let len = self.get_list_len();
                 if len > 0 {
                     let i = match self.list_state.selected() {
-                        Some(i) => if i == 0 { len - 1 } else { i - 1 },
+                        Some(i) => {
+                            if i == 0 {
+                                len - 1
+                            } else {
+                                i - 1
+                            }
+                        }
                         None => 0,
                     };
                     self.list_state.select(Some(i));
                 }
             }
             KeyCode::Char('?') => {
-                self.status_message = "Keys: q=quit, d=doc, m=models, a=materials, c=components, n=new, s=save, ↑↓=navigate".to_string();
+                self.status_message = "Keys: q=quit, d=doc, m=models, a=materials, c=components, n=new, s=save, ↑↓=navigate, space=select, x=delete, t=set attribute".to_string();
             }
             _ => {}
         }
     }
 
+    fn set_view_mode(&mut self, view_mode: ViewMode) {
+        if self.view_mode != view_mode {
+            self.selected.clear();
+        }
+        self.view_mode = view_mode;
+    }
+
+    fn supports_bulk_operations(&self) -> bool {
+        matches!(self.view_mode, ViewMode::Materials | ViewMode::Components)
+    }
+
+    /// Map list indices in the current view to (model index, item index) pairs
+    fn list_entries(&self) -> Vec<(usize, usize)> {
+        self.document
+            .agsi_model
+            .iter()
+            .enumerate()
+            .flat_map(|(m, model)| {
+                let len = match self.view_mode {
+                    ViewMode::Materials => model.materials.len(),
+                    ViewMode::Components => model.components.len(),
+                    _ => 0,
+                };
+                (0..len).map(move |i| (m, i))
+            })
+            .collect()
+    }
+
+    /// Items targeted by a bulk operation: the selection, or the highlighted item
+    fn bulk_targets(&self) -> Vec<(usize, usize)> {
+        let entries = self.list_entries();
+        if self.selected.is_empty() {
+            return self
+                .list_state
+                .selected()
+                .and_then(|i| entries.get(i).copied())
+                .into_iter()
+                .collect();
+        }
+
+        entries
+            .into_iter()
+            .enumerate()
+            .filter(|(i, _)| self.selected.contains(i))
+            .map(|(_, entry)| entry)
+            .collect()
+    }
+
+    fn toggle_current_selection(&mut self) {
+        if !self.supports_bulk_operations() {
+            return;
+        }
+
+        if let Some(i) = self
+            .list_state
+            .selected()
+            .filter(|i| *i < self.get_list_len())
+        {
+            toggle_selection(&mut self.selected, i);
+            self.status_message = format!("{} item(s) selected", self.selected.len());
+        }
+    }
+
+    fn start_bulk_attribute(&mut self) {
+        if self.supports_bulk_operations() && !self.bulk_targets().is_empty() {
+            self.creation_mode = Some(CreationMode::SetBulkAttribute);
+            self.input_buffer.clear();
+            self.status_message =
+                "Set attribute on selection: enter key=value (Esc to cancel)".to_string();
+        }
+    }
+
+    /// Ask for confirmation before deleting the selection or highlighted item
+    fn request_delete(&mut self) {
+        if !self.supports_bulk_operations() {
+            return;
+        }
+
+        let count = self.bulk_targets().len();
+        if count > 0 {
+            self.confirm_delete = true;
+            self.status_message = format!("🗑️ Delete {} item(s)? (y/n)", count);
+        }
+    }
+
+    fn delete_selected(&mut self) {
+        if !self.supports_bulk_operations() {
+            return;
+        }
+
+        let mut targets = self.bulk_targets();
+        // Remove from the back so earlier indices stay valid
+        targets.sort_unstable_by(|a, b| b.cmp(a));
+
+        let mut deleted = 0;
+        let mut skipped = 0;
+        for (m, i) in targets {
+            let model = &mut self.document.agsi_model[m];
+            match self.view_mode {
+                ViewMode::Materials => {
+                    let id = model.materials[i].id.clone();
+                    match model.remove_material(&id, false) {
+                        Ok(_) => deleted += 1,
+                        Err(_) => skipped += 1,
+                    }
+                }
+                _ => {
+                    model.components_mut().remove(i);
+                    deleted += 1;
+                }
+            }
+        }
+
+        self.dirty |= deleted > 0;
+        self.selected.clear();
+        self.list_state.select(Some(0));
+        self.status_message = if skipped > 0 {
+            format!(
+                "🗑️ Deleted {} item(s), skipped {} still referenced by components",
+                deleted, skipped
+            )
+        } else {
+            format!("🗑️ Deleted {} item(s)", deleted)
+        };
+    }
+
+    fn set_bulk_attribute(&mut self, input: &str) {
+        let Some((key, value)) = input.split_once('=') else {
+            self.status_message = "❌ Expected key=value".to_string();
+            return;
+        };
+        let key = key.trim();
+        let value = value.trim();
+        let value = serde_json::from_str(value)
+            .unwrap_or_else(|_| serde_json::Value::String(value.to_string()));
+
+        let targets = self.bulk_targets();
+        for (m, i) in &targets {
+            let model = &mut self.document.agsi_model[*m];
+            let attributes = match self.view_mode {
+                ViewMode::Materials => &mut model.materials[*i].metadata,
+                _ => &mut model.components[*i].attributes,
+            };
+            attributes.insert(key.to_string(), value.clone());
+        }
+        self.dirty |= !targets.is_empty();
+
+        self.status_message = format!("✅ Set '{}' on {} item(s)", key, targets.len());
+    }
+
+    /// Validate before saving, asking for confirmation if the document is invalid
+    fn request_save(&mut self) {
+        if self.file_path.is_none() {
+            self.status_message = "❌ No file path specified".to_string();
+            return;
+        }
+
+        let result = match validate_document(&self.document) {
+            Ok(result) => result,
+            Err(e) => {
+                self.status_message = format!("❌ Validation failed: {}", e);
+                return;
+            }
+        };
+
+        match pre_save_status(&result) {
+            Some(message) => {
+                self.status_message = message;
+                self.confirm_save = true;
+            }
+            None => self.save(),
+        }
+    }
+
+    fn save(&mut self) {
+        if let Some(ref path) = self.file_path {
+            match self.document.to_json_file(path) {
+                Ok(_) => {
+                    self.dirty = false;
+                    let placeholders = self
+                        .document
+                        .agsi_model
+                        .iter()
+                        .flat_map(|m| &m.components)
+                        .filter(|c| c.geometry.is_placeholder())
+                        .count();
+                    self.status_message = if placeholders > 0 {
+                        format!(
+                            "✅ Saved to {} (⚠️ {} component(s) still have placeholder geometry)",
+                            path.display(),
+                            placeholders
+                        )
+                    } else {
+                        format!("✅ Saved to {}", path.display())
+                    };
+                }
+                Err(e) => self.status_message = format!("❌ Error saving: {}", e),
+            }
+        } else {
+            self.status_message = "❌ No file path specified".to_string();
+        }
+    }
+
     fn start_creation_workflow(&mut self) {
         // Show menu based on current view
         match self.view_mode {
             ViewMode::Materials => {
-                self.creation_mode = Some(CreationMode::CreateMaterial(MaterialCreationState::default()));
-                self.status_message = "Creating Material: Enter ID (press Esc to cancel)".to_string();
+                self.creation_mode = Some(CreationMode::CreateMaterial(
+                    MaterialCreationState::default(),
+                ));
+                self.status_message =
+                    "Creating Material: Enter ID (press Esc to cancel)".to_string();
             }
             ViewMode::Models => {
                 self.creation_mode = Some(CreationMode::CreateModel(ModelCreationState::default()));
@@ -275,12 +530,17 @@ impl TuiEditor {
                 if self.document.agsi_model.is_empty() {
                     self.status_message = "❌ Create a model first!".to_string();
                 } else {
-                    self.creation_mode = Some(CreationMode::CreateComponent(ComponentCreationState::default()));
-                    self.status_message = "Creating Component: Select model (↑↓, Enter to confirm, Esc to cancel)".to_string();
+                    self.creation_mode = Some(CreationMode::CreateComponent(
+                        ComponentCreationState::default(),
+                    ));
+                    self.status_message =
+                        "Creating Component: Select model (↑↓, Enter to confirm, Esc to cancel)"
+                            .to_string();
                 }
             }
             _ => {
-                self.status_message = "Press: m=new model, a=new material, c=new component".to_string();
+                self.status_message =
+                    "Press: m=new model, a=new material, c=new component".to_string();
             }
         }
         self.input_buffer.clear();
@@ -313,7 +573,11 @@ impl TuiEditor {
                             let next = if key == KeyCode::Down {
                                 (current + 1) % len
                             } else {
-                                if current == 0 { len - 1 } else { current - 1 }
+                                if current == 0 {
+                                    len - 1
+                                } else {
+                                    current - 1
+                                }
                             };
                             self.list_state.select(Some(next));
                         }
@@ -338,7 +602,11 @@ impl TuiEditor {
         let next = if key == KeyCode::Down {
             (current + 1) % len
         } else {
-            if current == 0 { len - 1 } else { current - 1 }
+            if current == 0 {
+                len - 1
+            } else {
+                current - 1
+            }
         };
         self.list_state.select(Some(next));
     }
@@ -366,7 +634,8 @@ impl TuiEditor {
                         // Type already selected
                         state.step = MaterialCreationStep::EnteringDescription;
                         self.creation_mode = Some(CreationMode::CreateMaterial(state));
-                        self.status_message = "Enter description (optional, press Enter to finish):".to_string();
+                        self.status_message =
+                            "Enter description (optional, press Enter to finish):".to_string();
                     }
                     MaterialCreationStep::EnteringDescription => {
                         state.description = self.input_buffer.clone();
@@ -386,49 +655,49 @@ impl TuiEditor {
                     6 => MaterialType::Void,
                     _ => MaterialType::Unknown,
                 };
-                
+
                 if let Some(CreationMode::CreateMaterial(mut state)) = self.creation_mode.take() {
                     state.material_type = Some(mat_type);
                     state.step = MaterialCreationStep::EnteringDescription;
                     self.creation_mode = Some(CreationMode::CreateMaterial(state));
                     self.input_buffer.clear();
-                    self.status_message = "Enter description (optional, press Enter to finish):".to_string();
+                    self.status_message =
+                        "Enter description (optional, press Enter to finish):".to_string();
                 }
             }
-            CreationMode::CreateModel(mut state) => {
-                match state.step {
-                    ModelCreationStep::EnteringId => {
-                        state.id = self.input_buffer.clone();
-                        self.input_buffer.clear();
-                        state.step = ModelCreationStep::EnteringName;
-                        self.creation_mode = Some(CreationMode::CreateModel(state));
-                        self.status_message = "Enter model name:".to_string();
-                    }
-                    ModelCreationStep::EnteringName => {
-                        state.name = self.input_buffer.clone();
-                        self.input_buffer.clear();
-                        state.step = ModelCreationStep::SelectingType;
-                        self.creation_mode = Some(CreationMode::SelectModelType);
-                        self.list_state.select(Some(0));
-                        self.status_message = "Select model type (↑↓, Enter):".to_string();
-                    }
-                    ModelCreationStep::SelectingType => {
-                        state.step = ModelCreationStep::SelectingDimension;
-                        self.creation_mode = Some(CreationMode::SelectModelDimension);
-                        self.list_state.select(Some(0));
-                        self.status_message = "Select dimension (↑↓, Enter):".to_string();
-                    }
-                    ModelCreationStep::SelectingDimension => {
-                        state.step = ModelCreationStep::EnteringDescription;
-                        self.creation_mode = Some(CreationMode::CreateModel(state));
-                        self.status_message = "Enter description (optional, press Enter to finish):".to_string();
-                    }
-                    ModelCreationStep::EnteringDescription => {
-                        state.description = self.input_buffer.clone();
-                        self.finish_model_creation(state);
-                    }
+            CreationMode::CreateModel(mut state) => match state.step {
+                ModelCreationStep::EnteringId => {
+                    state.id = self.input_buffer.clone();
+                    self.input_buffer.clear();
+                    state.step = ModelCreationStep::EnteringName;
+                    self.creation_mode = Some(CreationMode::CreateModel(state));
+                    self.status_message = "Enter model name:".to_string();
                 }
-            }
+                ModelCreationStep::EnteringName => {
+                    state.name = self.input_buffer.clone();
+                    self.input_buffer.clear();
+                    state.step = ModelCreationStep::SelectingType;
+                    self.creation_mode = Some(CreationMode::SelectModelType);
+                    self.list_state.select(Some(0));
+                    self.status_message = "Select model type (↑↓, Enter):".to_string();
+                }
+                ModelCreationStep::SelectingType => {
+                    state.step = ModelCreationStep::SelectingDimension;
+                    self.creation_mode = Some(CreationMode::SelectModelDimension);
+                    self.list_state.select(Some(0));
+                    self.status_message = "Select dimension (↑↓, Enter):".to_string();
+                }
+                ModelCreationStep::SelectingDimension => {
+                    state.step = ModelCreationStep::EnteringDescription;
+                    self.creation_mode = Some(CreationMode::CreateModel(state));
+                    self.status_message =
+                        "Enter description (optional, press Enter to finish):".to_string();
+                }
+                ModelCreationStep::EnteringDescription => {
+                    state.description = self.input_buffer.clone();
+                    self.finish_model_creation(state);
+                }
+            },
             CreationMode::SelectModelType => {
                 let selected = self.list_state.selected().unwrap_or(0);
                 let model_type = match selected {
@@ -439,7 +708,7 @@ impl TuiEditor {
                     4 => ModelType::Environmental,
                     _ => ModelType::Composite,
                 };
-                
+
                 if let Some(CreationMode::CreateModel(mut state)) = self.creation_mode.take() {
                     state.model_type = Some(model_type);
                     state.step = ModelCreationStep::SelectingDimension;
@@ -456,48 +725,52 @@ impl TuiEditor {
                     1 => ModelDimension::TwoD,
                     _ => ModelDimension::ThreeD,
                 };
-                
+
                 if let Some(CreationMode::CreateModel(mut state)) = self.creation_mode.take() {
                     state.dimension = Some(dimension);
                     state.step = ModelCreationStep::EnteringDescription;
                     self.creation_mode = Some(CreationMode::CreateModel(state));
                     self.input_buffer.clear();
-                    self.status_message = "Enter description (optional, press Enter to finish):".to_string();
+                    self.status_message =
+                        "Enter description (optional, press Enter to finish):".to_string();
                 }
             }
-            CreationMode::CreateComponent(mut state) => {
-                match state.step {
-                    ComponentCreationStep::SelectingModel => {
-                        state.model_index = self.list_state.selected().unwrap_or(0);
-                        state.step = ComponentCreationStep::EnteringId;
-                        self.creation_mode = Some(CreationMode::CreateComponent(state));
-                        self.status_message = "Enter component ID:".to_string();
-                    }
-                    ComponentCreationStep::EnteringId => {
-                        state.id = self.input_buffer.clone();
-                        self.input_buffer.clear();
-                        state.step = ComponentCreationStep::EnteringName;
-                        self.creation_mode = Some(CreationMode::CreateComponent(state));
-                        self.status_message = "Enter component name:".to_string();
-                    }
-                    ComponentCreationStep::EnteringName => {
-                        state.name = self.input_buffer.clone();
-                        self.input_buffer.clear();
-                        state.step = ComponentCreationStep::SelectingType;
-                        self.creation_mode = Some(CreationMode::SelectComponentType);
-                        self.list_state.select(Some(0));
-                        self.status_message = "Select component type (↑↓, Enter):".to_string();
-                    }
-                    ComponentCreationStep::SelectingType => {
-                        state.step = ComponentCreationStep::EnteringMaterialId;
-                        self.creation_mode = Some(CreationMode::CreateComponent(state));
-                        self.status_message = "Enter material ID:".to_string();
-                    }
-                    ComponentCreationStep::EnteringMaterialId => {
-                        state.material_id = self.input_buffer.clone();
-                        self.finish_component_creation(state);
-                    }
+            CreationMode::CreateComponent(mut state) => match state.step {
+                ComponentCreationStep::SelectingModel => {
+                    state.model_index = self.list_state.selected().unwrap_or(0);
+                    state.step = ComponentCreationStep::EnteringId;
+                    self.creation_mode = Some(CreationMode::CreateComponent(state));
+                    self.status_message = "Enter component ID:".to_string();
+                }
+                ComponentCreationStep::EnteringId => {
+                    state.id = self.input_buffer.clone();
+                    self.input_buffer.clear();
+                    state.step = ComponentCreationStep::EnteringName;
+                    self.creation_mode = Some(CreationMode::CreateComponent(state));
+                    self.status_message = "Enter component name:".to_string();
+                }
+                ComponentCreationStep::EnteringName => {
+                    state.name = self.input_buffer.clone();
+                    self.input_buffer.clear();
+                    state.step = ComponentCreationStep::SelectingType;
+                    self.creation_mode = Some(CreationMode::SelectComponentType);
+                    self.list_state.select(Some(0));
+                    self.status_message = "Select component type (↑↓, Enter):".to_string();
+                }
+                ComponentCreationStep::SelectingType => {
+                    state.step = ComponentCreationStep::EnteringMaterialId;
+                    self.creation_mode = Some(CreationMode::CreateComponent(state));
+                    self.status_message = "Enter material ID:".to_string();
                 }
+                ComponentCreationStep::EnteringMaterialId => {
+                    state.material_id = self.input_buffer.clone();
+                    self.finish_component_creation(state);
+                }
+            },
+            CreationMode::SetBulkAttribute => {
+                let input = std::mem::take(&mut self.input_buffer);
+                self.creation_mode = None;
+                self.set_bulk_attribute(&input);
             }
             CreationMode::SelectComponentType => {
                 let selected = self.list_state.selected().unwrap_or(0);
@@ -509,7 +782,7 @@ impl TuiEditor {
                     4 => ComponentType::Intrusion,
                     _ => ComponentType::Boundary,
                 };
-                
+
                 if let Some(CreationMode::CreateComponent(mut state)) = self.creation_mode.take() {
                     state.component_type = Some(comp_type);
                     state.step = ComponentCreationStep::EnteringMaterialId;
@@ -542,6 +815,7 @@ impl TuiEditor {
                 self.document.agsi_model[0].add_material(material);
             }
 
+            self.dirty = true;
             self.creation_mode = None;
             self.input_buffer.clear();
             self.status_message = format!("✅ Material '{}' created!", state.id);
@@ -557,6 +831,7 @@ impl TuiEditor {
             }
 
             self.document.add_model(model);
+            self.dirty = true;
             self.creation_mode = None;
             self.input_buffer.clear();
             self.status_message = format!("✅ Model '{}' created!", state.id);
@@ -567,9 +842,9 @@ impl TuiEditor {
     fn finish_component_creation(&mut self, state: ComponentCreationState) {
         if let Some(comp_type) = state.component_type {
             if state.model_index < self.document.agsi_model.len() {
-                // Create simple point geometry as placeholder
-                let geometry = Geometry::point(0.0, 0.0, 0.0);
-                
+                // Real geometry is added later; lint flags the placeholder
+                let geometry = Geometry::placeholder();
+
                 let component = ModelComponent::new(
                     state.id.clone(),
                     state.name,
@@ -579,7 +854,8 @@ impl TuiEditor {
                 );
 
                 self.document.agsi_model[state.model_index].add_component(component);
-                
+                self.dirty = true;
+
                 self.creation_mode = None;
                 self.input_buffer.clear();
                 self.status_message = format!("✅ Component '{}' created!", state.id);
@@ -591,12 +867,18 @@ impl TuiEditor {
     fn get_list_len(&self) -> usize {
         match self.view_mode {
             ViewMode::Models => self.document.agsi_model.len(),
-            ViewMode::Materials => {
-                self.document.agsi_model.iter().map(|m| m.materials.len()).sum()
-            }
-            ViewMode::Components => {
-                self.document.agsi_model.iter().map(|m| m.components.len()).sum()
-            }
+            ViewMode::Materials => self
+                .document
+                .agsi_model
+                .iter()
+                .map(|m| m.materials.len())
+                .sum(),
+            ViewMode::Components => self
+                .document
+                .agsi_model
+                .iter()
+                .map(|m| m.components.len())
+                .sum(),
             _ => 0,
         }
     }
@@ -628,13 +910,13 @@ impl TuiEditor {
 
     fn render_creation_dialog(&mut self, f: &mut Frame) {
         let area = f.area();
-        
+
         // Create centered popup
         let popup_width = 60.min(area.width - 4);
         let popup_height = 20.min(area.height - 4);
         let popup_x = (area.width - popup_width) / 2;
         let popup_y = (area.height - popup_height) / 2;
-        
+
         let popup_area = Rect::new(popup_x, popup_y, popup_width, popup_height);
 
         // Clear the area
@@ -666,11 +948,30 @@ impl TuiEditor {
             Some(CreationMode::SelectComponentType) => {
                 self.render_component_type_selector(f, popup_area);
             }
+            Some(CreationMode::SetBulkAttribute) => {
+                let input = Paragraph::new(format!("key=value: {}", self.input_buffer))
+                    .style(Style::default().fg(Color::Yellow))
+                    .block(
+                        Block::default()
+                            .title(format!(
+                                "Set Attribute ({} item(s))",
+                                self.bulk_targets().len()
+                            ))
+                            .borders(Borders::ALL)
+                            .style(Style::default().fg(Color::Cyan)),
+                    );
+                f.render_widget(input, popup_area);
+            }
             None => {}
         }
     }
 
-    fn render_material_creation_dialog(&mut self, f: &mut Frame, area: Rect, state: &MaterialCreationState) {
+    fn render_material_creation_dialog(
+        &mut self,
+        f: &mut Frame,
+        area: Rect,
+        state: &MaterialCreationState,
+    ) {
         let chunks = Layout::default()
             .direction(Direction::Vertical)
             .margin(2)
@@ -690,9 +991,16 @@ impl TuiEditor {
         f.render_widget(block, area);
 
         // ID field
-        let id_text = format!("ID: {}", if state.id.is_empty() { &self.input_buffer } else { &state.id });
-        let id_widget = Paragraph::new(id_text)
-            .style(if state.step == MaterialCreationStep::EnteringId {
+        let id_text = format!(
+            "ID: {}",
+            if state.id.is_empty() {
+                &self.input_buffer
+            } else {
+                &state.id
+            }
+        );
+        let id_widget =
+            Paragraph::new(id_text).style(if state.step == MaterialCreationStep::EnteringId {
                 Style::default().fg(Color::Yellow)
             } else {
                 Style::default().fg(Color::Green)
@@ -700,9 +1008,16 @@ impl TuiEditor {
         f.render_widget(id_widget, chunks[0]);
 
         // Name field
-        let name_text = format!("Name: {}", if state.name.is_empty() { &self.input_buffer } else { &state.name });
-        let name_widget = Paragraph::new(name_text)
-            .style(if state.step == MaterialCreationStep::EnteringName {
+        let name_text = format!(
+            "Name: {}",
+            if state.name.is_empty() {
+                &self.input_buffer
+            } else {
+                &state.name
+            }
+        );
+        let name_widget =
+            Paragraph::new(name_text).style(if state.step == MaterialCreationStep::EnteringName {
                 Style::default().fg(Color::Yellow)
             } else if !state.name.is_empty() {
                 Style::default().fg(Color::Green)
@@ -712,25 +1027,35 @@ impl TuiEditor {
         f.render_widget(name_widget, chunks[1]);
 
         // Type field
-        let type_text = format!("Type: {:?}", state.material_type.unwrap_or(MaterialType::Unknown));
-        let type_widget = Paragraph::new(type_text)
-            .style(if state.material_type.is_some() {
-                Style::default().fg(Color::Green)
-            } else {
-                Style::default()
-            });
+        let type_text = format!(
+            "Type: {:?}",
+            state.material_type.unwrap_or(MaterialType::Unknown)
+        );
+        let type_widget = Paragraph::new(type_text).style(if state.material_type.is_some() {
+            Style::default().fg(Color::Green)
+        } else {
+            Style::default()
+        });
         f.render_widget(type_widget, chunks[2]);
 
         // Description field
-        let desc_text = format!("Description: {}", if state.description.is_empty() { &self.input_buffer } else { &state.description });
-        let desc_widget = Paragraph::new(desc_text)
-            .style(if state.step == MaterialCreationStep::EnteringDescription {
+        let desc_text = format!(
+            "Description: {}",
+            if state.description.is_empty() {
+                &self.input_buffer
+            } else {
+                &state.description
+            }
+        );
+        let desc_widget = Paragraph::new(desc_text).style(
+            if state.step == MaterialCreationStep::EnteringDescription {
                 Style::default().fg(Color::Yellow)
             } else if !state.description.is_empty() {
                 Style::default().fg(Color::Green)
             } else {
                 Style::default()
-            });
+            },
+        );
         f.render_widget(desc_widget, chunks[3]);
     }
 
@@ -747,18 +1072,29 @@ impl TuiEditor {
         ];
 
         let list = List::new(items)
-            .block(Block::default()
-                .title("Select Material Type")
-                .borders(Borders::ALL)
-                .style(Style::default().fg(Color::Cyan)))
-            .highlight_style(Style::default().bg(Color::DarkGray).add_modifier(Modifier::BOLD))
+            .block(
+                Block::default()
+                    .title("Select Material Type")
+                    .borders(Borders::ALL)
+                    .style(Style::default().fg(Color::Cyan)),
+            )
+            .highlight_style(
+                Style::default()
+                    .bg(Color::DarkGray)
+                    .add_modifier(Modifier::BOLD),
+            )
             .highlight_symbol("▶ ");
 
         f.render_widget(Clear, area);
         f.render_stateful_widget(list, area, &mut self.list_state);
     }
 
-    fn render_model_creation_dialog(&mut self, f: &mut Frame, area: Rect, state: &ModelCreationState) {
+    fn render_model_creation_dialog(
+        &mut self,
+        f: &mut Frame,
+        area: Rect,
+        state: &ModelCreationState,
+    ) {
         let chunks = Layout::default()
             .direction(Direction::Vertical)
             .margin(2)
@@ -779,41 +1115,92 @@ impl TuiEditor {
         f.render_widget(block, area);
 
         // ID
-        let id_text = format!("ID: {}", if state.id.is_empty() { &self.input_buffer } else { &state.id });
-        f.render_widget(Paragraph::new(id_text).style(
-            if state.step == ModelCreationStep::EnteringId { Style::default().fg(Color::Yellow) } 
-            else { Style::default().fg(Color::Green) }
-        ), chunks[0]);
+        let id_text = format!(
+            "ID: {}",
+            if state.id.is_empty() {
+                &self.input_buffer
+            } else {
+                &state.id
+            }
+        );
+        f.render_widget(
+            Paragraph::new(id_text).style(if state.step == ModelCreationStep::EnteringId {
+                Style::default().fg(Color::Yellow)
+            } else {
+                Style::default().fg(Color::Green)
+            }),
+            chunks[0],
+        );
 
         // Name
-        let name_text = format!("Name: {}", if state.name.is_empty() { &self.input_buffer } else { &state.name });
-        f.render_widget(Paragraph::new(name_text).style(
-            if state.step == ModelCreationStep::EnteringName { Style::default().fg(Color::Yellow) }
-            else if !state.name.is_empty() { Style::default().fg(Color::Green) }
-            else { Style::default() }
-        ), chunks[1]);
+        let name_text = format!(
+            "Name: {}",
+            if state.name.is_empty() {
+                &self.input_buffer
+            } else {
+                &state.name
+            }
+        );
+        f.render_widget(
+            Paragraph::new(name_text).style(if state.step == ModelCreationStep::EnteringName {
+                Style::default().fg(Color::Yellow)
+            } else if !state.name.is_empty() {
+                Style::default().fg(Color::Green)
+            } else {
+                Style::default()
+            }),
+            chunks[1],
+        );
 
         // Type
-        let type_text = format!("Type: {:?}", state.model_type.unwrap_or(ModelType::Composite));
-        f.render_widget(Paragraph::new(type_text).style(
-            if state.model_type.is_some() { Style::default().fg(Color::Green) }
-            else { Style::default() }
-        ), chunks[2]);
+        let type_text = format!(
+            "Type: {:?}",
+            state.model_type.unwrap_or(ModelType::Composite)
+        );
+        f.render_widget(
+            Paragraph::new(type_text).style(if state.model_type.is_some() {
+                Style::default().fg(Color::Green)
+            } else {
+                Style::default()
+            }),
+            chunks[2],
+        );
 
         // Dimension
-        let dim_text = format!("Dimension: {:?}", state.dimension.unwrap_or(ModelDimension::TwoD));
-        f.render_widget(Paragraph::new(dim_text).style(
-            if state.dimension.is_some() { Style::default().fg(Color::Green) }
-            else { Style::default() }
-        ), chunks[3]);
+        let dim_text = format!(
+            "Dimension: {:?}",
+            state.dimension.unwrap_or(ModelDimension::TwoD)
+        );
+        f.render_widget(
+            Paragraph::new(dim_text).style(if state.dimension.is_some() {
+                Style::default().fg(Color::Green)
+            } else {
+                Style::default()
+            }),
+            chunks[3],
+        );
 
         // Description
-        let desc_text = format!("Description: {}", if state.description.is_empty() { &self.input_buffer } else { &state.description });
-        f.render_widget(Paragraph::new(desc_text).style(
-            if state.step == ModelCreationStep::EnteringDescription { Style::default().fg(Color::Yellow) }
-            else if !state.description.is_empty() { Style::default().fg(Color::Green) }
-            else { Style::default() }
-        ), chunks[4]);
+        let desc_text = format!(
+            "Description: {}",
+            if state.description.is_empty() {
+                &self.input_buffer
+            } else {
+                &state.description
+            }
+        );
+        f.render_widget(
+            Paragraph::new(desc_text).style(
+                if state.step == ModelCreationStep::EnteringDescription {
+                    Style::default().fg(Color::Yellow)
+                } else if !state.description.is_empty() {
+                    Style::default().fg(Color::Green)
+                } else {
+                    Style::default()
+                },
+            ),
+            chunks[4],
+        );
     }
 
     fn render_model_type_selector(&mut self, f: &mut Frame, area: Rect) {
@@ -827,11 +1214,17 @@ impl TuiEditor {
         ];
 
         let list = List::new(items)
-            .block(Block::default()
-                .title("Select Model Type")
-                .borders(Borders::ALL)
-                .style(Style::default().fg(Color::Cyan)))
-            .highlight_style(Style::default().bg(Color::DarkGray).add_modifier(Modifier::BOLD))
+            .block(
+                Block::default()
+                    .title("Select Model Type")
+                    .borders(Borders::ALL)
+                    .style(Style::default().fg(Color::Cyan)),
+            )
+            .highlight_style(
+                Style::default()
+                    .bg(Color::DarkGray)
+                    .add_modifier(Modifier::BOLD),
+            )
             .highlight_symbol("▶ ");
 
         f.render_widget(Clear, area);
@@ -846,30 +1239,50 @@ impl TuiEditor {
         ];
 
         let list = List::new(items)
-            .block(Block::default()
-                .title("Select Dimension")
-                .borders(Borders::ALL)
-                .style(Style::default().fg(Color::Cyan)))
-            .highlight_style(Style::default().bg(Color::DarkGray).add_modifier(Modifier::BOLD))
+            .block(
+                Block::default()
+                    .title("Select Dimension")
+                    .borders(Borders::ALL)
+                    .style(Style::default().fg(Color::Cyan)),
+            )
+            .highlight_style(
+                Style::default()
+                    .bg(Color::DarkGray)
+                    .add_modifier(Modifier::BOLD),
+            )
             .highlight_symbol("▶ ");
 
         f.render_widget(Clear, area);
         f.render_stateful_widget(list, area, &mut self.list_state);
     }
 
-    fn render_component_creation_dialog(&mut self, f: &mut Frame, area: Rect, state: &ComponentCreationState) {
+    fn render_component_creation_dialog(
+        &mut self,
+        f: &mut Frame,
+        area: Rect,
+        state: &ComponentCreationState,
+    ) {
         if state.step == ComponentCreationStep::SelectingModel {
             // Show model selection
-            let items: Vec<ListItem> = self.document.agsi_model.iter()
+            let items: Vec<ListItem> = self
+                .document
+                .agsi_model
+                .iter()
                 .map(|m| ListItem::new(format!("{} ({})", m.name, m.id)))
                 .collect();
 
             let list = List::new(items)
-                .block(Block::default()
-                    .title("Select Model for Component")
-                    .borders(Borders::ALL)
-                    .style(Style::default().fg(Color::Cyan)))
-                .highlight_style(Style::default().bg(Color::DarkGray).add_modifier(Modifier::BOLD))
+                .block(
+                    Block::default()
+                        .title("Select Model for Component")
+                        .borders(Borders::ALL)
+                        .style(Style::default().fg(Color::Cyan)),
+                )
+                .highlight_style(
+                    Style::default()
+                        .bg(Color::DarkGray)
+                        .add_modifier(Modifier::BOLD),
+                )
                 .highlight_symbol("▶ ");
 
             f.render_widget(Clear, area);
@@ -894,34 +1307,80 @@ impl TuiEditor {
             f.render_widget(block, area);
 
             // ID
-            let id_text = format!("ID: {}", if state.id.is_empty() { &self.input_buffer } else { &state.id });
-            f.render_widget(Paragraph::new(id_text).style(
-                if state.step == ComponentCreationStep::EnteringId { Style::default().fg(Color::Yellow) }
-                else { Style::default().fg(Color::Green) }
-            ), chunks[0]);
+            let id_text = format!(
+                "ID: {}",
+                if state.id.is_empty() {
+                    &self.input_buffer
+                } else {
+                    &state.id
+                }
+            );
+            f.render_widget(
+                Paragraph::new(id_text).style(if state.step == ComponentCreationStep::EnteringId {
+                    Style::default().fg(Color::Yellow)
+                } else {
+                    Style::default().fg(Color::Green)
+                }),
+                chunks[0],
+            );
 
             // Name
-            let name_text = format!("Name: {}", if state.name.is_empty() { &self.input_buffer } else { &state.name });
-            f.render_widget(Paragraph::new(name_text).style(
-                if state.step == ComponentCreationStep::EnteringName { Style::default().fg(Color::Yellow) }
-                else if !state.name.is_empty() { Style::default().fg(Color::Green) }
-                else { Style::default() }
-            ), chunks[1]);
+            let name_text = format!(
+                "Name: {}",
+                if state.name.is_empty() {
+                    &self.input_buffer
+                } else {
+                    &state.name
+                }
+            );
+            f.render_widget(
+                Paragraph::new(name_text).style(
+                    if state.step == ComponentCreationStep::EnteringName {
+                        Style::default().fg(Color::Yellow)
+                    } else if !state.name.is_empty() {
+                        Style::default().fg(Color::Green)
+                    } else {
+                        Style::default()
+                    },
+                ),
+                chunks[1],
+            );
 
             // Type
-            let type_text = format!("Type: {:?}", state.component_type.unwrap_or(ComponentType::Layer));
-            f.render_widget(Paragraph::new(type_text).style(
-                if state.component_type.is_some() { Style::default().fg(Color::Green) }
-                else { Style::default() }
-            ), chunks[2]);
+            let type_text = format!(
+                "Type: {:?}",
+                state.component_type.unwrap_or(ComponentType::Layer)
+            );
+            f.render_widget(
+                Paragraph::new(type_text).style(if state.component_type.is_some() {
+                    Style::default().fg(Color::Green)
+                } else {
+                    Style::default()
+                }),
+                chunks[2],
+            );
 
             // Material ID
-            let mat_text = format!("Material ID: {}", if state.material_id.is_empty() { &self.input_buffer } else { &state.material_id });
-            f.render_widget(Paragraph::new(mat_text).style(
-                if state.step == ComponentCreationStep::EnteringMaterialId { Style::default().fg(Color::Yellow) }
-                else if !state.material_id.is_empty() { Style::default().fg(Color::Green) }
-                else { Style::default() }
-            ), chunks[3]);
+            let mat_text = format!(
+                "Material ID: {}",
+                if state.material_id.is_empty() {
+                    &self.input_buffer
+                } else {
+                    &state.material_id
+                }
+            );
+            f.render_widget(
+                Paragraph::new(mat_text).style(
+                    if state.step == ComponentCreationStep::EnteringMaterialId {
+                        Style::default().fg(Color::Yellow)
+                    } else if !state.material_id.is_empty() {
+                        Style::default().fg(Color::Green)
+                    } else {
+                        Style::default()
+                    },
+                ),
+                chunks[3],
+            );
         }
     }
 
@@ -936,11 +1395,17 @@ impl TuiEditor {
         ];
 
         let list = List::new(items)
-            .block(Block::default()
-                .title("Select Component Type")
-                .borders(Borders::ALL)
-                .style(Style::default().fg(Color::Cyan)))
-            .highlight_style(Style::default().bg(Color::DarkGray).add_modifier(Modifier::BOLD))
+            .block(
+                Block::default()
+                    .title("Select Component Type")
+                    .borders(Borders::ALL)
+                    .style(Style::default().fg(Color::Cyan)),
+            )
+            .highlight_style(
+                Style::default()
+                    .bg(Color::DarkGray)
+                    .add_modifier(Modifier::BOLD),
+            )
             .highlight_symbol("▶ ");
 
         f.render_widget(Clear, area);
@@ -948,13 +1413,21 @@ impl TuiEditor {
     }
 
     fn render_title(&self, f: &mut Frame, area: Rect) {
-        let title = Paragraph::new(vec![
-            Line::from(vec![
-                Span::styled("AGSi TUI Editor", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
-                Span::raw(" - "),
-                Span::raw(self.file_path.as_ref().map(|p| p.display().to_string()).unwrap_or_else(|| "New Document".to_string())),
-            ]),
-        ])
+        let title = Paragraph::new(vec![Line::from(vec![
+            Span::styled(
+                "AGSi TUI Editor",
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(" - "),
+            Span::raw(
+                self.file_path
+                    .as_ref()
+                    .map(|p| p.display().to_string())
+                    .unwrap_or_else(|| "New Document".to_string()),
+            ),
+        ])])
         .block(Block::default().borders(Borders::ALL));
         f.render_widget(title, area);
     }
@@ -986,10 +1459,26 @@ impl TuiEditor {
 
     fn render_menu(&mut self, f: &mut Frame, area: Rect) {
         let menu_items = vec![
-            ListItem::new(if self.view_mode == ViewMode::DocumentInfo { "▶ Document Info (d)" } else { "  Document Info (d)" }),
-            ListItem::new(if self.view_mode == ViewMode::Models { "▶ Models (m)" } else { "  Models (m)" }),
-            ListItem::new(if self.view_mode == ViewMode::Materials { "▶ Materials (a)" } else { "  Materials (a)" }),
-            ListItem::new(if self.view_mode == ViewMode::Components { "▶ Components (c)" } else { "  Components (c)" }),
+            ListItem::new(if self.view_mode == ViewMode::DocumentInfo {
+                "▶ Document Info (d)"
+            } else {
+                "  Document Info (d)"
+            }),
+            ListItem::new(if self.view_mode == ViewMode::Models {
+                "▶ Models (m)"
+            } else {
+                "  Models (m)"
+            }),
+            ListItem::new(if self.view_mode == ViewMode::Materials {
+                "▶ Materials (a)"
+            } else {
+                "  Materials (a)"
+            }),
+            ListItem::new(if self.view_mode == ViewMode::Components {
+                "▶ Components (c)"
+            } else {
+                "  Components (c)"
+            }),
         ];
 
         let menu = List::new(menu_items)
@@ -1001,7 +1490,12 @@ impl TuiEditor {
 
     fn render_document_info(&self, f: &mut Frame, area: Rect) {
         let mut text = vec![
-            Line::from(vec![Span::styled("Document Information", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))]),
+            Line::from(vec![Span::styled(
+                "Document Information",
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            )]),
             Line::from(""),
             Line::from(vec![
                 Span::styled("File ID: ", Style::default().fg(Color::Green)),
@@ -1023,16 +1517,26 @@ impl TuiEditor {
         text.push(Line::from(""));
         text.push(Line::from(vec![
             Span::styled("Models: ", Style::default().fg(Color::Yellow)),
-                Span::raw(self.document.agsi_model.len().to_string()),
+            Span::raw(self.document.agsi_model.len().to_string()),
         ]));
 
-        let total_materials: usize = self.document.agsi_model.iter().map(|m| m.materials.len()).sum();
+        let total_materials: usize = self
+            .document
+            .agsi_model
+            .iter()
+            .map(|m| m.materials.len())
+            .sum();
         text.push(Line::from(vec![
             Span::styled("Materials: ", Style::default().fg(Color::Yellow)),
             Span::raw(total_materials.to_string()),
         ]));
 
-        let total_components: usize = self.document.agsi_model.iter().map(|m| m.components.len()).sum();
+        let total_components: usize = self
+            .document
+            .agsi_model
+            .iter()
+            .map(|m| m.components.len())
+            .sum();
         text.push(Line::from(vec![
             Span::styled("Components: ", Style::default().fg(Color::Yellow)),
             Span::raw(total_components.to_string()),
@@ -1040,7 +1544,12 @@ impl TuiEditor {
 
         if let Some(ref project) = self.document.ags_project {
             text.push(Line::from(""));
-            text.push(Line::from(vec![Span::styled("Project", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))]));
+            text.push(Line::from(vec![Span::styled(
+                "Project",
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            )]));
             text.push(Line::from(vec![
                 Span::styled("  Name: ", Style::default().fg(Color::Green)),
                 Span::raw(&project.name),
@@ -1071,15 +1580,26 @@ impl TuiEditor {
                         Span::styled(&model.name, Style::default().fg(Color::Cyan)),
                         Span::raw(format!(" ({})", model.id)),
                     ]),
-                    Line::from(format!("  Type: {:?}, Dimension: {:?}", model.model_type, model.dimension)),
-                    Line::from(format!("  Materials: {}, Components: {}", model.materials.len(), model.components.len())),
+                    Line::from(format!(
+                        "  Type: {:?}, Dimension: {:?}",
+                        model.model_type, model.dimension
+                    )),
+                    Line::from(format!(
+                        "  Materials: {}, Components: {}",
+                        model.materials.len(),
+                        model.components.len()
+                    )),
                 ])
             })
             .collect();
 
         let list = List::new(items)
             .block(Block::default().borders(Borders::ALL).title("Models"))
-            .highlight_style(Style::default().bg(Color::DarkGray).add_modifier(Modifier::BOLD));
+            .highlight_style(
+                Style::default()
+                    .bg(Color::DarkGray)
+                    .add_modifier(Modifier::BOLD),
+            );
 
         f.render_stateful_widget(list, area, &mut self.list_state);
     }
@@ -1090,6 +1610,7 @@ impl TuiEditor {
             for material in &model.materials {
                 items.push(ListItem::new(vec![
                     Line::from(vec![
+                        Span::raw(selection_marker(&self.selected, items.len())),
                         Span::styled(&material.name, Style::default().fg(Color::Yellow)),
                         Span::raw(format!(" ({})", material.id)),
                     ]),
@@ -1101,7 +1622,11 @@ impl TuiEditor {
 
         let list = List::new(items)
             .block(Block::default().borders(Borders::ALL).title("Materials"))
-            .highlight_style(Style::default().bg(Color::DarkGray).add_modifier(Modifier::BOLD));
+            .highlight_style(
+                Style::default()
+                    .bg(Color::DarkGray)
+                    .add_modifier(Modifier::BOLD),
+            );
 
         f.render_stateful_widget(list, area, &mut self.list_state);
     }
@@ -1112,23 +1637,56 @@ impl TuiEditor {
             for component in &model.components {
                 items.push(ListItem::new(vec![
                     Line::from(vec![
+                        Span::raw(selection_marker(&self.selected, items.len())),
                         Span::styled(&component.name, Style::default().fg(Color::Magenta)),
                         Span::raw(format!(" ({})", component.id)),
                     ]),
                     Line::from(format!("  Type: {:?}", component.component_type)),
                     Line::from(format!("  Material: {}", component.material_id)),
+                    Line::from(format!("  Geometry: {}", component.geometry)),
                 ]));
             }
         }
 
         let list = List::new(items)
             .block(Block::default().borders(Borders::ALL).title("Components"))
-            .highlight_style(Style::default().bg(Color::DarkGray).add_modifier(Modifier::BOLD));
+            .highlight_style(
+                Style::default()
+                    .bg(Color::DarkGray)
+                    .add_modifier(Modifier::BOLD),
+            );
 
         f.render_stateful_widget(list, area, &mut self.list_state);
     }
 }
 
+/// Add an index to the selection, or remove it if already selected
+fn toggle_selection(selected: &mut HashSet<usize>, index: usize) {
+    if !selected.remove(&index) {
+        selected.insert(index);
+    }
+}
+
+fn selection_marker(selected: &HashSet<usize>, index: usize) -> &'static str {
+    if selected.contains(&index) {
+        "[x] "
+    } else {
+        ""
+    }
+}
+
+/// Status bar message shown before saving an invalid document, if any
+fn pre_save_status(result: &ValidationResult) -> Option<String> {
+    if result.is_valid {
+        return None;
+    }
+
+    Some(format!(
+        "⚠️ {} validation error(s) - press 's' again to save anyway, any other key to cancel",
+        result.errors.len()
+    ))
+}
+
 pub async fn launch_editor(file: Option<PathBuf>) -> Result<()> {
     let doc = if let Some(ref path) = file {
         Document::from_json_file(path)?
@@ -1141,3 +1699,95 @@ pub async fn launch_editor(file: Option<PathBuf>) -> Result<()> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use agsi_core::validation::{ValidationError, ValidationErrorType};
+
+    #[test]
+    fn test_pre_save_status() {
+        let mut result = ValidationResult::new();
+        assert_eq!(pre_save_status(&result), None);
+
+        result.is_valid = false;
+        result.errors = vec![
+            ValidationError::new("agsiModel[0]", "first", ValidationErrorType::Schema),
+            ValidationError::new("agsiModel[1]", "second", ValidationErrorType::Reference),
+        ];
+        let message = pre_save_status(&result).unwrap();
+        assert!(message.contains("2 validation error(s)"));
+        assert!(message.contains("press 's' again"));
+    }
+
+    #[test]
+    fn test_dirty_flag_through_create_and_save() {
+        let path = std::env::temp_dir().join(format!("agsi-tui-dirty-{}.json", std::process::id()));
+        let mut editor = TuiEditor::new(Document::new("DOC001"), Some(path.clone()));
+        assert!(!editor.dirty);
+
+        editor.finish_material_creation(MaterialCreationState {
+            id: "MAT001".to_string(),
+            name: "Clay".to_string(),
+            material_type: Some(MaterialType::Soil),
+            ..Default::default()
+        });
+        assert!(editor.dirty);
+
+        // Quitting with unsaved changes needs a second 'q'
+        editor.handle_input(KeyCode::Char('q'));
+        assert!(!editor.should_quit);
+        assert!(editor.confirm_quit);
+
+        editor.save();
+        std::fs::remove_file(&path).ok();
+        assert!(!editor.dirty);
+
+        editor.handle_input(KeyCode::Char('d'));
+        editor.handle_input(KeyCode::Char('q'));
+        assert!(editor.should_quit);
+    }
+
+    #[test]
+    fn test_delete_needs_confirmation() {
+        let mut doc = Document::new("DOC001");
+        let mut model = GroundModel::new(
+            "MODEL001",
+            "Model",
+            ModelType::Stratigraphic,
+            ModelDimension::TwoD,
+        );
+        model.add_material(Material::new("MAT001", "Clay", MaterialType::Soil));
+        doc.add_model(model);
+        let mut editor = TuiEditor::new(doc, None);
+        editor.handle_input(KeyCode::Char('a'));
+        editor.list_state.select(Some(0));
+
+        // Any key but 'y' cancels, and is not handled as a command
+        editor.handle_input(KeyCode::Char('x'));
+        assert!(editor.confirm_delete);
+        editor.handle_input(KeyCode::Char('q'));
+        assert!(!editor.should_quit);
+        assert_eq!(editor.document.agsi_model[0].materials.len(), 1);
+        assert_eq!(editor.status_message, "Delete cancelled");
+
+        editor.handle_input(KeyCode::Char('x'));
+        editor.handle_input(KeyCode::Char('y'));
+        assert!(editor.document.agsi_model[0].materials.is_empty());
+        assert!(editor.dirty);
+    }
+
+    #[test]
+    fn test_toggle_selection() {
+        let mut selected = HashSet::new();
+
+        toggle_selection(&mut selected, 2);
+        toggle_selection(&mut selected, 5);
+        assert_eq!(selected, HashSet::from([2, 5]));
+
+        toggle_selection(&mut selected, 2);
+        assert_eq!(selected, HashSet::from([5]));
+        assert_eq!(selection_marker(&selected, 5), "[x] ");
+        assert_eq!(selection_marker(&selected, 2), "");
+    }
+}
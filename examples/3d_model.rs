@@ -1,18 +1,21 @@
 use agsi_core::{
-    Document, Material, MaterialProperty, GroundModel,
-    material::{MaterialType, PropertySource, PropertyValue},
-    model::{ModelType, ModelDimension, ComponentType, ModelComponent, ModelExtent},
     geometry::Geometry,
-    project::{Project, Location},
+    material::{MaterialType, PropertySource},
+    model::{ComponentType, ModelComponent, ModelDimension, ModelExtent, ModelType},
+    project::{Location, Project},
+    Document, GroundModel, Material, MaterialProperty,
 };
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("Creating a complete 3D ground model...\n");
 
     // Create project
-    let location = Location::new("Silvertown, London")
-        .with_coordinates(-0.0249, 51.5033, Some("EPSG:4326".into()));
-    
+    let location = Location::new("Silvertown, London").with_coordinates(
+        -0.0249,
+        51.5033,
+        Some("EPSG:4326".into()),
+    );
+
     let project = Project::new("SILVER-2024", "Silvertown Tunnel Site Investigation")
         .with_client("Transport for London")
         .with_contractor("Ground Investigation Ltd")
@@ -26,43 +29,48 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Create detailed materials
     let made_ground = Material::new("MAT001", "Made Ground", MaterialType::MadeGround)
-        .with_description("Heterogeneous fill comprising brick, concrete fragments, sand and gravel")
+        .with_description(
+            "Heterogeneous fill comprising brick, concrete fragments, sand and gravel",
+        )
         .with_property(
             MaterialProperty::range("bulk_density", 1700.0, 1900.0, Some("kg/m3".into()))
-                .with_source(PropertySource::Estimated)
+                .with_source(PropertySource::Estimated),
         )
-        .with_property(
-            MaterialProperty::text("consistency", "Loose to medium dense")
-        );
+        .with_property(MaterialProperty::text(
+            "consistency",
+            "Loose to medium dense",
+        ));
 
     let alluvium = Material::new("MAT002", "Alluvium", MaterialType::Soil)
         .with_description("Soft to firm, silty clay with occasional peat lenses")
         .with_property(
             MaterialProperty::numeric("undrained_shear_strength", 35.0, Some("kPa".into()))
                 .with_source(PropertySource::Tested)
-                .with_method("Triaxial UU")
+                .with_method("Triaxial UU"),
         )
         .with_property(
             MaterialProperty::range("water_content", 25.0, 45.0, Some("%".into()))
-                .with_source(PropertySource::Tested)
+                .with_source(PropertySource::Tested),
         )
-        .with_property(
-            MaterialProperty::numeric("plasticity_index", 28.0, Some("%".into()))
-        );
+        .with_property(MaterialProperty::numeric(
+            "plasticity_index",
+            28.0,
+            Some("%".into()),
+        ));
 
     let terrace_gravel = Material::new("MAT003", "River Terrace Deposits", MaterialType::Soil)
         .with_description("Dense, sandy GRAVEL with occasional cobbles")
         .with_property(
             MaterialProperty::numeric("spt_n", 42.0, Some("blows/300mm".into()))
-                .with_source(PropertySource::Tested)
+                .with_source(PropertySource::Tested),
         )
         .with_property(
             MaterialProperty::numeric("friction_angle", 38.0, Some("degrees".into()))
-                .with_source(PropertySource::Estimated)
+                .with_source(PropertySource::Estimated),
         )
         .with_property(
             MaterialProperty::numeric("permeability", 1e-4, Some("m/s".into()))
-                .with_source(PropertySource::Tested)
+                .with_source(PropertySource::Tested),
         );
 
     let london_clay = Material::new("MAT004", "London Clay", MaterialType::Soil)
@@ -70,29 +78,34 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         .with_property(
             MaterialProperty::numeric("undrained_shear_strength", 150.0, Some("kPa".into()))
                 .with_source(PropertySource::Tested)
-                .with_method("Triaxial UU")
-        )
-        .with_property(
-            MaterialProperty::range("plasticity_index", 40.0, 55.0, Some("%".into()))
+                .with_method("Triaxial UU"),
         )
+        .with_property(MaterialProperty::range(
+            "plasticity_index",
+            40.0,
+            55.0,
+            Some("%".into()),
+        ))
         .with_property(
             MaterialProperty::numeric("bulk_density", 2050.0, Some("kg/m3".into()))
-                .with_source(PropertySource::Tested)
+                .with_source(PropertySource::Tested),
         )
         .with_property(
             MaterialProperty::numeric("cohesion", 15.0, Some("kPa".into()))
-                .with_source(PropertySource::Calculated)
+                .with_source(PropertySource::Calculated),
         );
 
     let chalk = Material::new("MAT005", "Thanet Sand", MaterialType::Soil)
         .with_description("Dense to very dense, fine to medium SAND")
         .with_property(
             MaterialProperty::numeric("spt_n", 55.0, Some("blows/300mm".into()))
-                .with_source(PropertySource::Tested)
+                .with_source(PropertySource::Tested),
         )
-        .with_property(
-            MaterialProperty::numeric("friction_angle", 40.0, Some("degrees".into()))
-        );
+        .with_property(MaterialProperty::numeric(
+            "friction_angle",
+            40.0,
+            Some("degrees".into()),
+        ));
 
     // Create 3D ground model
     let mut model = GroundModel::new(
@@ -103,9 +116,9 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     )
     .with_crs("EPSG:27700") // British National Grid
     .with_extent(ModelExtent::new_3d(
-        540000.0, 541000.0,  // 1km x extent
-        179000.0, 180000.0,  // 1km y extent
-        -50.0, 5.0,          // 55m vertical extent
+        540000.0, 541000.0, // 1km x extent
+        179000.0, 180000.0, // 1km y extent
+        -50.0, 5.0, // 55m vertical extent
     ));
 
     // Add materials
@@ -225,7 +238,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Validate
     println!("Validating 3D model...");
     let validation_result = agsi_core::validation::validate_document(&doc)?;
-    
+
     if validation_result.is_valid() {
         println!("✅ 3D model is valid!\n");
     } else {
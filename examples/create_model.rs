@@ -1,10 +1,9 @@
 use agsi_core::{
-    Document, Material, MaterialProperty, GroundModel,
-    material::{MaterialType, PropertySource},
-    model::{ModelType, ModelDimension, ComponentType, ModelComponent, ModelExtent},
     geometry::Geometry,
+    material::{MaterialType, PropertySource},
+    model::{ComponentType, ModelComponent, ModelDimension, ModelExtent, ModelType},
     project::Project,
-    validation,
+    validation, Document, GroundModel, Material, MaterialProperty,
 };
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -26,7 +25,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         .with_description("Mixed fill material with brick and concrete fragments")
         .with_property(
             MaterialProperty::numeric("bulk_density", 1800.0, Some("kg/m3".to_string()))
-                .with_source(PropertySource::Estimated)
+                .with_source(PropertySource::Estimated),
         );
 
     let london_clay = Material::new("MAT002", "London Clay", MaterialType::Soil)
@@ -34,25 +33,28 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         .with_property(
             MaterialProperty::numeric("undrained_shear_strength", 100.0, Some("kPa".to_string()))
                 .with_source(PropertySource::Tested)
-                .with_method("Triaxial UU")
-        )
-        .with_property(
-            MaterialProperty::range("plasticity_index", 35.0, 50.0, Some("%".to_string()))
+                .with_method("Triaxial UU"),
         )
+        .with_property(MaterialProperty::range(
+            "plasticity_index",
+            35.0,
+            50.0,
+            Some("%".to_string()),
+        ))
         .with_property(
             MaterialProperty::numeric("bulk_density", 2000.0, Some("kg/m3".to_string()))
-                .with_source(PropertySource::Tested)
+                .with_source(PropertySource::Tested),
         );
 
     let terrace_gravel = Material::new("MAT003", "River Terrace Deposits", MaterialType::Soil)
         .with_description("Dense sandy gravel")
         .with_property(
             MaterialProperty::numeric("relative_density", 75.0, Some("%".to_string()))
-                .with_source(PropertySource::Tested)
+                .with_source(PropertySource::Tested),
         )
         .with_property(
             MaterialProperty::numeric("friction_angle", 38.0, Some("degrees".to_string()))
-                .with_source(PropertySource::Tested)
+                .with_source(PropertySource::Tested),
         );
 
     // Create ground model
@@ -64,9 +66,9 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     )
     .with_crs("EPSG:27700")
     .with_extent(ModelExtent::new_3d(
-        530000.0, 530200.0,  // X extent
-        180000.0, 180200.0,  // Y extent
-        -20.0, 5.0,          // Z extent (elevation)
+        530000.0, 530200.0, // X extent
+        180000.0, 180200.0, // Y extent
+        -20.0, 5.0, // Z extent (elevation)
     ));
 
     // Add materials
@@ -143,7 +145,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Validate
     println!("Validating document...");
     let validation_result = validation::validate_document(&doc)?;
-    
+
     if validation_result.is_valid() {
         println!("✅ Document is valid!\n");
     } else {